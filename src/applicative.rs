@@ -43,9 +43,9 @@ pub mod kind {
     //! The `lift_a1` function in this module demonstrates this pattern.
 
     use crate::apply::kind::Apply; // Kind-based Apply
-    use crate::function::{CFn, CFnOnce};
+    use crate::function::{CFn, CFnOnce, RcFn};
     use crate::kind_based::kind::{
-        CFnKind, CFnOnceKind, Kind, Kind1, OptionKind, ResultKind, VecKind,
+        CFnKind, CFnOnceKind, Kind, Kind1, OptionKind, RcFnKind, ResultKind, VecKind,
     };
 
     /// Represents a Kind-encoded type that is an Applicative Functor.
@@ -152,6 +152,20 @@ pub mod kind {
         }
     }
 
+    // Applicative for RcFnKind
+    // Lifts a value `T` into `RcFn<X, T>` which always returns `value.clone()`
+    impl<X, T> Applicative<T> for RcFnKind<X>
+    where
+        X: 'static + Clone,
+        T: 'static + Clone,
+    {
+        /// Lifts a value `T` into an `RcFn<X, T>` that ignores its input and returns a
+        /// clone of `value`.
+        fn pure(value: T) -> Self::Of<T> {
+            RcFn::new(move |_x: X| value.clone())
+        }
+    }
+
     // Applicative for CFnOnceKind
     // Lifts a value `T` into `CFnOnce<X, T>`
     impl<X, T> Applicative<T> for CFnOnceKind<X>
@@ -14,10 +14,10 @@ pub mod kind {
     //! - `A`: The input type of the function `A -> B` and the type of value in `Self::Of<A>`.
     //! - `B`: The output type of the function `A -> B` and the type of value in `Self::Of<B>`.
 
-    use crate::function::{CFn, CFnOnce};
+    use crate::function::{CFn, CFnMut, CFnOnce, RcFn};
     use crate::functor::Functor; // Kind-based Functor
     use crate::kind_based::kind::{
-        CFnKind, CFnOnceKind, Kind, Kind1, OptionKind, ResultKind, VecKind,
+        CFnKind, CFnMutKind, CFnOnceKind, Kind, Kind1, OptionKind, RcFnKind, ResultKind, VecKind,
     };
 
     /// Represents a Kind-encoded type that can apply a wrapped function to a wrapped value.
@@ -137,6 +137,24 @@ pub mod kind {
         }
     }
 
+    // Apply for RcFnKind<X>: the same as for CFnKind, producing a shareable RcFn
+    impl<X, A, B> Apply<A, B> for RcFnKind<X>
+    where
+        X: 'static + Clone, // Clone for x_val in the closure
+        A: 'static,
+        B: 'static,
+    {
+        fn apply(
+            value_container: Self::Of<A>,
+            function_container: Self::Of<CFn<A, B>>,
+        ) -> Self::Of<B> {
+            RcFn::new(move |x_val: X| {
+                let func_ab = function_container.call(x_val.clone());
+                func_ab.call(value_container.call(x_val))
+            })
+        }
+    }
+
     // Apply for CFnOnceKind<X>
     // Similar to CFnKind, but uses call_once and produces CFnOnce
     impl<X, A, B> Apply<A, B> for CFnOnceKind<X>
@@ -166,6 +184,24 @@ pub mod kind {
         }
     }
 
+    // Apply for CFnMutKind<X>: both stateful functions are called once per call of the result
+    impl<X, A, B> Apply<A, B> for CFnMutKind<X>
+    where
+        X: 'static + Clone, // Clone for x_val in the closure
+        A: 'static,
+        B: 'static,
+    {
+        fn apply(
+            mut value_container: Self::Of<A>,
+            mut function_container: Self::Of<CFn<A, B>>,
+        ) -> Self::Of<B> {
+            CFnMut::new(move |x_val: X| {
+                let func_ab = function_container.call_mut(x_val.clone());
+                func_ab.call(value_container.call_mut(x_val))
+            })
+        }
+    }
+
     /// Lifts a binary curried function to operate on Kind-encoded contexts.
     ///
     /// Given `func: A -> (B -> C)` (represented as `A -> CFn<B, C>`),
@@ -0,0 +1,206 @@
+//! # Category and Arrow traits for the `monadify` library
+//!
+//! An *arrow* is a value describing a computation from `B` to `C`: a plain function
+//! (`CFn`, `CFnOnce`), or an effectful one such as [`crate::kleisli::Kleisli`]
+//! (`B -> MKind::Of<C>`). This module abstracts over how arrows are built and wired
+//! together:
+//!
+//! - [`Semigroupoid`]: `compose` two arrows end to end.
+//! - [`Category`]: adds the identity arrow `id`.
+//! - [`Arrow`]: lifts plain functions with `arr`, and runs arrows side by side on pairs
+//!   with `split` (`***` in Haskell) and `fanout` (`&&&`). `first` and `second` come from
+//!   the [`Strong`] supertrait.
+//! - [`ArrowChoice`]: runs one of two arrows depending on the case of a `Result`, with
+//!   `choose` (`+++`) and `fanin` (`|||`). `left` and `right` come from the [`Choice`]
+//!   supertrait.
+//!
+//! Like [`Profunctor`], the traits are implemented on the arrow types themselves, with
+//! `Self::Pro<T, U>` naming the arrow of the same kind from `T` to `U`. As in [`Choice`],
+//! the "left" case of a sum is `Err` and the "right" case is `Ok`.
+//!
+//! ## Laws
+//! 1.  `f.compose(id()) == f` and `id().compose(f) == f`.
+//! 2.  `h.compose(g).compose(f) == h.compose(g.compose(f))`.
+//! 3.  `arr(|b| g(f(b))) == arr(g).compose(arr(f))`.
+//! 4.  `arr(f).first() == arr(|(b, d)| (f(b), d))`.
+//!
+//! ## Example
+//! ```
+//! use monadify::arrow::{Arrow, Semigroupoid};
+//! use monadify::function::CFn;
+//!
+//! let length = CFn::arr(|s: String| s.len());
+//! let shout = CFn::arr(|s: String| s.to_uppercase());
+//! // `&&&`: feed the same input to both arrows.
+//! let both = length.fanout(shout);
+//! assert_eq!(both.call("abc".to_string()), (3, "ABC".to_string()));
+//!
+//! // `***`: run each arrow on its own half of a pair, then keep going.
+//! let halves = CFn::arr(|x: i32| x + 1).split(CFn::arr(|c: char| c.is_alphabetic()));
+//! let summary = CFn::arr(|(n, ok): (i32, bool)| format!("{n}/{ok}")).compose(halves);
+//! assert_eq!(summary.call((1, 'x')), "2/true");
+//! ```
+
+use crate::function::{CFn, CFnOnce};
+use crate::profunctor::{Choice, Profunctor, Strong};
+
+/// Arrows that can be composed end to end.
+///
+/// ## Law
+/// Associativity: `h.compose(g).compose(f) == h.compose(g.compose(f))`.
+pub trait Semigroupoid<B, C>: Profunctor<B, C> {
+    /// Composes `self` after `before`: the result runs `before`, then `self`.
+    /// If `self` is `P<B,C>` and `before` is `P<A,B>`, the result is `P<A,C>`.
+    fn compose<A: 'static>(self, before: Self::Pro<A, B>) -> Self::Pro<A, C>;
+}
+
+/// A [`Semigroupoid`] with an identity arrow.
+///
+/// `Self` is the arrow from `A` to `A`, e.g. `CFn<A, A>`.
+///
+/// ## Law
+/// Identity: `f.compose(id()) == f` and `id().compose(f) == f`.
+pub trait Category<A>: Semigroupoid<A, A> {
+    /// The arrow returning its input unchanged.
+    fn id() -> Self;
+}
+
+/// Arrows that plain functions can be lifted into, and that can run on pairs.
+///
+/// `first` and `second` are [`Strong`]'s; `split` and `fanout` combine two arrows
+/// into one over pairs.
+pub trait Arrow<B, C>: Semigroupoid<B, C> + Strong<B, C> + Sized {
+    /// Lifts a plain function into an arrow.
+    fn arr(f: impl Fn(B) -> C + 'static) -> Self;
+
+    /// Runs `self` on the first component and `other` on the second (`***`).
+    /// If `self` is `P<B,C>` and `other` is `P<B2,C2>`, the result is `P<(B,B2), (C,C2)>`.
+    fn split<B2: 'static, C2: 'static>(
+        self,
+        other: Self::Pro<B2, C2>,
+    ) -> Self::Pro<(B, B2), (C, C2)>;
+
+    /// Runs `self` and `other` on copies of the same input (`&&&`).
+    /// If `self` is `P<B,C>` and `other` is `P<B,C2>`, the result is `P<B, (C,C2)>`.
+    fn fanout<C2: 'static>(self, other: Self::Pro<B, C2>) -> Self::Pro<B, (C, C2)>
+    where
+        B: Clone;
+}
+
+/// Arrows that can choose between two computations based on a `Result`.
+///
+/// `left` and `right` are [`Choice`]'s; `choose` and `fanin` combine two arrows into
+/// one over sums. `self` handles the `Err` ("left") case, `other` the `Ok` case.
+pub trait ArrowChoice<B, C>: Arrow<B, C> + Choice<B, C> {
+    /// Runs `self` on `Err` and `other` on `Ok`, keeping the case (`+++`).
+    /// If `self` is `P<B,C>` and `other` is `P<B2,C2>`, the result is
+    /// `P<Result<B2,B>, Result<C2,C>>`.
+    fn choose<B2: 'static, C2: 'static>(
+        self,
+        other: Self::Pro<B2, C2>,
+    ) -> Self::Pro<Result<B2, B>, Result<C2, C>>;
+
+    /// Runs `self` on `Err` and `other` on `Ok`, merging their outputs (`|||`).
+    /// If `self` is `P<B,C>` and `other` is `P<B2,C>`, the result is `P<Result<B2,B>, C>`.
+    fn fanin<B2: 'static>(self, other: Self::Pro<B2, C>) -> Self::Pro<Result<B2, B>, C>;
+}
+
+// --- CFn ---
+
+impl<B: 'static, C: 'static> Semigroupoid<B, C> for CFn<B, C> {
+    fn compose<A: 'static>(self, before: CFn<A, B>) -> CFn<A, C> {
+        before >> self
+    }
+}
+
+impl<A: 'static> Category<A> for CFn<A, A> {
+    fn id() -> Self {
+        CFn::new(|a| a)
+    }
+}
+
+impl<B: 'static, C: 'static> Arrow<B, C> for CFn<B, C> {
+    fn arr(f: impl Fn(B) -> C + 'static) -> Self {
+        CFn::new(f)
+    }
+
+    fn split<B2: 'static, C2: 'static>(self, other: CFn<B2, C2>) -> CFn<(B, B2), (C, C2)> {
+        CFn::new(move |(b, b2)| (self.call(b), other.call(b2)))
+    }
+
+    fn fanout<C2: 'static>(self, other: CFn<B, C2>) -> CFn<B, (C, C2)>
+    where
+        B: Clone,
+    {
+        CFn::new(move |b: B| (self.call(b.clone()), other.call(b)))
+    }
+}
+
+impl<B: 'static, C: 'static> ArrowChoice<B, C> for CFn<B, C> {
+    fn choose<B2: 'static, C2: 'static>(
+        self,
+        other: CFn<B2, C2>,
+    ) -> CFn<Result<B2, B>, Result<C2, C>> {
+        CFn::new(move |r: Result<B2, B>| match r {
+            Ok(b2) => Ok(other.call(b2)),
+            Err(b) => Err(self.call(b)),
+        })
+    }
+
+    fn fanin<B2: 'static>(self, other: CFn<B2, C>) -> CFn<Result<B2, B>, C> {
+        CFn::new(move |r: Result<B2, B>| match r {
+            Ok(b2) => other.call(b2),
+            Err(b) => self.call(b),
+        })
+    }
+}
+
+// --- CFnOnce ---
+
+impl<B: 'static, C: 'static> Semigroupoid<B, C> for CFnOnce<B, C> {
+    fn compose<A: 'static>(self, before: CFnOnce<A, B>) -> CFnOnce<A, C> {
+        before >> self
+    }
+}
+
+impl<A: 'static> Category<A> for CFnOnce<A, A> {
+    fn id() -> Self {
+        CFnOnce::new(|a| a)
+    }
+}
+
+impl<B: 'static, C: 'static> Arrow<B, C> for CFnOnce<B, C> {
+    fn arr(f: impl Fn(B) -> C + 'static) -> Self {
+        CFnOnce::new(f)
+    }
+
+    fn split<B2: 'static, C2: 'static>(self, other: CFnOnce<B2, C2>) -> CFnOnce<(B, B2), (C, C2)> {
+        CFnOnce::new(move |(b, b2)| (self.call_once(b), other.call_once(b2)))
+    }
+
+    fn fanout<C2: 'static>(self, other: CFnOnce<B, C2>) -> CFnOnce<B, (C, C2)>
+    where
+        B: Clone,
+    {
+        CFnOnce::new(move |b: B| (self.call_once(b.clone()), other.call_once(b)))
+    }
+}
+
+impl<B: 'static, C: 'static> ArrowChoice<B, C> for CFnOnce<B, C> {
+    fn choose<B2: 'static, C2: 'static>(
+        self,
+        other: CFnOnce<B2, C2>,
+    ) -> CFnOnce<Result<B2, B>, Result<C2, C>> {
+        CFnOnce::new(move |r: Result<B2, B>| match r {
+            Ok(b2) => Ok(other.call_once(b2)),
+            Err(b) => Err(self.call_once(b)),
+        })
+    }
+
+    fn fanin<B2: 'static>(self, other: CFnOnce<B2, C>) -> CFnOnce<Result<B2, B>, C> {
+        CFnOnce::new(move |r: Result<B2, B>| match r {
+            Ok(b2) => other.call_once(b2),
+            Err(b) => self.call_once(b),
+        })
+    }
+}
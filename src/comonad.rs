@@ -0,0 +1,446 @@
+//! # Kind-based Extend and Comonad for the `monadify` library
+//!
+//! This module defines the duals of `Bind` and `Monad`:
+//!
+//! - [`Extend`]: Provides `extend`, which applies a function that looks at a *whole*
+//!   structure `W::Of<A>` (e.g. a cell together with its neighbourhood) at every
+//!   position, producing `W::Of<B>`. [`duplicate`] is `extend` with the identity function.
+//! - [`Comonad`]: Extends [`Extend`] with `extract`, which reads the value at the
+//!   current focus (the dual of `pure`).
+//!
+//! ## Key Components
+//! - Instances for [`IdentityKind`].
+//! - [`NonEmpty<A>`] / [`NonEmptyKind`]: a list with at least one element. Extending
+//!   runs the function on every suffix of the list.
+//! - [`Store<S, A>`] / [`StoreKind<S>`]: a lookup function `S -> A` plus a current
+//!   position `S`; useful for grids and cellular automata.
+//! - [`Env<E, A>`] / [`EnvKind<E>`]: a value paired with a read-only environment
+//!   (the dual of the Reader monad).
+//! - [`Traced<M, A>`] / [`TracedKind<M>`]: a function `M -> A` from a [`Monoid`],
+//!   read at `M::empty()` (the dual of the Writer monad).
+//!
+//! ## Comonad Laws
+//! 1.  **Left identity**: `W::extend(w, W::extract) == w`.
+//! 2.  **Right identity**: `W::extract(W::extend(w, f)) == f(w)`.
+//! 3.  **Associativity**: `W::extend(W::extend(w, g), f) == W::extend(w, |x| f(W::extend(x, g)))`.
+//!
+//! ## Example
+//! ```
+//! use monadify::comonad::{Comonad, Extend, Store, StoreKind};
+//!
+//! // A one-dimensional "grid" where each cell is its index squared.
+//! let grid: Store<i32, i32> = Store::new(|i: i32| i * i, 3);
+//! assert_eq!(StoreKind::extract(grid.clone()), 9);
+//!
+//! // Sum each cell with its neighbours.
+//! let blurred = StoreKind::extend(grid, |w: Store<i32, i32>| {
+//!     w.peek(w.pos - 1) + w.peek(w.pos) + w.peek(w.pos + 1)
+//! });
+//! assert_eq!(StoreKind::extract(blurred.clone()), 4 + 9 + 16);
+//! assert_eq!(blurred.peek(0), 1 + 0 + 1);
+//! ```
+
+use crate::functor::kind::Functor;
+use crate::identity::{Identity, IdentityKind};
+use crate::kind_based::kind::{Kind, Kind1};
+use crate::monoid::Monoid;
+use std::marker::PhantomData;
+use std::rc::Rc;
+
+/// Represents a Kind-encoded structure that can be extended with a function of the
+/// whole structure.
+///
+/// `extend` is the dual of `Bind::bind`: where `bind` takes `A -> F::Of<B>`,
+/// `extend` takes `W::Of<A> -> B`.
+pub trait Extend<A, B>: Functor<A, B>
+where
+    Self: Sized + Kind1,
+    A: 'static,
+    B: 'static,
+{
+    /// Applies `func` to the structure focused at every position.
+    ///
+    /// # Parameters
+    /// - `input`: The structure `Self::Of<A>` to extend.
+    /// - `func`: A function reading a whole `Self::Of<A>` (focused at some position)
+    ///   and producing the new value for that position.
+    ///
+    /// # Returns
+    /// A structure `Self::Of<B>` of the same shape as `input`.
+    fn extend(
+        input: Self::Of<A>,
+        func: impl FnMut(Self::Of<A>) -> B + Clone + 'static,
+    ) -> Self::Of<B>;
+}
+
+/// Represents a Kind-encoded structure with a focused value that can be extracted.
+///
+/// `Comonad` is the dual of `Monad`: `extract` is the dual of `pure`, and
+/// [`duplicate`] is the dual of `join`.
+pub trait Comonad<A>: Extend<A, A>
+where
+    Self: Sized + Kind1,
+    A: 'static,
+{
+    /// Returns the value at the current focus.
+    fn extract(input: Self::Of<A>) -> A;
+}
+
+/// Nests a structure inside itself, so that every position holds the structure
+/// focused at that position. `duplicate(w) == W::extend(w, |x| x)`.
+pub fn duplicate<W, A>(input: W::Of<A>) -> W::Of<W::Of<A>>
+where
+    W: Kind1 + Extend<A, <W as Kind>::Of<A>>,
+    A: 'static,
+    W::Of<A>: 'static,
+{
+    W::extend(input, |w: W::Of<A>| w)
+}
+
+// --- IdentityKind ---
+
+impl<A: 'static, B: 'static> Extend<A, B> for IdentityKind {
+    /// `Identity(a)` becomes `Identity(func(Identity(a)))`.
+    fn extend(
+        input: Identity<A>,
+        mut func: impl FnMut(Identity<A>) -> B + Clone + 'static,
+    ) -> Identity<B> {
+        Identity(func(input))
+    }
+}
+
+impl<A: 'static> Comonad<A> for IdentityKind {
+    /// Unwraps the value.
+    fn extract(input: Identity<A>) -> A {
+        input.0
+    }
+}
+
+// --- NonEmpty ---
+
+/// A list with at least one element: a `head` followed by a (possibly empty) `tail`.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct NonEmpty<A> {
+    /// The first element.
+    pub head: A,
+    /// The remaining elements.
+    pub tail: Vec<A>,
+}
+
+impl<A> NonEmpty<A> {
+    /// Creates a new `NonEmpty` from its first element and the rest.
+    pub fn new(head: A, tail: Vec<A>) -> Self {
+        NonEmpty { head, tail }
+    }
+
+    /// Converts a `Vec` into a `NonEmpty`, returning `None` if it is empty.
+    pub fn from_vec(mut items: Vec<A>) -> Option<Self> {
+        if items.is_empty() {
+            None
+        } else {
+            let head = items.remove(0);
+            Some(NonEmpty { head, tail: items })
+        }
+    }
+
+    /// Converts the `NonEmpty` back into a `Vec`.
+    pub fn into_vec(self) -> Vec<A> {
+        let mut items = Vec::with_capacity(self.tail.len() + 1);
+        items.push(self.head);
+        items.extend(self.tail);
+        items
+    }
+
+    /// Returns the number of elements, which is always at least one.
+    pub fn len(&self) -> usize {
+        self.tail.len() + 1
+    }
+
+    /// Always returns `false`; provided for API symmetry with `len`.
+    pub fn is_empty(&self) -> bool {
+        false
+    }
+}
+
+/// The Kind marker for [`NonEmpty`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct NonEmptyKind;
+
+impl Kind for NonEmptyKind {
+    type Of<A> = NonEmpty<A>;
+}
+
+impl<A, B> Functor<A, B> for NonEmptyKind {
+    fn map(input: NonEmpty<A>, mut func: impl FnMut(A) -> B + Clone + 'static) -> NonEmpty<B> {
+        let head = func(input.head);
+        NonEmpty::new(head, input.tail.into_iter().map(func).collect())
+    }
+}
+
+impl<A: Clone + 'static, B: 'static> Extend<A, B> for NonEmptyKind {
+    /// Applies `func` to every suffix of the list: the whole list, the list without
+    /// its first element, and so on.
+    fn extend(
+        input: NonEmpty<A>,
+        mut func: impl FnMut(NonEmpty<A>) -> B + Clone + 'static,
+    ) -> NonEmpty<B> {
+        let items = input.into_vec();
+        let mut results = (0..items.len()).map(|i| {
+            let suffix = NonEmpty::new(items[i].clone(), items[i + 1..].to_vec());
+            func(suffix)
+        });
+        // `items` is non-empty, so there is always a first result.
+        let head = results.next().expect("NonEmpty always has a head");
+        NonEmpty::new(head, results.collect())
+    }
+}
+
+impl<A: Clone + 'static> Comonad<A> for NonEmptyKind {
+    /// Returns the head of the list.
+    fn extract(input: NonEmpty<A>) -> A {
+        input.head
+    }
+}
+
+// --- Store ---
+
+/// The `Store` comonad: a lookup function `S -> A` together with a current position `S`.
+///
+/// The lookup is stored behind an `Rc`, so a `Store` is cheap to clone.
+pub struct Store<S, A> {
+    /// The function giving the value at any position.
+    pub lookup: Rc<dyn Fn(S) -> A + 'static>,
+    /// The current position (the focus).
+    pub pos: S,
+}
+
+impl<S, A> Store<S, A> {
+    /// Creates a new `Store` from a lookup function and a starting position.
+    pub fn new<F>(lookup: F, pos: S) -> Self
+    where
+        F: Fn(S) -> A + 'static,
+    {
+        Store {
+            lookup: Rc::new(lookup),
+            pos,
+        }
+    }
+
+    /// Returns the value at position `s`, regardless of the current focus.
+    pub fn peek(&self, s: S) -> A {
+        (self.lookup)(s)
+    }
+
+    /// Returns the value at the position obtained by applying `f` to the current one.
+    pub fn peeks(&self, f: impl FnOnce(S) -> S) -> A
+    where
+        S: Clone,
+    {
+        (self.lookup)(f(self.pos.clone()))
+    }
+
+    /// Moves the focus to position `s`.
+    pub fn seek(self, s: S) -> Self {
+        Store {
+            lookup: self.lookup,
+            pos: s,
+        }
+    }
+
+    /// Moves the focus by applying `f` to the current position.
+    pub fn seeks(self, f: impl FnOnce(S) -> S) -> Self {
+        Store {
+            lookup: self.lookup,
+            pos: f(self.pos),
+        }
+    }
+}
+
+impl<S: Clone, A> Clone for Store<S, A> {
+    fn clone(&self) -> Self {
+        Store {
+            lookup: self.lookup.clone(),
+            pos: self.pos.clone(),
+        }
+    }
+}
+
+/// The Kind marker for `Store<S, _>`, where the position type `S` is fixed.
+#[derive(Default)]
+pub struct StoreKind<S>(PhantomData<S>);
+
+impl<S> Kind for StoreKind<S> {
+    type Of<A> = Store<S, A>;
+}
+
+impl<S: 'static, A: 'static, B: 'static> Functor<A, B> for StoreKind<S> {
+    /// Post-composes `func` with the lookup function; the position is unchanged.
+    fn map(input: Store<S, A>, func: impl FnMut(A) -> B + Clone + 'static) -> Store<S, B> {
+        let lookup = input.lookup;
+        Store::new(move |s: S| func.clone()(lookup(s)), input.pos)
+    }
+}
+
+impl<S: Clone + 'static, A: 'static, B: 'static> Extend<A, B> for StoreKind<S> {
+    /// The new lookup at position `s` runs `func` on the store refocused at `s`.
+    fn extend(
+        input: Store<S, A>,
+        func: impl FnMut(Store<S, A>) -> B + Clone + 'static,
+    ) -> Store<S, B> {
+        let lookup = input.lookup;
+        Store::new(
+            move |s: S| {
+                func.clone()(Store {
+                    lookup: lookup.clone(),
+                    pos: s,
+                })
+            },
+            input.pos,
+        )
+    }
+}
+
+impl<S: Clone + 'static, A: 'static> Comonad<A> for StoreKind<S> {
+    /// Looks up the value at the current position.
+    fn extract(input: Store<S, A>) -> A {
+        (input.lookup)(input.pos)
+    }
+}
+
+// --- Env ---
+
+/// The `Env` comonad: a value paired with a read-only environment.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Env<E, A> {
+    /// The environment.
+    pub env: E,
+    /// The focused value.
+    pub value: A,
+}
+
+impl<E, A> Env<E, A> {
+    /// Creates a new `Env` from an environment and a value.
+    pub fn new(env: E, value: A) -> Self {
+        Env { env, value }
+    }
+
+    /// Returns a reference to the environment.
+    pub fn ask(&self) -> &E {
+        &self.env
+    }
+
+    /// Replaces the environment with the result of `f`.
+    pub fn local(self, f: impl FnOnce(E) -> E) -> Self {
+        Env {
+            env: f(self.env),
+            value: self.value,
+        }
+    }
+}
+
+/// The Kind marker for `Env<E, _>`, where the environment type `E` is fixed.
+#[derive(Default)]
+pub struct EnvKind<E>(PhantomData<E>);
+
+impl<E> Kind for EnvKind<E> {
+    type Of<A> = Env<E, A>;
+}
+
+impl<E, A, B> Functor<A, B> for EnvKind<E> {
+    /// Maps the value; the environment is unchanged.
+    fn map(input: Env<E, A>, mut func: impl FnMut(A) -> B + Clone + 'static) -> Env<E, B> {
+        Env::new(input.env, func(input.value))
+    }
+}
+
+impl<E: Clone + 'static, A: 'static, B: 'static> Extend<A, B> for EnvKind<E> {
+    /// Runs `func` on the whole `Env` and keeps the environment.
+    fn extend(
+        input: Env<E, A>,
+        mut func: impl FnMut(Env<E, A>) -> B + Clone + 'static,
+    ) -> Env<E, B> {
+        let env = input.env.clone();
+        Env::new(env, func(input))
+    }
+}
+
+impl<E: Clone + 'static, A: 'static> Comonad<A> for EnvKind<E> {
+    /// Returns the value, dropping the environment.
+    fn extract(input: Env<E, A>) -> A {
+        input.value
+    }
+}
+
+// --- Traced ---
+
+/// The `Traced` comonad: a function `M -> A` where `M` is a [`Monoid`].
+///
+/// The focus is the value at `M::empty()`; extending reads "relative" positions
+/// by combining them onto the current one.
+pub struct Traced<M, A> {
+    /// The traced function.
+    pub run_traced: Rc<dyn Fn(M) -> A + 'static>,
+}
+
+impl<M, A> Traced<M, A> {
+    /// Creates a new `Traced` from a function `M -> A`.
+    pub fn new<F>(f: F) -> Self
+    where
+        F: Fn(M) -> A + 'static,
+    {
+        Traced {
+            run_traced: Rc::new(f),
+        }
+    }
+
+    /// Runs the traced function at `m`.
+    pub fn trace(&self, m: M) -> A {
+        (self.run_traced)(m)
+    }
+}
+
+impl<M, A> Clone for Traced<M, A> {
+    fn clone(&self) -> Self {
+        Traced {
+            run_traced: self.run_traced.clone(),
+        }
+    }
+}
+
+/// The Kind marker for `Traced<M, _>`, where the monoid `M` is fixed.
+#[derive(Default)]
+pub struct TracedKind<M>(PhantomData<M>);
+
+impl<M> Kind for TracedKind<M> {
+    type Of<A> = Traced<M, A>;
+}
+
+impl<M: 'static, A: 'static, B: 'static> Functor<A, B> for TracedKind<M> {
+    /// Post-composes `func` with the traced function.
+    fn map(input: Traced<M, A>, func: impl FnMut(A) -> B + Clone + 'static) -> Traced<M, B> {
+        let run = input.run_traced;
+        Traced::new(move |m: M| func.clone()(run(m)))
+    }
+}
+
+impl<M: Monoid + Clone + 'static, A: 'static, B: 'static> Extend<A, B> for TracedKind<M> {
+    /// The new function at `m` runs `func` on the input shifted by `m`, i.e. on
+    /// `|m2| input(m.combine(m2))`.
+    fn extend(
+        input: Traced<M, A>,
+        func: impl FnMut(Traced<M, A>) -> B + Clone + 'static,
+    ) -> Traced<M, B> {
+        let run = input.run_traced;
+        Traced::new(move |m: M| {
+            let run = run.clone();
+            func.clone()(Traced::new(move |m2: M| run(m.clone().combine(m2))))
+        })
+    }
+}
+
+impl<M: Monoid + Clone + 'static, A: 'static> Comonad<A> for TracedKind<M> {
+    /// Runs the traced function at `M::empty()`.
+    fn extract(input: Traced<M, A>) -> A {
+        (input.run_traced)(M::empty())
+    }
+}
@@ -0,0 +1,191 @@
+//! # Composite Kinds for the `monadify` library
+//!
+//! This module provides Kind markers that build new type constructors out of
+//! existing ones, so that Functor-family operations can reach through several
+//! layers (or alongside several structures) at once.
+//!
+//! ## Key Components
+//! - [`ComposeKind<F, G>`]: Functor composition. `ComposeKind<F, G>::Of<A>` is
+//!   `F::Of<G::Of<A>>`, e.g. `Vec<Option<A>>` for `ComposeKind<VecKind, OptionKind>`.
+//!   It is a `Functor` when both `F` and `G` are, and an `Apply`/`Applicative`
+//!   when both components are.
+//! - [`ProductKind<F, G>`]: The product of two Kinds. `ProductKind<F, G>::Of<A>` is
+//!   the pair `(F::Of<A>, G::Of<A>)`; every operation is performed on both sides.
+//! - [`CoproductKind<F, G>`]: The coproduct (sum) of two Kinds.
+//!   `CoproductKind<F, G>::Of<A>` is `Result<F::Of<A>, G::Of<A>>`, where `Ok` holds the
+//!   `F` side and `Err` holds the `G` side (mirroring how [`crate::profunctor::Choice`]
+//!   uses `Result` as the sum type). It is a `Functor` only.
+//!
+//! ## Example
+//! ```
+//! use monadify::compose::ComposeKind;
+//! use monadify::functor::kind::Functor;
+//! use monadify::kind_based::kind::{OptionKind, VecKind};
+//!
+//! type VecOption = ComposeKind<VecKind, OptionKind>;
+//!
+//! let values: Vec<Option<i32>> = vec![Some(1), None, Some(3)];
+//! let doubled = VecOption::map(values, |x: i32| x * 2);
+//! assert_eq!(doubled, vec![Some(2), None, Some(6)]);
+//! ```
+
+use crate::applicative::kind::Applicative;
+use crate::apply::kind::Apply;
+use crate::function::CFn;
+use crate::functor::kind::Functor;
+use crate::kind_based::kind::Kind;
+use std::marker::PhantomData;
+use std::rc::Rc;
+
+/// Kind marker for the composition of two type constructors `F` and `G`.
+///
+/// Implements [`Kind`] such that `ComposeKind<F, G>::Of<A>` resolves to `F::Of<G::Of<A>>`.
+/// `F` is the outer layer and `G` the inner one.
+#[derive(Default)]
+pub struct ComposeKind<F, G>(PhantomData<(F, G)>);
+
+impl<F: Kind, G: Kind> Kind for ComposeKind<F, G> {
+    type Of<A> = F::Of<G::Of<A>>;
+}
+
+/// Kind marker for the product of two type constructors `F` and `G`.
+///
+/// Implements [`Kind`] such that `ProductKind<F, G>::Of<A>` resolves to
+/// `(F::Of<A>, G::Of<A>)`.
+#[derive(Default)]
+pub struct ProductKind<F, G>(PhantomData<(F, G)>);
+
+impl<F: Kind, G: Kind> Kind for ProductKind<F, G> {
+    type Of<A> = (F::Of<A>, G::Of<A>);
+}
+
+/// Kind marker for the coproduct (sum) of two type constructors `F` and `G`.
+///
+/// Implements [`Kind`] such that `CoproductKind<F, G>::Of<A>` resolves to
+/// `Result<F::Of<A>, G::Of<A>>`. `Ok` carries the `F` side, `Err` the `G` side.
+#[derive(Default)]
+pub struct CoproductKind<F, G>(PhantomData<(F, G)>);
+
+impl<F: Kind, G: Kind> Kind for CoproductKind<F, G> {
+    type Of<A> = Result<F::Of<A>, G::Of<A>>;
+}
+
+// --- ComposeKind ---
+
+impl<F, G, A, B> Functor<A, B> for ComposeKind<F, G>
+where
+    F: Functor<G::Of<A>, G::Of<B>>,
+    G: Functor<A, B>,
+{
+    /// Maps `func` through both layers: `F::map(input, |ga| G::map(ga, func))`.
+    fn map(input: Self::Of<A>, func: impl FnMut(A) -> B + Clone + 'static) -> Self::Of<B> {
+        F::map(input, move |ga: G::Of<A>| G::map(ga, func.clone()))
+    }
+}
+
+impl<F, G, A, B> Apply<A, B> for ComposeKind<F, G>
+where
+    F: Apply<G::Of<A>, G::Of<B>> + Functor<G::Of<CFn<A, B>>, CFn<G::Of<A>, G::Of<B>>>,
+    G: Apply<A, B>
+        + Functor<CFn<A, B>, Rc<CFn<A, B>>>
+        + Functor<Rc<CFn<A, B>>, CFn<A, B>>
+        + 'static,
+    A: 'static,
+    B: 'static,
+    G::Of<A>: 'static,
+    G::Of<B>: 'static,
+    G::Of<CFn<A, B>>: 'static,
+    G::Of<Rc<CFn<A, B>>>: Clone + 'static,
+{
+    /// Applies the inner functions with `G::apply`, sequenced by `F::apply`.
+    ///
+    /// Each inner function container `G::Of<CFn<A, B>>` is turned into a
+    /// `CFn<G::Of<A>, G::Of<B>>`. Because `F::apply` may call that function more than
+    /// once (e.g. for `Vec`), the wrapped functions are shared through `Rc` so the
+    /// container can be rebuilt for every call.
+    fn apply(value_container: Self::Of<A>, function_container: Self::Of<CFn<A, B>>) -> Self::Of<B> {
+        let lifted = F::map(function_container, |g_fn: G::Of<CFn<A, B>>| {
+            let shared: G::Of<Rc<CFn<A, B>>> = G::map(g_fn, Rc::new);
+            CFn::new(move |ga: G::Of<A>| {
+                let g_fn = G::map(shared.clone(), |f: Rc<CFn<A, B>>| {
+                    CFn::new(move |a: A| f.call(a))
+                });
+                G::apply(ga, g_fn)
+            })
+        });
+        F::apply(value_container, lifted)
+    }
+}
+
+impl<F, G, T> Applicative<T> for ComposeKind<F, G>
+where
+    F: Applicative<G::Of<T>>,
+    G: Applicative<T>,
+    T: 'static,
+    G::Of<T>: 'static,
+    Self: Apply<T, T>,
+    Self: Kind<Of<T> = F::Of<G::Of<T>>>, // Keeps `Self::Of<T>` normalizable under the `Apply` bound
+{
+    /// Lifts a value into both layers: `F::pure(G::pure(value))`.
+    fn pure(value: T) -> Self::Of<T> {
+        F::pure(G::pure(value))
+    }
+}
+
+// --- ProductKind ---
+
+impl<F, G, A, B> Functor<A, B> for ProductKind<F, G>
+where
+    F: Functor<A, B>,
+    G: Functor<A, B>,
+{
+    /// Maps `func` over both components of the pair.
+    fn map(input: Self::Of<A>, func: impl FnMut(A) -> B + Clone + 'static) -> Self::Of<B> {
+        let (fa, ga) = input;
+        (F::map(fa, func.clone()), G::map(ga, func))
+    }
+}
+
+impl<F, G, A, B> Apply<A, B> for ProductKind<F, G>
+where
+    F: Apply<A, B>,
+    G: Apply<A, B>,
+    A: 'static,
+    B: 'static,
+{
+    /// Applies component-wise: the `F` functions to the `F` values and the `G`
+    /// functions to the `G` values.
+    fn apply(value_container: Self::Of<A>, function_container: Self::Of<CFn<A, B>>) -> Self::Of<B> {
+        let (fa, ga) = value_container;
+        let (f_fn, g_fn) = function_container;
+        (F::apply(fa, f_fn), G::apply(ga, g_fn))
+    }
+}
+
+impl<F, G, T> Applicative<T> for ProductKind<F, G>
+where
+    F: Applicative<T>,
+    G: Applicative<T>,
+    T: Clone + 'static, // The value is lifted into both components
+{
+    /// Lifts a value into both components: `(F::pure(value), G::pure(value))`.
+    fn pure(value: T) -> Self::Of<T> {
+        (F::pure(value.clone()), G::pure(value))
+    }
+}
+
+// --- CoproductKind ---
+
+impl<F, G, A, B> Functor<A, B> for CoproductKind<F, G>
+where
+    F: Functor<A, B>,
+    G: Functor<A, B>,
+{
+    /// Maps `func` over whichever side is present.
+    fn map(input: Self::Of<A>, func: impl FnMut(A) -> B + Clone + 'static) -> Self::Of<B> {
+        match input {
+            Ok(fa) => Ok(F::map(fa, func)),
+            Err(ga) => Err(G::map(ga, func)),
+        }
+    }
+}
@@ -0,0 +1,110 @@
+//! # Const Functor for the `monadify` library
+//!
+//! `Const<C, A>` holds a value of type `C` and carries `A` only as a phantom type.
+//! Mapping over it changes the phantom type and leaves the stored value alone.
+//!
+//! When `C` is a [`Monoid`], `Const<C, _>` is also an `Applicative`: `pure` stores
+//! `C::empty()` and `apply` combines the two stored values. This lets a traversal
+//! *accumulate* a summary instead of rebuilding a structure, which is how
+//! [`crate::traversable::fold_map_default`] derives folding from `traverse`.
+//!
+//! ## Key Components
+//! - [`Const<C, A>`]: The wrapper struct holding a value of type `C`.
+//! - [`ConstKind<C>`]: The Kind marker for `Const<C, _>`.
+//!
+//! ## Example
+//! ```
+//! use monadify::constant::{Const, ConstKind};
+//! use monadify::functor::kind::Functor;
+//! use monadify::apply::kind::Apply;
+//! use monadify::function::CFn;
+//!
+//! let c: Const<String, i32> = Const::new("kept".to_string());
+//! let mapped: Const<String, bool> = ConstKind::map(c, |x: i32| x > 0);
+//! assert_eq!(mapped.0, "kept");
+//!
+//! let f: Const<String, CFn<i32, i32>> = Const::new("a".to_string());
+//! let v: Const<String, i32> = Const::new("b".to_string());
+//! assert_eq!(ConstKind::apply(v, f).0, "ab");
+//! ```
+
+use crate::applicative::kind::Applicative;
+use crate::apply::kind::Apply;
+use crate::function::CFn;
+use crate::functor::kind::Functor;
+use crate::kind_based::kind::Kind;
+use crate::monoid::Monoid;
+use std::fmt;
+use std::marker::PhantomData;
+
+/// A wrapper holding a value of type `C`, with `A` as a phantom type parameter.
+///
+/// The trait implementations (`Clone`, `PartialEq`, ...) only depend on `C`, so
+/// `Const<C, A>` can be compared or cloned whatever `A` is.
+pub struct Const<C, A>(pub C, PhantomData<A>);
+
+impl<C, A> Const<C, A> {
+    /// Creates a new `Const` holding `value`.
+    pub fn new(value: C) -> Self {
+        Const(value, PhantomData)
+    }
+
+    /// Consumes the `Const` and returns the stored value.
+    pub fn get_const(self) -> C {
+        self.0
+    }
+}
+
+impl<C: Clone, A> Clone for Const<C, A> {
+    fn clone(&self) -> Self {
+        Const::new(self.0.clone())
+    }
+}
+
+impl<C: Copy, A> Copy for Const<C, A> {}
+
+impl<C: PartialEq, A> PartialEq for Const<C, A> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
+impl<C: Eq, A> Eq for Const<C, A> {}
+
+impl<C: fmt::Debug, A> fmt::Debug for Const<C, A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("Const").field(&self.0).finish()
+    }
+}
+
+/// The Kind marker for `Const<C, _>`, where `C` (the stored type) is fixed.
+///
+/// Implements [`Kind`] such that `ConstKind<C>::Of<A>` resolves to `Const<C, A>`.
+#[derive(Default)]
+pub struct ConstKind<C>(PhantomData<C>);
+
+impl<C> Kind for ConstKind<C> {
+    type Of<A> = Const<C, A>;
+}
+
+impl<C, A, B> Functor<A, B> for ConstKind<C> {
+    /// Changes the phantom type; `func` is never called.
+    fn map(input: Const<C, A>, _func: impl FnMut(A) -> B + Clone + 'static) -> Const<C, B> {
+        Const::new(input.0)
+    }
+}
+
+impl<C: Monoid, A: 'static, B: 'static> Apply<A, B> for ConstKind<C> {
+    /// Combines the stored values, the function side first:
+    /// `Const(f).apply(Const(v))` is `Const(f.combine(v))`.
+    fn apply(value_container: Const<C, A>, function_container: Const<C, CFn<A, B>>) -> Const<C, B> {
+        Const::new(function_container.0.combine(value_container.0))
+    }
+}
+
+impl<C: Monoid, T: 'static> Applicative<T> for ConstKind<C> {
+    /// Ignores `value` and stores `C::empty()`.
+    fn pure(_value: T) -> Const<C, T> {
+        Const::new(C::empty())
+    }
+}
@@ -0,0 +1,447 @@
+//! # Kind-based Contravariant functors for the `monadify` library
+//!
+//! A `Functor` produces values, so it maps *outputs* (`A -> B` turns `F::Of<A>` into
+//! `F::Of<B>`). A contravariant functor *consumes* values, so it maps *inputs*
+//! in the opposite direction: given `B -> A`, a consumer of `A` becomes a consumer of `B`.
+//! Predicates, comparators and serializers are all contravariant.
+//!
+//! This module defines:
+//! - [`Contravariant`]: Provides `contramap`.
+//! - [`Divide`] / [`Divisible`]: Combine consumers of the parts of a product
+//!   (`divide`), with a consumer that accepts anything (`conquer`). These mirror
+//!   `Apply` / `Applicative`.
+//! - [`Decide`] / [`Decidable`]: Combine consumers of the alternatives of a sum
+//!   (`choose`), with a consumer of the impossible type (`lose`). As in
+//!   [`crate::profunctor::Choice`], sums are represented with `Result`.
+//!
+//! ## Key Components
+//! - [`Predicate<A>`] / [`PredicateKind`]: a test `A -> bool`.
+//! - [`Comparison<A>`] / [`ComparisonKind`]: an ordering `(A, A) -> Ordering`.
+//! - [`Equivalence<A>`] / [`EquivalenceKind`]: an equivalence relation `(A, A) -> bool`.
+//! - [`Op<R, A>`] / [`OpKind<R>`]: a function `A -> R` viewed as a consumer of `A`.
+//!
+//! ## Contravariant Laws
+//! 1.  **Identity**: `F::contramap(x, |a| a) == x`.
+//! 2.  **Composition**: `F::contramap(F::contramap(x, f), g) == F::contramap(x, |c| f(g(c)))`.
+//!
+//! ## Example
+//! ```
+//! use monadify::contravariant::{Contravariant, Divide, Predicate, PredicateKind};
+//!
+//! let is_even: Predicate<i32> = Predicate::new(|x: i32| x % 2 == 0);
+//! let has_even_length: Predicate<String> = PredicateKind::contramap(is_even, |s: String| s.len() as i32);
+//! assert!(has_even_length.test("ab".to_string()));
+//!
+//! let is_short: Predicate<String> = Predicate::new(|s: String| s.len() < 5);
+//! let is_adult: Predicate<u32> = Predicate::new(|age: u32| age >= 18);
+//! // A person is accepted if both the name and age predicates hold.
+//! let accept: Predicate<(String, u32)> = PredicateKind::divide(|p: (String, u32)| p, is_short, is_adult);
+//! assert!(accept.test(("Ann".to_string(), 30)));
+//! assert!(!accept.test(("Ann".to_string(), 12)));
+//! ```
+
+use crate::function::CFn;
+use crate::kind_based::kind::{Kind, Kind1};
+use crate::monoid::{Monoid, Semigroup};
+use std::cmp::Ordering;
+use std::convert::Infallible;
+use std::marker::PhantomData;
+
+/// Represents a Kind-encoded consumer whose input can be mapped contravariantly.
+///
+/// `Self` is the Kind marker (e.g., [`PredicateKind`]). Given `Self::Of<A>`
+/// (a consumer of `A`) and a function `B -> A`, `contramap` produces `Self::Of<B>`.
+pub trait Contravariant<A, B>: Kind1 {
+    /// Pre-composes `func` with the consumer.
+    ///
+    /// # Parameters
+    /// - `input`: The consumer of `A`.
+    /// - `func`: A function `B -> A` adapting the new input type to the old one.
+    ///
+    /// # Returns
+    /// A consumer of `B`.
+    fn contramap(input: Self::Of<A>, func: impl FnMut(B) -> A + Clone + 'static) -> Self::Of<B>;
+}
+
+/// Combines consumers of `B` and `C` into a consumer of `A`, by splitting each `A`
+/// into a pair `(B, C)`. This is the contravariant analogue of `Apply`.
+pub trait Divide<A, B, C>: Kind1 {
+    /// Splits the input with `split` and feeds the parts to `fb` and `fc`.
+    fn divide(
+        split: impl FnMut(A) -> (B, C) + Clone + 'static,
+        fb: Self::Of<B>,
+        fc: Self::Of<C>,
+    ) -> Self::Of<A>;
+}
+
+/// A [`Divide`] with a neutral consumer, `conquer`, that accepts any input.
+/// This is the contravariant analogue of `Applicative`.
+///
+/// `conquer` is the identity for `divide`:
+/// `divide(|a| (a, a), x, conquer()) == x`.
+pub trait Divisible<A>: Divide<A, A, A> {
+    /// A consumer that ignores its input.
+    fn conquer() -> Self::Of<A>;
+}
+
+/// Combines consumers of `B` and `C` into a consumer of `A`, by deciding for each `A`
+/// which of the two it should be handed to.
+///
+/// `choose` maps `Ok(b)` to `fb` and `Err(c)` to `fc`.
+pub trait Decide<A, B, C>: Divide<A, B, C> {
+    /// Classifies the input with `decide` and feeds it to `fb` or `fc`.
+    fn choose(
+        decide: impl FnMut(A) -> Result<B, C> + Clone + 'static,
+        fb: Self::Of<B>,
+        fc: Self::Of<C>,
+    ) -> Self::Of<A>;
+}
+
+/// A [`Decide`] with `lose`, which builds a consumer from a proof that its input
+/// can never exist (a function into [`Infallible`]).
+pub trait Decidable<A>: Decide<A, A, A> + Divisible<A> {
+    /// A consumer of an uninhabited input.
+    fn lose(absurd: impl FnMut(A) -> Infallible + Clone + 'static) -> Self::Of<A>;
+}
+
+// --- Predicate ---
+
+/// A predicate on `A`, i.e. a function `A -> bool`.
+pub struct Predicate<A>(pub CFn<A, bool>);
+
+impl<A> Predicate<A> {
+    /// Creates a new `Predicate` from a function `A -> bool`.
+    pub fn new<F>(f: F) -> Self
+    where
+        F: Fn(A) -> bool + 'static,
+    {
+        Predicate(CFn::new(f))
+    }
+
+    /// Runs the predicate on `a`.
+    pub fn test(&self, a: A) -> bool {
+        self.0.call(a)
+    }
+}
+
+/// The Kind marker for [`Predicate`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct PredicateKind;
+
+impl Kind for PredicateKind {
+    type Of<A> = Predicate<A>;
+}
+
+impl<A: 'static, B: 'static> Contravariant<A, B> for PredicateKind {
+    fn contramap(input: Predicate<A>, func: impl FnMut(B) -> A + Clone + 'static) -> Predicate<B> {
+        Predicate::new(move |b: B| input.test(func.clone()(b)))
+    }
+}
+
+impl<A: 'static, B: 'static, C: 'static> Divide<A, B, C> for PredicateKind {
+    /// Holds when both parts satisfy their predicates.
+    fn divide(
+        split: impl FnMut(A) -> (B, C) + Clone + 'static,
+        fb: Predicate<B>,
+        fc: Predicate<C>,
+    ) -> Predicate<A> {
+        Predicate::new(move |a: A| {
+            let (b, c) = split.clone()(a);
+            fb.test(b) && fc.test(c)
+        })
+    }
+}
+
+impl<A: 'static> Divisible<A> for PredicateKind {
+    /// Always holds.
+    fn conquer() -> Predicate<A> {
+        Predicate::new(|_: A| true)
+    }
+}
+
+impl<A: 'static, B: 'static, C: 'static> Decide<A, B, C> for PredicateKind {
+    fn choose(
+        decide: impl FnMut(A) -> Result<B, C> + Clone + 'static,
+        fb: Predicate<B>,
+        fc: Predicate<C>,
+    ) -> Predicate<A> {
+        Predicate::new(move |a: A| match decide.clone()(a) {
+            Ok(b) => fb.test(b),
+            Err(c) => fc.test(c),
+        })
+    }
+}
+
+impl<A: 'static> Decidable<A> for PredicateKind {
+    fn lose(absurd: impl FnMut(A) -> Infallible + Clone + 'static) -> Predicate<A> {
+        Predicate::new(move |a: A| match absurd.clone()(a) {})
+    }
+}
+
+// --- Comparison ---
+
+/// A total ordering on `A`, i.e. a function `(A, A) -> Ordering`.
+pub struct Comparison<A>(pub CFn<(A, A), Ordering>);
+
+impl<A> Comparison<A> {
+    /// Creates a new `Comparison` from a function `(A, A) -> Ordering`.
+    pub fn new<F>(f: F) -> Self
+    where
+        F: Fn(A, A) -> Ordering + 'static,
+    {
+        Comparison(CFn::new(move |(x, y)| f(x, y)))
+    }
+
+    /// The comparison given by `A`'s `Ord` instance.
+    pub fn by_ord() -> Self
+    where
+        A: Ord,
+    {
+        Comparison::new(|x: A, y: A| x.cmp(&y))
+    }
+
+    /// Compares `x` with `y`.
+    pub fn compare(&self, x: A, y: A) -> Ordering {
+        self.0.call((x, y))
+    }
+}
+
+/// The Kind marker for [`Comparison`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct ComparisonKind;
+
+impl Kind for ComparisonKind {
+    type Of<A> = Comparison<A>;
+}
+
+impl<A: 'static, B: 'static> Contravariant<A, B> for ComparisonKind {
+    /// Compares the images of both inputs under `func`.
+    fn contramap(
+        input: Comparison<A>,
+        func: impl FnMut(B) -> A + Clone + 'static,
+    ) -> Comparison<B> {
+        Comparison::new(move |x: B, y: B| {
+            let mut func = func.clone();
+            input.compare(func(x), func(y))
+        })
+    }
+}
+
+impl<A: 'static, B: 'static, C: 'static> Divide<A, B, C> for ComparisonKind {
+    /// Lexicographic ordering: compare the `B` parts first, then the `C` parts.
+    fn divide(
+        split: impl FnMut(A) -> (B, C) + Clone + 'static,
+        fb: Comparison<B>,
+        fc: Comparison<C>,
+    ) -> Comparison<A> {
+        Comparison::new(move |x: A, y: A| {
+            let mut split = split.clone();
+            let (xb, xc) = split(x);
+            let (yb, yc) = split(y);
+            fb.compare(xb, yb).then_with(|| fc.compare(xc, yc))
+        })
+    }
+}
+
+impl<A: 'static> Divisible<A> for ComparisonKind {
+    /// Considers all inputs equal.
+    fn conquer() -> Comparison<A> {
+        Comparison::new(|_: A, _: A| Ordering::Equal)
+    }
+}
+
+impl<A: 'static, B: 'static, C: 'static> Decide<A, B, C> for ComparisonKind {
+    /// Compares within the same alternative; every `Ok` input sorts before every `Err` one.
+    fn choose(
+        decide: impl FnMut(A) -> Result<B, C> + Clone + 'static,
+        fb: Comparison<B>,
+        fc: Comparison<C>,
+    ) -> Comparison<A> {
+        Comparison::new(move |x: A, y: A| {
+            let mut decide = decide.clone();
+            match (decide(x), decide(y)) {
+                (Ok(xb), Ok(yb)) => fb.compare(xb, yb),
+                (Err(xc), Err(yc)) => fc.compare(xc, yc),
+                (Ok(_), Err(_)) => Ordering::Less,
+                (Err(_), Ok(_)) => Ordering::Greater,
+            }
+        })
+    }
+}
+
+impl<A: 'static> Decidable<A> for ComparisonKind {
+    fn lose(absurd: impl FnMut(A) -> Infallible + Clone + 'static) -> Comparison<A> {
+        Comparison::new(move |x: A, _: A| match absurd.clone()(x) {})
+    }
+}
+
+// --- Equivalence ---
+
+/// An equivalence relation on `A`, i.e. a function `(A, A) -> bool`.
+pub struct Equivalence<A>(pub CFn<(A, A), bool>);
+
+impl<A> Equivalence<A> {
+    /// Creates a new `Equivalence` from a function `(A, A) -> bool`.
+    pub fn new<F>(f: F) -> Self
+    where
+        F: Fn(A, A) -> bool + 'static,
+    {
+        Equivalence(CFn::new(move |(x, y)| f(x, y)))
+    }
+
+    /// The equivalence given by `A`'s `PartialEq` instance.
+    pub fn by_eq() -> Self
+    where
+        A: PartialEq,
+    {
+        Equivalence::new(|x: A, y: A| x == y)
+    }
+
+    /// Returns whether `x` and `y` are equivalent.
+    pub fn equivalent(&self, x: A, y: A) -> bool {
+        self.0.call((x, y))
+    }
+}
+
+/// The Kind marker for [`Equivalence`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct EquivalenceKind;
+
+impl Kind for EquivalenceKind {
+    type Of<A> = Equivalence<A>;
+}
+
+impl<A: 'static, B: 'static> Contravariant<A, B> for EquivalenceKind {
+    /// Two inputs are equivalent when their images under `func` are.
+    fn contramap(
+        input: Equivalence<A>,
+        func: impl FnMut(B) -> A + Clone + 'static,
+    ) -> Equivalence<B> {
+        Equivalence::new(move |x: B, y: B| {
+            let mut func = func.clone();
+            input.equivalent(func(x), func(y))
+        })
+    }
+}
+
+impl<A: 'static, B: 'static, C: 'static> Divide<A, B, C> for EquivalenceKind {
+    /// Two inputs are equivalent when both of their parts are.
+    fn divide(
+        split: impl FnMut(A) -> (B, C) + Clone + 'static,
+        fb: Equivalence<B>,
+        fc: Equivalence<C>,
+    ) -> Equivalence<A> {
+        Equivalence::new(move |x: A, y: A| {
+            let mut split = split.clone();
+            let (xb, xc) = split(x);
+            let (yb, yc) = split(y);
+            fb.equivalent(xb, yb) && fc.equivalent(xc, yc)
+        })
+    }
+}
+
+impl<A: 'static> Divisible<A> for EquivalenceKind {
+    /// Considers all inputs equivalent.
+    fn conquer() -> Equivalence<A> {
+        Equivalence::new(|_: A, _: A| true)
+    }
+}
+
+impl<A: 'static, B: 'static, C: 'static> Decide<A, B, C> for EquivalenceKind {
+    /// Inputs are equivalent only if they fall in the same alternative and are
+    /// equivalent there.
+    fn choose(
+        decide: impl FnMut(A) -> Result<B, C> + Clone + 'static,
+        fb: Equivalence<B>,
+        fc: Equivalence<C>,
+    ) -> Equivalence<A> {
+        Equivalence::new(move |x: A, y: A| {
+            let mut decide = decide.clone();
+            match (decide(x), decide(y)) {
+                (Ok(xb), Ok(yb)) => fb.equivalent(xb, yb),
+                (Err(xc), Err(yc)) => fc.equivalent(xc, yc),
+                _ => false,
+            }
+        })
+    }
+}
+
+impl<A: 'static> Decidable<A> for EquivalenceKind {
+    fn lose(absurd: impl FnMut(A) -> Infallible + Clone + 'static) -> Equivalence<A> {
+        Equivalence::new(move |x: A, _: A| match absurd.clone()(x) {})
+    }
+}
+
+// --- Op ---
+
+/// A function `A -> R` viewed as a consumer of `A` (the "flipped" function type).
+pub struct Op<R, A>(pub CFn<A, R>);
+
+impl<R, A> Op<R, A> {
+    /// Creates a new `Op` from a function `A -> R`.
+    pub fn new<F>(f: F) -> Self
+    where
+        F: Fn(A) -> R + 'static,
+    {
+        Op(CFn::new(f))
+    }
+
+    /// Runs the function on `a`.
+    pub fn call(&self, a: A) -> R {
+        self.0.call(a)
+    }
+}
+
+/// The Kind marker for `Op<R, _>`, where the result type `R` is fixed.
+#[derive(Default)]
+pub struct OpKind<R>(PhantomData<R>);
+
+impl<R> Kind for OpKind<R> {
+    type Of<A> = Op<R, A>;
+}
+
+impl<R: 'static, A: 'static, B: 'static> Contravariant<A, B> for OpKind<R> {
+    fn contramap(input: Op<R, A>, func: impl FnMut(B) -> A + Clone + 'static) -> Op<R, B> {
+        Op::new(move |b: B| input.call(func.clone()(b)))
+    }
+}
+
+impl<R: Semigroup + 'static, A: 'static, B: 'static, C: 'static> Divide<A, B, C> for OpKind<R> {
+    /// Runs both functions on their parts and combines the results.
+    fn divide(
+        split: impl FnMut(A) -> (B, C) + Clone + 'static,
+        fb: Op<R, B>,
+        fc: Op<R, C>,
+    ) -> Op<R, A> {
+        Op::new(move |a: A| {
+            let (b, c) = split.clone()(a);
+            fb.call(b).combine(fc.call(c))
+        })
+    }
+}
+
+impl<R: Monoid + 'static, A: 'static> Divisible<A> for OpKind<R> {
+    /// Ignores the input and returns `R::empty()`.
+    fn conquer() -> Op<R, A> {
+        Op::new(|_: A| R::empty())
+    }
+}
+
+impl<R: Semigroup + 'static, A: 'static, B: 'static, C: 'static> Decide<A, B, C> for OpKind<R> {
+    fn choose(
+        decide: impl FnMut(A) -> Result<B, C> + Clone + 'static,
+        fb: Op<R, B>,
+        fc: Op<R, C>,
+    ) -> Op<R, A> {
+        Op::new(move |a: A| match decide.clone()(a) {
+            Ok(b) => fb.call(b),
+            Err(c) => fc.call(c),
+        })
+    }
+}
+
+impl<R: Monoid + 'static, A: 'static> Decidable<A> for OpKind<R> {
+    fn lose(absurd: impl FnMut(A) -> Infallible + Clone + 'static) -> Op<R, A> {
+        Op::new(move |a: A| match absurd.clone()(a) {})
+    }
+}
@@ -0,0 +1,188 @@
+//! # Extension methods for the `monadify` library
+//!
+//! The Kind traits are implemented on marker types, so calling them directly means naming
+//! the marker: `OptionKind::map(x, f)`, or `<OptionKind as Functor<i32, String>>::map(x, f)`
+//! when inference needs help. The traits in this module put those operations on the values
+//! themselves, finding the marker through [`HasKind`]:
+//!
+//! - [`FunctorExt::fmap`]: [`Functor::map`].
+//! - [`ApplyExt::ap`]: [`Apply::apply`], with the value first and the wrapped functions second.
+//! - [`BindExt::flat_map`]: [`Bind::bind`].
+//! - [`Pipe::pipe`]: passes any value to a function, so a chain can continue through plain
+//!   functions (`x.pipe(f)` is `f(x)`).
+//!
+//! They are implemented for every [`HasKind`] type: `Option`, `Vec`, `Result<_, E>`,
+//! [`crate::identity::kind::Identity`], [`crate::transformers::reader::ReaderT`] and the
+//! function wrappers `CFn`, `CFnOnce`, `CFnMut` and `RcFn` (as Readers of their input).
+//!
+//! The free functions [`map`], [`apply`], [`bind`] and [`pure_as`] do the same in prefix
+//! form; `pure_as` has no value to inspect, so it reads the Kind from the expected result
+//! type instead (`let x: Option<i32> = pure_as(1)`).
+//!
+//! ## Example
+//! ```
+//! use monadify::function::CFn;
+//! use monadify::ext;
+//! use monadify::{ApplyExt, BindExt, FunctorExt, Pipe};
+//!
+//! let parsed = Some("42")
+//!     .flat_map(|s: &str| s.parse::<i32>().ok())
+//!     .fmap(|n| n * 2)
+//!     .ap(Some(CFn::new(|n: i32| n + 1)));
+//! assert_eq!(parsed, Some(85));
+//!
+//! let total = vec![1, 2, 3]
+//!     .flat_map(|n| vec![n; n as usize])
+//!     .pipe(|v| v.iter().sum::<i32>());
+//! assert_eq!(total, 14);
+//!
+//! let wrapped: Vec<i32> = ext::pure_as(3);
+//! assert_eq!(ext::bind(wrapped, |n| vec![n, -n]), vec![3, -3]);
+//! ```
+
+use crate::applicative::kind::Applicative;
+use crate::apply::kind::Apply;
+use crate::function::CFn;
+use crate::functor::kind::Functor;
+use crate::kind_based::kind::{HasKind, Kind};
+use crate::monad::kind::Bind;
+
+/// `map` as a method: `x.fmap(f)` is `F::map(x, f)` for `x: F::Of<A>`.
+pub trait FunctorExt: HasKind {
+    /// Applies `f` to the value(s) inside `self`, with the Kind inferred from `self`.
+    fn fmap<B>(
+        self,
+        f: impl FnMut(Self::Arg) -> B + Clone + 'static,
+    ) -> <Self::Kind as Kind>::Of<B>
+    where
+        Self::Kind: Functor<Self::Arg, B>;
+}
+
+impl<T: HasKind> FunctorExt for T {
+    fn fmap<B>(self, f: impl FnMut(Self::Arg) -> B + Clone + 'static) -> <Self::Kind as Kind>::Of<B>
+    where
+        Self::Kind: Functor<Self::Arg, B>,
+    {
+        <Self::Kind as Functor<Self::Arg, B>>::map(self, f)
+    }
+}
+
+/// `apply` as a method: `x.ap(ff)` is `F::apply(x, ff)` for `x: F::Of<A>`.
+pub trait ApplyExt: HasKind {
+    /// Applies the function(s) in `ff` to the value(s) inside `self`.
+    fn ap<B>(self, ff: <Self::Kind as Kind>::Of<CFn<Self::Arg, B>>) -> <Self::Kind as Kind>::Of<B>
+    where
+        Self::Kind: Apply<Self::Arg, B>,
+        Self::Arg: 'static,
+        B: 'static;
+}
+
+impl<T: HasKind> ApplyExt for T {
+    fn ap<B>(self, ff: <Self::Kind as Kind>::Of<CFn<Self::Arg, B>>) -> <Self::Kind as Kind>::Of<B>
+    where
+        Self::Kind: Apply<Self::Arg, B>,
+        Self::Arg: 'static,
+        B: 'static,
+    {
+        <Self::Kind as Apply<Self::Arg, B>>::apply(self, ff)
+    }
+}
+
+/// `bind` as a method: `x.flat_map(f)` is `F::bind(x, f)` for `x: F::Of<A>`.
+pub trait BindExt: HasKind {
+    /// Sequences `self` with `f`, which returns the next computation of the same Kind.
+    fn flat_map<B>(
+        self,
+        f: impl FnMut(Self::Arg) -> <Self::Kind as Kind>::Of<B> + Clone + 'static,
+    ) -> <Self::Kind as Kind>::Of<B>
+    where
+        Self::Kind: Bind<Self::Arg, B>,
+        Self::Arg: 'static,
+        B: 'static;
+}
+
+impl<T: HasKind> BindExt for T {
+    fn flat_map<B>(
+        self,
+        f: impl FnMut(Self::Arg) -> <Self::Kind as Kind>::Of<B> + Clone + 'static,
+    ) -> <Self::Kind as Kind>::Of<B>
+    where
+        Self::Kind: Bind<Self::Arg, B>,
+        Self::Arg: 'static,
+        B: 'static,
+    {
+        <Self::Kind as Bind<Self::Arg, B>>::bind(self, f)
+    }
+}
+
+/// Passes a value to a function in method position, for any type.
+pub trait Pipe: Sized {
+    /// Returns `f(self)`.
+    fn pipe<B>(self, f: impl FnOnce(Self) -> B) -> B {
+        f(self)
+    }
+}
+
+impl<T> Pipe for T {}
+
+// --- Free functions ---
+
+/// Maps `f` over `fa`, with the Kind inferred from `fa`: [`Functor::map`] in prefix form.
+pub fn map<FA, B>(
+    fa: FA,
+    f: impl FnMut(FA::Arg) -> B + Clone + 'static,
+) -> <FA::Kind as Kind>::Of<B>
+where
+    FA: HasKind,
+    FA::Kind: Functor<FA::Arg, B>,
+{
+    <FA::Kind as Functor<FA::Arg, B>>::map(fa, f)
+}
+
+/// Applies the function(s) in `ff` to the value(s) in `fa`: [`Apply::apply`] in prefix form.
+pub fn apply<FA, B>(
+    fa: FA,
+    ff: <FA::Kind as Kind>::Of<CFn<FA::Arg, B>>,
+) -> <FA::Kind as Kind>::Of<B>
+where
+    FA: HasKind,
+    FA::Kind: Apply<FA::Arg, B>,
+    FA::Arg: 'static,
+    B: 'static,
+{
+    <FA::Kind as Apply<FA::Arg, B>>::apply(fa, ff)
+}
+
+/// Sequences `fa` with `f`: [`Bind::bind`] in prefix form.
+pub fn bind<FA, B>(
+    fa: FA,
+    f: impl FnMut(FA::Arg) -> <FA::Kind as Kind>::Of<B> + Clone + 'static,
+) -> <FA::Kind as Kind>::Of<B>
+where
+    FA: HasKind,
+    FA::Kind: Bind<FA::Arg, B>,
+    FA::Arg: 'static,
+    B: 'static,
+{
+    <FA::Kind as Bind<FA::Arg, B>>::bind(fa, f)
+}
+
+/// Lifts `value` into the type `FA` the caller expects: [`Applicative::pure`] with the Kind
+/// taken from the result type.
+///
+/// # Examples
+/// ```
+/// use monadify::ext::pure_as;
+///
+/// let opt: Option<&str> = pure_as("hi");
+/// assert_eq!(opt, Some("hi"));
+/// assert_eq!(pure_as::<Result<i32, String>>(7), Ok(7));
+/// ```
+pub fn pure_as<FA>(value: FA::Arg) -> FA
+where
+    FA: HasKind,
+    FA::Kind: Applicative<FA::Arg>,
+    FA::Arg: 'static,
+{
+    <FA::Kind as Applicative<FA::Arg>>::pure(value)
+}
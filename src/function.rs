@@ -1,4 +1,6 @@
-use std::ops::Deref;
+use std::ops::{Deref, DerefMut};
+use std::rc::Rc;
+use std::sync::Arc;
 
 /// Type alias for a boxed, dynamically dispatched, repeatable closure.
 /// `BFn<A, B>` is equivalent to `Box<dyn Fn(A) -> B + 'static>`.
@@ -10,6 +12,12 @@ type BFn<A, B> = Box<dyn Fn(A) -> B + 'static>;
 /// This represents a heap-allocated closure that can be called at most once.
 type BFnOnce<A, B> = Box<dyn FnOnce(A) -> B + 'static>;
 
+/// Type alias for a boxed, dynamically dispatched, stateful closure.
+/// `BFnMut<A, B>` is equivalent to `Box<dyn FnMut(A) -> B + 'static>`.
+/// This represents a heap-allocated closure that can be called many times and may
+/// update the state it captures on each call.
+type BFnMut<A, B> = Box<dyn FnMut(A) -> B + 'static>;
+
 /// A wrapper around `BFn<A, B>` (a `Box<dyn Fn(A) -> B + 'static>`).
 ///
 /// This struct provides a concrete type for heap-allocated, repeatable closures,
@@ -48,6 +56,85 @@ pub struct CFn<A, B>(pub BFn<A, B>);
 /// ```
 pub struct CFnOnce<A, B>(pub BFnOnce<A, B>);
 
+/// A wrapper around `BFnMut<A, B>` (a `Box<dyn FnMut(A) -> B + 'static>`).
+///
+/// This struct provides a concrete type for stateful closures, such as counters and
+/// accumulators, whose results depend on how often they have been called. Calling one
+/// needs `&mut self`.
+///
+/// # Examples
+/// ```
+/// use monadify::function::CFnMut;
+///
+/// let mut total = 0;
+/// let mut running_sum = CFnMut::new(move |x: i32| {
+///     total += x;
+///     total
+/// });
+/// assert_eq!(running_sum.call_mut(5), 5);
+/// assert_eq!(running_sum.call_mut(10), 15);
+/// ```
+pub struct CFnMut<A, B>(pub BFnMut<A, B>);
+
+/// A reference-counted, repeatable closure: `Rc<dyn Fn(A) -> B + 'static>`.
+///
+/// Unlike [`CFn`], `RcFn` is cheaply `Clone` (cloning shares the same closure), so it can
+/// be captured by other closures that run more than once, or lifted with `pure` and
+/// compared through the Applicative laws.
+///
+/// # Examples
+/// ```
+/// use monadify::function::{CFn, RcFn};
+///
+/// let add_one = RcFn::new(|x: i32| x + 1);
+/// let shared = add_one.clone();
+/// assert_eq!(add_one.call(5), 6);
+/// assert_eq!(shared.call(10), 11);
+///
+/// let twice: CFn<i32, i32> = (add_one.clone() >> add_one).into();
+/// assert_eq!(twice.call(1), 3);
+/// ```
+pub struct RcFn<A, B>(pub Rc<dyn Fn(A) -> B + 'static>);
+
+/// A thread-safe, reference-counted, repeatable closure: `Arc<dyn Fn(A) -> B + Send + Sync + 'static>`.
+///
+/// The `Send + Sync` counterpart of [`RcFn`]: an `ArcFn` can be moved to, shared with and
+/// called from other threads. `ArcFnKind<X>` is a Reader over `X` through the
+/// [`crate::send`] traits.
+///
+/// # Examples
+/// ```
+/// use monadify::function::ArcFn;
+///
+/// let add_one = ArcFn::new(|x: i32| x + 1);
+/// let results: Vec<i32> = std::thread::scope(|s| {
+///     let handles: Vec<_> = (0..3)
+///         .map(|i| {
+///             let f = add_one.clone();
+///             s.spawn(move || f.call(i))
+///         })
+///         .collect();
+///     handles.into_iter().map(|h| h.join().unwrap()).collect()
+/// });
+/// assert_eq!(results, vec![1, 2, 3]);
+/// ```
+pub struct ArcFn<A, B>(pub Arc<dyn Fn(A) -> B + Send + Sync + 'static>);
+
+/// A once-callable closure that can be sent to another thread: `Box<dyn FnOnce(A) -> B + Send + 'static>`.
+///
+/// The `Send` counterpart of [`CFnOnce`].
+///
+/// # Examples
+/// ```
+/// use monadify::function::SendFnOnce;
+///
+/// let name = "worker".to_string();
+/// let greet = SendFnOnce::new(move |n: i32| format!("{name}-{n}"));
+/// let greeting = std::thread::spawn(move || greet.call_once(1)).join().unwrap();
+/// assert_eq!(greeting, "worker-1");
+/// ```
+pub struct SendFnOnce<A, B>(pub Box<dyn FnOnce(A) -> B + Send + 'static>);
+
 impl<A, B> CFn<A, B> {
     /// Creates a new `CFn` by boxing the given closure.
     ///
@@ -78,6 +165,128 @@ impl<A, B> CFn<A, B> {
     }
 }
 
+impl<A, B> CFnMut<A, B> {
+    /// Creates a new `CFnMut` by boxing the given closure.
+    ///
+    /// # Parameters
+    /// - `f`: A closure that implements `FnMut(A) -> B` and is `'static`.
+    ///
+    /// # Returns
+    /// A new `CFnMut<A, B>` instance.
+    pub fn new<F>(f: F) -> Self
+    where
+        F: FnMut(A) -> B + 'static,
+    {
+        CFnMut(Box::new(f))
+    }
+
+    /// Calls the wrapped closure, letting it update its captured state.
+    ///
+    /// # Parameters
+    /// - `arg`: The argument of type `A` to pass to the closure.
+    ///
+    /// # Returns
+    /// The result of type `B` from calling the closure.
+    pub fn call_mut(&mut self, arg: A) -> B {
+        (self.0)(arg)
+    }
+}
+
+impl<A, B> RcFn<A, B> {
+    /// Creates a new `RcFn` by wrapping the given closure in an `Rc`.
+    ///
+    /// # Parameters
+    /// - `f`: A closure that implements `Fn(A) -> B` and is `'static`.
+    ///
+    /// # Returns
+    /// A new `RcFn<A, B>` instance.
+    pub fn new<F>(f: F) -> Self
+    where
+        F: Fn(A) -> B + 'static,
+    {
+        RcFn(Rc::new(f))
+    }
+
+    /// Calls the wrapped closure.
+    ///
+    /// # Parameters
+    /// - `arg`: The argument of type `A` to pass to the closure.
+    ///
+    /// # Returns
+    /// The result of type `B` from calling the closure.
+    pub fn call(&self, arg: A) -> B {
+        (self.0)(arg)
+    }
+}
+
+/// Cloning an `RcFn` shares the wrapped closure; it does not require `A` or `B` to be `Clone`.
+impl<A, B> Clone for RcFn<A, B> {
+    fn clone(&self) -> Self {
+        RcFn(self.0.clone())
+    }
+}
+
+impl<A, B> ArcFn<A, B> {
+    /// Creates a new `ArcFn` by wrapping the given closure in an `Arc`.
+    ///
+    /// # Parameters
+    /// - `f`: A closure that implements `Fn(A) -> B`, is `Send + Sync` and `'static`.
+    ///
+    /// # Returns
+    /// A new `ArcFn<A, B>` instance.
+    pub fn new<F>(f: F) -> Self
+    where
+        F: Fn(A) -> B + Send + Sync + 'static,
+    {
+        ArcFn(Arc::new(f))
+    }
+
+    /// Calls the wrapped closure.
+    ///
+    /// # Parameters
+    /// - `arg`: The argument of type `A` to pass to the closure.
+    ///
+    /// # Returns
+    /// The result of type `B` from calling the closure.
+    pub fn call(&self, arg: A) -> B {
+        (self.0)(arg)
+    }
+}
+
+/// Cloning an `ArcFn` shares the wrapped closure; it does not require `A` or `B` to be `Clone`.
+impl<A, B> Clone for ArcFn<A, B> {
+    fn clone(&self) -> Self {
+        ArcFn(self.0.clone())
+    }
+}
+
+impl<A, B> SendFnOnce<A, B> {
+    /// Creates a new `SendFnOnce` by boxing the given closure.
+    ///
+    /// # Parameters
+    /// - `f`: A closure that implements `FnOnce(A) -> B`, is `Send` and `'static`.
+    ///
+    /// # Returns
+    /// A new `SendFnOnce<A, B>` instance.
+    pub fn new<F>(f: F) -> Self
+    where
+        F: FnOnce(A) -> B + Send + 'static,
+    {
+        SendFnOnce(Box::new(f))
+    }
+
+    /// Calls the wrapped closure, consuming the `SendFnOnce`.
+    ///
+    /// # Parameters
+    /// - `arg`: The argument of type `A` to pass to the closure.
+    ///
+    /// # Returns
+    /// The result of type `B` from calling the closure.
+    pub fn call_once(self, arg: A) -> B {
+        (self.0)(arg)
+    }
+}
+
 impl<A, B> CFnOnce<A, B> {
     /// Creates a new `CFnOnce` by boxing the given closure.
     ///
@@ -128,6 +337,91 @@ impl<A, B> Deref for CFnOnce<A, B> {
     }
 }
 
+/// Allows `CFnMut<A, B>` to be dereferenced to `&Box<dyn FnMut(A) -> B + 'static>`.
+impl<A, B> Deref for CFnMut<A, B> {
+    type Target = BFnMut<A, B>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// Allows `CFnMut<A, B>` to be mutably dereferenced, so `(*cfn_mut)(arg)` can be called.
+impl<A, B> DerefMut for CFnMut<A, B> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+/// Allows `RcFn<A, B>` to be dereferenced to `&Rc<dyn Fn(A) -> B + 'static>`.
+impl<A, B> Deref for RcFn<A, B> {
+    type Target = Rc<dyn Fn(A) -> B + 'static>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// Allows `ArcFn<A, B>` to be dereferenced to `&Arc<dyn Fn(A) -> B + Send + Sync + 'static>`.
+impl<A, B> Deref for ArcFn<A, B> {
+    type Target = Arc<dyn Fn(A) -> B + Send + Sync + 'static>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// Allows `SendFnOnce<A, B>` to be dereferenced to `&Box<dyn FnOnce(A) -> B + Send + 'static>`.
+impl<A, B> Deref for SendFnOnce<A, B> {
+    type Target = Box<dyn FnOnce(A) -> B + Send + 'static>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// Converts a `CFn` into an `RcFn`, moving the boxed closure behind an `Rc`.
+impl<A: 'static, B: 'static> From<CFn<A, B>> for RcFn<A, B> {
+    fn from(f: CFn<A, B>) -> Self {
+        RcFn(Rc::from(f.0))
+    }
+}
+
+/// Converts an `RcFn` into a `CFn` that calls the (possibly shared) closure.
+impl<A: 'static, B: 'static> From<RcFn<A, B>> for CFn<A, B> {
+    fn from(f: RcFn<A, B>) -> Self {
+        CFn::new(move |a| f.call(a))
+    }
+}
+
+/// Converts a `CFn` into a `CFnMut`; a closure without state is also a stateful one.
+impl<A: 'static, B: 'static> From<CFn<A, B>> for CFnMut<A, B> {
+    fn from(f: CFn<A, B>) -> Self {
+        CFnMut(Box::new(f.0))
+    }
+}
+
+/// Converts an `ArcFn` into a `CFn` that calls the (possibly shared) closure.
+impl<A: 'static, B: 'static> From<ArcFn<A, B>> for CFn<A, B> {
+    fn from(f: ArcFn<A, B>) -> Self {
+        CFn::new(move |a| f.call(a))
+    }
+}
+
+/// Converts an `ArcFn` into an `RcFn` that calls the (possibly shared) closure.
+impl<A: 'static, B: 'static> From<ArcFn<A, B>> for RcFn<A, B> {
+    fn from(f: ArcFn<A, B>) -> Self {
+        RcFn::new(move |a| f.call(a))
+    }
+}
+
+/// Converts a `SendFnOnce` into a `CFnOnce`, giving up `Send`.
+impl<A: 'static, B: 'static> From<SendFnOnce<A, B>> for CFnOnce<A, B> {
+    fn from(f: SendFnOnce<A, B>) -> Self {
+        CFnOnce(f.0)
+    }
+}
+
 /// Composes two boxed `Fn` closures.
 /// Given `f: A -> B` and `g: B -> C`, returns a new boxed closure `h: A -> C`
 /// such that `h(x) = g(f(x))`.
@@ -146,6 +440,16 @@ fn compose_fn_once<A: 'static, B: 'static, C: 'static>(
     Box::new(move |x| g(f(x))) // f and g are moved into the closure
 }
 
+/// Composes two boxed `FnMut` closures.
+/// Given `f: A -> B` and `g: B -> C`, returns a new boxed closure `h: A -> C`
+/// such that `h(x) = g(f(x))`. Each call of `h` calls `f` and `g` once, in that order.
+fn compose_fn_mut<A: 'static, B: 'static, C: 'static>(
+    mut f: BFnMut<A, B>,
+    mut g: BFnMut<B, C>,
+) -> BFnMut<A, C> {
+    Box::new(move |x| g(f(x)))
+}
+
 /// Implements `f >> g` (forward composition) for `CFn`.
 /// `(self >> rhs)(x)` is equivalent to `rhs(self(x))`.
 /// `CFn<A,B> >> CFn<B,C>` results in `CFn<A,C>`.
@@ -189,3 +493,83 @@ impl<A: 'static, B: 'static, C: 'static> std::ops::Shl<CFnOnce<A, B>> for CFnOnc
         CFnOnce(compose_fn_once(rhs.0, self.0))
     }
 }
+
+/// Implements `f >> g` (forward composition) for `CFnMut`.
+/// `(self >> rhs)(x)` is equivalent to `rhs(self(x))`.
+/// `CFnMut<A,B> >> CFnMut<B,C>` results in `CFnMut<A,C>`.
+impl<A: 'static, B: 'static, C: 'static> std::ops::Shr<CFnMut<B, C>> for CFnMut<A, B> {
+    type Output = CFnMut<A, C>;
+    fn shr(self, rhs: CFnMut<B, C>) -> Self::Output {
+        CFnMut(compose_fn_mut(self.0, rhs.0))
+    }
+}
+
+/// Implements `g << f` (backward composition) for `CFnMut`.
+/// `(self << rhs)(x)` is equivalent to `self(rhs(x))`.
+/// `CFnMut<B,C> << CFnMut<A,B>` results in `CFnMut<A,C>`.
+impl<A: 'static, B: 'static, C: 'static> std::ops::Shl<CFnMut<A, B>> for CFnMut<B, C> {
+    type Output = CFnMut<A, C>;
+    fn shl(self, rhs: CFnMut<A, B>) -> Self::Output {
+        CFnMut(compose_fn_mut(rhs.0, self.0))
+    }
+}
+
+/// Implements `f >> g` (forward composition) for `RcFn`.
+/// `(self >> rhs)(x)` is equivalent to `rhs(self(x))`.
+/// `RcFn<A,B> >> RcFn<B,C>` results in `RcFn<A,C>`.
+impl<A: 'static, B: 'static, C: 'static> std::ops::Shr<RcFn<B, C>> for RcFn<A, B> {
+    type Output = RcFn<A, C>;
+    fn shr(self, rhs: RcFn<B, C>) -> Self::Output {
+        RcFn::new(move |x| rhs.call(self.call(x)))
+    }
+}
+
+/// Implements `g << f` (backward composition) for `RcFn`.
+/// `(self << rhs)(x)` is equivalent to `self(rhs(x))`.
+/// `RcFn<B,C> << RcFn<A,B>` results in `RcFn<A,C>`.
+impl<A: 'static, B: 'static, C: 'static> std::ops::Shl<RcFn<A, B>> for RcFn<B, C> {
+    type Output = RcFn<A, C>;
+    fn shl(self, rhs: RcFn<A, B>) -> Self::Output {
+        RcFn::new(move |x| self.call(rhs.call(x)))
+    }
+}
+
+/// Implements `f >> g` (forward composition) for `ArcFn`.
+/// `(self >> rhs)(x)` is equivalent to `rhs(self(x))`.
+/// `ArcFn<A,B> >> ArcFn<B,C>` results in `ArcFn<A,C>`.
+impl<A: 'static, B: 'static, C: 'static> std::ops::Shr<ArcFn<B, C>> for ArcFn<A, B> {
+    type Output = ArcFn<A, C>;
+    fn shr(self, rhs: ArcFn<B, C>) -> Self::Output {
+        ArcFn::new(move |x| rhs.call(self.call(x)))
+    }
+}
+
+/// Implements `g << f` (backward composition) for `ArcFn`.
+/// `(self << rhs)(x)` is equivalent to `self(rhs(x))`.
+/// `ArcFn<B,C> << ArcFn<A,B>` results in `ArcFn<A,C>`.
+impl<A: 'static, B: 'static, C: 'static> std::ops::Shl<ArcFn<A, B>> for ArcFn<B, C> {
+    type Output = ArcFn<A, C>;
+    fn shl(self, rhs: ArcFn<A, B>) -> Self::Output {
+        ArcFn::new(move |x| self.call(rhs.call(x)))
+    }
+}
+
+/// Implements `f >> g` (forward composition) for `SendFnOnce`.
+/// `(self >> rhs)(x)` is equivalent to `rhs(self(x))`.
+/// `SendFnOnce<A,B> >> SendFnOnce<B,C>` results in `SendFnOnce<A,C>`.
+impl<A: 'static, B: 'static, C: 'static> std::ops::Shr<SendFnOnce<B, C>> for SendFnOnce<A, B> {
+    type Output = SendFnOnce<A, C>;
+    fn shr(self, rhs: SendFnOnce<B, C>) -> Self::Output {
+        SendFnOnce::new(move |x| rhs.call_once(self.call_once(x)))
+    }
+}
+
+/// Implements `g << f` (backward composition) for `SendFnOnce`.
+/// `(self << rhs)(x)` is equivalent to `self(rhs(x))`.
+/// `SendFnOnce<B,C> << SendFnOnce<A,B>` results in `SendFnOnce<A,C>`.
+impl<A: 'static, B: 'static, C: 'static> std::ops::Shl<SendFnOnce<A, B>> for SendFnOnce<B, C> {
+    type Output = SendFnOnce<A, C>;
+    fn shl(self, rhs: SendFnOnce<A, B>) -> Self::Output {
+        SendFnOnce::new(move |x| self.call_once(rhs.call_once(x)))
+    }
+}
@@ -16,8 +16,10 @@ pub mod kind {
     //! It relies on the [`Kind1`] trait from `crate::kind_based::kind` to relate the
     //! marker `Self` to its concrete type application `Self::Of<T>`.
 
-    use crate::function::{CFn, CFnOnce};
-    use crate::kind_based::kind::{CFnKind, CFnOnceKind, Kind1, OptionKind, ResultKind, VecKind};
+    use crate::function::{CFn, CFnMut, CFnOnce, RcFn};
+    use crate::kind_based::kind::{
+        CFnKind, CFnMutKind, CFnOnceKind, Kind1, OptionKind, RcFnKind, ResultKind, VecKind,
+    };
 
     /// Represents a type constructor that can be mapped over, using the Kind pattern.
     ///
@@ -97,6 +99,18 @@ pub mod kind {
         }
     }
 
+    // Functor impl for RcFnKind (maps over the output type of RcFn)
+    impl<X, A, B> Functor<A, B> for RcFnKind<X>
+    where
+        X: 'static,
+        A: 'static,
+        B: 'static,
+    {
+        fn map(input: Self::Of<A>, func: impl FnMut(A) -> B + Clone + 'static) -> Self::Of<B> {
+            RcFn::new(move |x: X| func.clone()(input.call(x)))
+        }
+    }
+
     // Functor impl for CFnOnceKind (maps over the output type of CFnOnce)
     impl<X, A, B> Functor<A, B> for CFnOnceKind<X>
     where
@@ -109,6 +123,22 @@ pub mod kind {
             CFnOnce::new(move |x: X| func(input.call_once(x)))
         }
     }
+
+    // Functor impl for CFnMutKind (maps over the output type of CFnMut).
+    // The state of both `input` and `func` carries over from one call to the next.
+    impl<X, A, B> Functor<A, B> for CFnMutKind<X>
+    where
+        X: 'static,
+        A: 'static,
+        B: 'static,
+    {
+        fn map(
+            mut input: Self::Of<A>,
+            mut func: impl FnMut(A) -> B + Clone + 'static,
+        ) -> Self::Of<B> {
+            CFnMut::new(move |x: X| func(input.call_mut(x)))
+        }
+    }
 }
 
 // Directly export Kind-based Functor
@@ -45,7 +45,7 @@ pub mod kind {
     use crate::apply::kind as apply_kind; // Renamed hkt to kind
     use crate::function::CFn;
     use crate::functor::kind as functor_kind; // Renamed hkt to kind
-    use crate::kind_based::kind::Kind; // Changed HKT to Kind
+    use crate::kind_based::kind::{HasKind, Kind}; // Changed HKT to Kind
     use crate::monad::kind as monad_kind; // Renamed hkt to kind // For Apply's function container
 
     /// A simple wrapper struct that holds a value of type `A`.
@@ -66,6 +66,11 @@ pub mod kind {
         // Changed HKT to Kind, IdentityHKTMarker to IdentityKind
         type Of<T> = Identity<T>; // Changed Applied to Of
     }
+
+    impl<T> HasKind for Identity<T> {
+        type Kind = IdentityKind;
+        type Arg = T;
+    }
     // Kind1 is implemented by the blanket impl in kind_based/kind.rs for types that impl Kind.
 
     // Kind-based Functor for IdentityKind
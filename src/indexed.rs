@@ -0,0 +1,414 @@
+//! # Indexed optics for the `monadify` library
+//!
+//! An indexed optic gives access to each focus *together with its position*: the index
+//! of a `Vec` element or the key of a map entry.
+//!
+//! Indices are threaded through the [`Indexed<I, P>`] profunctor, which wraps a
+//! profunctor `P` over `(I, A) -> B` and presents it as a profunctor over `A -> B`.
+//! An indexed optic is an [`Optic`] whose inner profunctor is `Indexed<I, PI>`.
+//!
+//! ## Key Components
+//! - [`Indexed<I, P>`]: The indexed profunctor wrapper.
+//! - [`itraversed`], [`itraversed_hash_map`], [`itraversed_btree_map`]: Indexed traversals
+//!   of every element of a `Vec` (indexed by `usize`) or every value of a map (indexed by key).
+//! - [`iover`] and [`ito_list_of`]: Modify or collect the foci along with their indices.
+//! - [`at`]: A `Lens` onto the `Option` value stored under a key of a map.
+//! - [`ix`]: A `Traversal` onto the element at an index of a `Vec`, if present.
+//!
+//! ## Example
+//! ```
+//! use monadify::indexed::{at, iover, ito_list_of, itraversed};
+//! use monadify::profunctor::{set, view};
+//! use std::collections::BTreeMap;
+//!
+//! let scaled = iover(itraversed(), |i: usize, x: i32| x * i as i32, vec![5, 5, 5]);
+//! assert_eq!(scaled, vec![0, 5, 10]);
+//!
+//! let pairs = ito_list_of(itraversed::<_, _, char, char>(), vec!['a', 'b']);
+//! assert_eq!(pairs, vec![(0, 'a'), (1, 'b')]);
+//!
+//! let ages = BTreeMap::from([("ann", 30)]);
+//! assert_eq!(view(at("ann").0, ages.clone()), Some(30));
+//! assert_eq!(view(at("bob").0, ages.clone()), None);
+//! let ages = set(at("bob").0, Some(25), ages);
+//! assert_eq!(ages.get("bob"), Some(&25));
+//! ```
+
+use crate::applicative::kind::Applicative;
+use crate::function::{CFn, RcFn};
+use crate::functor::kind::Functor;
+use crate::kind_based::kind::{Kind, VecKind};
+use crate::profunctor::{
+    lens, traversal, Forget, Lens, Optic, Profunctor, Strong, Traversal, Traversing, Wander,
+};
+use crate::traversable::Traversable;
+use std::collections::{BTreeMap, HashMap};
+use std::hash::Hash;
+use std::marker::PhantomData;
+
+/// A profunctor over `A -> B` built from a profunctor `P` over `(I, A) -> B`.
+///
+/// Running an indexed optic with `Indexed<I, P>` hands every focus to `P` paired with
+/// its index `I`. For example, `Indexed<usize, CFn<(usize, A), B>>` is a function
+/// that sees each element's position.
+pub struct Indexed<I, P>(pub P, PhantomData<I>);
+
+impl<I, P> Indexed<I, P> {
+    /// Wraps a profunctor over `(I, A) -> B`.
+    pub fn new(p: P) -> Self {
+        Indexed(p, PhantomData)
+    }
+}
+
+impl<I: 'static, P, B, C> Profunctor<B, C> for Indexed<I, P>
+where
+    P: Profunctor<(I, B), C>,
+{
+    type Pro<T, U> = Indexed<I, P::Pro<(I, T), U>>;
+
+    /// Maps the focus, leaving its index untouched.
+    fn dimap<A, D, A2B, C2D>(self, a2b: A2B, c2d: C2D) -> Self::Pro<A, D>
+    where
+        A2B: Fn(A) -> B + 'static,
+        C2D: Fn(C) -> D + 'static,
+        A: 'static,
+        B: 'static,
+        C: 'static,
+        D: 'static,
+    {
+        Indexed::new(self.0.dimap(move |(i, a): (I, A)| (i, a2b(a)), c2d))
+    }
+}
+
+/// An indexed optic: an [`Optic`] run with the [`Indexed`] version of the inner profunctor `PI`.
+pub type IndexedOptic<PO, PI, I, S, T, A, B> = Optic<PO, Indexed<I, PI>, S, T, A, B>;
+
+/// An indexed optic run with `CFn`, as taken by [`iover`].
+pub type IndexedSetter<I, S, T, A, B> = IndexedOptic<CFn<S, T>, CFn<(I, A), B>, I, S, T, A, B>;
+
+/// An indexed optic run with `Forget<R, _, _>`, as taken by [`ito_list_of`].
+pub type IndexedFold<R, I, S, T, A, B> =
+    IndexedOptic<Forget<R, S, T>, Forget<R, (I, A), B>, I, S, T, A, B>;
+
+/// The indexed traversal of a `HashMap`'s values returned by [`itraversed_hash_map`].
+pub type IndexedHashMapTraversal<PO, PI, K, A, B> =
+    IndexedOptic<PO, PI, K, HashMap<K, A>, HashMap<K, B>, A, B>;
+
+/// The indexed traversal of a `BTreeMap`'s values returned by [`itraversed_btree_map`].
+pub type IndexedBTreeMapTraversal<PO, PI, K, A, B> =
+    IndexedOptic<PO, PI, K, BTreeMap<K, A>, BTreeMap<K, B>, A, B>;
+
+/// The traversal of a `Vec` that pairs every element with its position.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Enumerated;
+
+impl<G: Kind, A, B> Traversing<G, Vec<A>, Vec<B>, (usize, A), B> for Enumerated
+where
+    VecKind: Traversable<G, (usize, A), B>,
+{
+    fn traverse_with(
+        &self,
+        s: Vec<A>,
+        func: impl FnMut((usize, A)) -> G::Of<B> + Clone + 'static,
+    ) -> G::Of<Vec<B>> {
+        VecKind::traverse(s.into_iter().enumerate().collect(), func)
+    }
+}
+
+/// The traversal of a map that pairs every value with its key.
+///
+/// `M` is a marker for the map type, [`HashMapEntries`] or [`BTreeMapEntries`]; use
+/// [`MapEntries::hash_map`] or [`MapEntries::btree_map`] to create one.
+pub struct MapEntries<M>(PhantomData<M>);
+
+impl<M> Clone for MapEntries<M> {
+    fn clone(&self) -> Self {
+        MapEntries(PhantomData)
+    }
+}
+
+/// Marker for the entries of a `HashMap`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HashMapEntries;
+
+/// Marker for the entries of a `BTreeMap`.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct BTreeMapEntries;
+
+impl MapEntries<HashMapEntries> {
+    /// The traversal of the entries of a `HashMap`, in the map's iteration order.
+    pub fn hash_map() -> Self {
+        MapEntries(PhantomData)
+    }
+}
+
+impl MapEntries<BTreeMapEntries> {
+    /// The traversal of the entries of a `BTreeMap`, in ascending key order.
+    pub fn btree_map() -> Self {
+        MapEntries(PhantomData)
+    }
+}
+
+// Traverses the entries as a `Vec<(K, A)>`, keeping each key next to its new value.
+fn traverse_entries<G, K, A, B>(
+    entries: Vec<(K, A)>,
+    mut func: impl FnMut((K, A)) -> G::Of<B> + Clone + 'static,
+) -> G::Of<Vec<(K, B)>>
+where
+    G: Functor<B, (K, B)>,
+    VecKind: Traversable<G, (K, A), (K, B)>,
+    K: Clone + 'static,
+    B: 'static,
+{
+    VecKind::traverse(entries, move |(k, a): (K, A)| {
+        let key = k.clone();
+        G::map(func((k, a)), move |b: B| (key.clone(), b))
+    })
+}
+
+impl<G: Kind, K, A, B> Traversing<G, HashMap<K, A>, HashMap<K, B>, (K, A), B>
+    for MapEntries<HashMapEntries>
+where
+    G: Functor<B, (K, B)> + Functor<Vec<(K, B)>, HashMap<K, B>>,
+    VecKind: Traversable<G, (K, A), (K, B)>,
+    K: Clone + Eq + Hash + 'static,
+    B: 'static,
+{
+    fn traverse_with(
+        &self,
+        s: HashMap<K, A>,
+        func: impl FnMut((K, A)) -> G::Of<B> + Clone + 'static,
+    ) -> G::Of<HashMap<K, B>> {
+        let entries = traverse_entries::<G, K, A, B>(s.into_iter().collect(), func);
+        G::map(entries, |kbs: Vec<(K, B)>| kbs.into_iter().collect())
+    }
+}
+
+impl<G: Kind, K, A, B> Traversing<G, BTreeMap<K, A>, BTreeMap<K, B>, (K, A), B>
+    for MapEntries<BTreeMapEntries>
+where
+    G: Functor<B, (K, B)> + Functor<Vec<(K, B)>, BTreeMap<K, B>>,
+    VecKind: Traversable<G, (K, A), (K, B)>,
+    K: Clone + Ord + 'static,
+    B: 'static,
+{
+    fn traverse_with(
+        &self,
+        s: BTreeMap<K, A>,
+        func: impl FnMut((K, A)) -> G::Of<B> + Clone + 'static,
+    ) -> G::Of<BTreeMap<K, B>> {
+        let entries = traverse_entries::<G, K, A, B>(s.into_iter().collect(), func);
+        G::map(entries, |kbs: Vec<(K, B)>| kbs.into_iter().collect())
+    }
+}
+
+// Builds an indexed optic from a traversal whose foci are `(index, value)` pairs.
+fn indexed_traversal<PO, PI, W, I, S, T, A, B>(traversal: W) -> IndexedOptic<PO, PI, I, S, T, A, B>
+where
+    PO: Profunctor<S, T>,
+    PI: Wander<(I, A), B, Pro<S, T> = PO>,
+    W: Traversing<PI::Effect, S, T, (I, A), B> + Clone + 'static,
+    I: 'static,
+    S: 'static,
+    T: 'static,
+    A: 'static,
+    B: 'static,
+{
+    Optic::new(move |indexed: Indexed<I, PI>| indexed.0.wander(traversal.clone()))
+}
+
+/// An indexed traversal of every element of a `Vec`, indexed by position.
+pub fn itraversed<PO, PI, A: 'static, B: 'static>(
+) -> IndexedOptic<PO, PI, usize, Vec<A>, Vec<B>, A, B>
+where
+    PO: Profunctor<Vec<A>, Vec<B>>,
+    PI: Wander<(usize, A), B, Pro<Vec<A>, Vec<B>> = PO>,
+    VecKind: Traversable<PI::Effect, (usize, A), B>,
+{
+    indexed_traversal(Enumerated)
+}
+
+/// An indexed traversal of every value of a `HashMap`, indexed by key.
+///
+/// Values are visited in the map's (unspecified) iteration order.
+pub fn itraversed_hash_map<PO, PI, K, A: 'static, B: 'static>(
+) -> IndexedHashMapTraversal<PO, PI, K, A, B>
+where
+    PO: Profunctor<HashMap<K, A>, HashMap<K, B>>,
+    PI: Wander<(K, A), B, Pro<HashMap<K, A>, HashMap<K, B>> = PO>,
+    MapEntries<HashMapEntries>: Traversing<PI::Effect, HashMap<K, A>, HashMap<K, B>, (K, A), B>,
+    K: 'static,
+{
+    indexed_traversal(MapEntries::hash_map())
+}
+
+/// An indexed traversal of every value of a `BTreeMap`, indexed by key, in ascending key order.
+pub fn itraversed_btree_map<PO, PI, K, A: 'static, B: 'static>(
+) -> IndexedBTreeMapTraversal<PO, PI, K, A, B>
+where
+    PO: Profunctor<BTreeMap<K, A>, BTreeMap<K, B>>,
+    PI: Wander<(K, A), B, Pro<BTreeMap<K, A>, BTreeMap<K, B>> = PO>,
+    MapEntries<BTreeMapEntries>: Traversing<PI::Effect, BTreeMap<K, A>, BTreeMap<K, B>, (K, A), B>,
+    K: 'static,
+{
+    indexed_traversal(MapEntries::btree_map())
+}
+
+/// Modifies every focus of an indexed optic with `func`, which also receives the index.
+pub fn iover<I: 'static, S: 'static, T: 'static, A: 'static, B: 'static>(
+    optic: IndexedSetter<I, S, T, A, B>,
+    func: impl Fn(I, A) -> B + 'static,
+    s: S,
+) -> T {
+    (optic.optic)(Indexed::new(CFn::new(move |(i, a)| func(i, a)))).call(s)
+}
+
+/// Collects every focus of an indexed optic, paired with its index, into a `Vec`.
+pub fn ito_list_of<I: 'static, S: 'static, T: 'static, A: 'static, B: 'static>(
+    optic: IndexedFold<Vec<(I, A)>, I, S, T, A, B>,
+    s: S,
+) -> Vec<(I, A)> {
+    (optic.optic)(Indexed::new(Forget::new(|ia: (I, A)| vec![ia])))
+        .inner
+        .call(s)
+}
+
+/// Maps whose entries can be looked up, inserted and removed by key, for [`at`].
+pub trait At: Clone {
+    /// The key type.
+    type Key;
+    /// The value type.
+    type Value;
+
+    /// Returns a copy of the value stored under `key`, if any.
+    fn get_at(&self, key: &Self::Key) -> Option<Self::Value>;
+
+    /// Stores `value` under `key` if it is `Some`, or removes the entry if it is `None`.
+    fn set_at(&mut self, key: Self::Key, value: Option<Self::Value>);
+}
+
+impl<K: Clone + Eq + Hash, V: Clone> At for HashMap<K, V> {
+    type Key = K;
+    type Value = V;
+
+    fn get_at(&self, key: &K) -> Option<V> {
+        self.get(key).cloned()
+    }
+
+    fn set_at(&mut self, key: K, value: Option<V>) {
+        match value {
+            Some(v) => {
+                self.insert(key, v);
+            }
+            None => {
+                self.remove(&key);
+            }
+        }
+    }
+}
+
+impl<K: Clone + Ord, V: Clone> At for BTreeMap<K, V> {
+    type Key = K;
+    type Value = V;
+
+    fn get_at(&self, key: &K) -> Option<V> {
+        self.get(key).cloned()
+    }
+
+    fn set_at(&mut self, key: K, value: Option<V>) {
+        match value {
+            Some(v) => {
+                self.insert(key, v);
+            }
+            None => {
+                self.remove(&key);
+            }
+        }
+    }
+}
+
+/// The `Lens` onto an optional map entry returned by [`at`].
+pub type AtLens<PO, PI, M> = Lens<PO, PI, M, M, Option<<M as At>::Value>, Option<<M as At>::Value>>;
+
+/// A `Lens` onto the value stored under `key` in a map, as an `Option`.
+///
+/// Viewing gives `None` when the key is absent. Setting `Some(v)` inserts or replaces
+/// the entry, and setting `None` removes it.
+pub fn at<PO, PI, M>(key: M::Key) -> AtLens<PO, PI, M>
+where
+    M: At + 'static,
+    M::Key: Clone + 'static,
+    M::Value: 'static,
+    PO: Strong<M, M>,
+    PI: Strong<Option<M::Value>, Option<M::Value>>,
+    <PI as Profunctor<Option<M::Value>, Option<M::Value>>>::Pro<
+        (Option<M::Value>, RcFn<Option<M::Value>, M>),
+        (Option<M::Value>, RcFn<Option<M::Value>, M>),
+    >: Profunctor<
+        (Option<M::Value>, RcFn<Option<M::Value>, M>),
+        (Option<M::Value>, RcFn<Option<M::Value>, M>),
+        Pro<M, M> = PO,
+    >,
+{
+    let get_key = key.clone();
+    lens(
+        CFn::new(move |m: M| m.get_at(&get_key)),
+        CFn::new(move |m: M| {
+            let key = key.clone();
+            CFn::new(move |value: Option<M::Value>| {
+                // The setter is an `Fn` that may be called more than once on the same `m`,
+                // so each call updates its own copy.
+                let mut m = m.clone();
+                m.set_at(key.clone(), value);
+                m
+            })
+        }),
+    )
+}
+
+/// The traversal of the single element at an index of a `Vec`, if it exists.
+#[derive(Debug, Clone, Copy)]
+pub struct ElementAt(pub usize);
+
+impl<G: Kind, A> Traversing<G, Vec<A>, Vec<A>, A, A> for ElementAt
+where
+    G: Applicative<Vec<A>> + Functor<A, Vec<A>>,
+    A: Clone + 'static,
+{
+    fn traverse_with(
+        &self,
+        mut s: Vec<A>,
+        mut func: impl FnMut(A) -> G::Of<A> + Clone + 'static,
+    ) -> G::Of<Vec<A>> {
+        let index = self.0;
+        if index >= s.len() {
+            return G::pure(s);
+        }
+        let suffix = s.split_off(index + 1);
+        let a = s.pop().expect("index is in bounds");
+        let prefix = s;
+        // The rebuilding closure may run once per result of `func` (e.g. for `Vec`), so
+        // it copies the untouched elements each time; this is why `A: Clone` is needed.
+        G::map(func(a), move |b: A| {
+            let mut s = Vec::with_capacity(prefix.len() + 1 + suffix.len());
+            s.extend_from_slice(&prefix);
+            s.push(b);
+            s.extend_from_slice(&suffix);
+            s
+        })
+    }
+}
+
+/// A `Traversal` onto the element at `index` of a `Vec`: one focus if the index is in
+/// bounds, none otherwise.
+///
+/// Use it with `preview` to read the element as an `Option`, or with `over`/`set` to
+/// update it (an out-of-bounds index leaves the `Vec` unchanged).
+pub fn ix<PO, PI, A: Clone + 'static>(index: usize) -> Traversal<PO, PI, Vec<A>, Vec<A>, A, A>
+where
+    PO: Wander<Vec<A>, Vec<A>>,
+    PI: Wander<A, A, Pro<Vec<A>, Vec<A>> = PO>,
+    PI::Effect: Applicative<Vec<A>> + Functor<A, Vec<A>>,
+{
+    traversal(ElementAt(index))
+}
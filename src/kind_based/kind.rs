@@ -16,7 +16,7 @@
 //! the marker's `Of<Arg>` GAT, they can refer to the concrete type
 //! (e.g., `Option<String>`, `Vec<i32>`).
 
-use crate::function::{CFn, CFnOnce};
+use crate::function::{ArcFn, CFn, CFnMut, CFnOnce, RcFn, SendFnOnce};
 use std::marker::PhantomData;
 
 /// Represents a type constructor, often referred to as a Kind.
@@ -89,6 +89,48 @@ impl<X> Kind for CFnKind<X> {
     type Of<Output> = CFn<X, Output>;
 }
 
+/// Kind Marker for `CFnMut<X, _>`. `X` is the fixed input type of the function.
+///
+/// Implements [`Kind`] such that `CFnMutKind<X>::Of<Output>` resolves to `CFnMut<X, Output>`.
+#[derive(Default)]
+pub struct CFnMutKind<X>(PhantomData<X>);
+
+impl<X> Kind for CFnMutKind<X> {
+    type Of<Output> = CFnMut<X, Output>;
+}
+
+/// Kind Marker for `RcFn<X, _>`. `X` is the fixed input type of the function.
+///
+/// Implements [`Kind`] such that `RcFnKind<X>::Of<Output>` resolves to `RcFn<X, Output>`.
+#[derive(Default)]
+pub struct RcFnKind<X>(PhantomData<X>);
+
+impl<X> Kind for RcFnKind<X> {
+    type Of<Output> = RcFn<X, Output>;
+}
+
+/// Kind Marker for `ArcFn<X, _>`. `X` is the fixed input type of the function.
+///
+/// Implements [`Kind`] such that `ArcFnKind<X>::Of<Output>` resolves to `ArcFn<X, Output>`.
+/// Its instances are the thread-safe traits of [`crate::send`].
+#[derive(Default)]
+pub struct ArcFnKind<X>(PhantomData<X>);
+
+impl<X> Kind for ArcFnKind<X> {
+    type Of<Output> = ArcFn<X, Output>;
+}
+
+/// Kind Marker for `SendFnOnce<X, _>`. `X` is the fixed input type of the function.
+///
+/// Implements [`Kind`] such that `SendFnOnceKind<X>::Of<Output>` resolves to `SendFnOnce<X, Output>`.
+/// Its instances are the thread-safe traits of [`crate::send`].
+#[derive(Default)]
+pub struct SendFnOnceKind<X>(PhantomData<X>);
+
+impl<X> Kind for SendFnOnceKind<X> {
+    type Of<Output> = SendFnOnce<X, Output>;
+}
+
 /// Kind Marker for `CFnOnce<X, _>`. `X` is the fixed input type of the function.
 ///
 /// Implements [`Kind`] such that `CFnOnceKind<X>::Of<Output>` resolves to `CFnOnce<X, Output>`.
@@ -111,6 +153,272 @@ impl<X> Kind for CFnOnceKind<X> {
 pub trait Kind1: Kind {}
 impl<T: Kind> Kind1 for T {} // Blanket implementation
 
+// --- Reverse Mapping ---
+
+/// Maps a concrete type back to its Kind marker and type argument.
+///
+/// [`Kind`] goes from a marker to a type (`OptionKind::Of<i32>` is `Option<i32>`), but the
+/// compiler can't go back from `Option<i32>` to `OptionKind`, so callers of the Kind traits
+/// have to name the marker. `HasKind` records that link once per type constructor: for
+/// `T: HasKind`, `<T::Kind as Kind>::Of<T::Arg>` is `T` itself, which lets generic code
+/// (such as the extension methods and free functions of [`crate::ext`]) pick the marker
+/// from a value.
+///
+/// # Examples
+/// ```
+/// use monadify::kind_based::kind::{HasKind, Kind, OptionKind};
+///
+/// fn same_kind<T: HasKind<Kind = OptionKind>>(_: &T) {}
+/// same_kind(&Some(1));
+///
+/// let value: <<Option<i32> as HasKind>::Kind as Kind>::Of<&str> = Some("hi");
+/// assert_eq!(value, Some("hi"));
+/// ```
+pub trait HasKind: Sized {
+    /// The Kind marker of the type constructor, e.g. [`OptionKind`] for `Option<_>`.
+    type Kind: Kind<Of<Self::Arg> = Self>;
+    /// The type argument, e.g. `i32` for `Option<i32>`.
+    type Arg;
+}
+
+impl<T> HasKind for Option<T> {
+    type Kind = OptionKind;
+    type Arg = T;
+}
+
+impl<T> HasKind for Vec<T> {
+    type Kind = VecKind;
+    type Arg = T;
+}
+
+impl<T, E> HasKind for Result<T, E> {
+    type Kind = ResultKind<E>;
+    type Arg = T;
+}
+
+impl<X, T> HasKind for CFn<X, T> {
+    type Kind = CFnKind<X>;
+    type Arg = T;
+}
+
+impl<X, T> HasKind for CFnOnce<X, T> {
+    type Kind = CFnOnceKind<X>;
+    type Arg = T;
+}
+
+impl<X, T> HasKind for CFnMut<X, T> {
+    type Kind = CFnMutKind<X>;
+    type Arg = T;
+}
+
+impl<X, T> HasKind for RcFn<X, T> {
+    type Kind = RcFnKind<X>;
+    type Arg = T;
+}
+
+// --- Defining Kinds ---
+
+/// Defines a Kind marker for an existing single-parameter type constructor.
+///
+/// `kind!(pub MyKind for MyType<_>)` generates the marker struct `MyKind`, its [`Kind`]
+/// impl (`MyKind::Of<T>` is `MyType<T>`) and the [`HasKind`] back mapping. The `_` marks
+/// the varying parameter; any other parameters are fixed and become parameters of the
+/// marker, as in `kind!(pub MyResultKind<E> for MyResult<_, E>)`. Attributes, such as doc
+/// comments, are applied to the marker.
+///
+/// Trait instances (`Functor`, `Bind`, ...) are then implemented on the marker as usual.
+/// For a type defined alongside its marker, [`derive_kind!`] can also generate `Functor`.
+///
+/// # Examples
+/// ```
+/// use monadify::functor::kind::Functor;
+/// use monadify::kind;
+/// use monadify::kind_based::kind::HasKind;
+///
+/// #[derive(Debug, PartialEq)]
+/// pub struct Labelled<T, L>(pub T, pub L);
+///
+/// kind!(
+///     /// The Kind of `Labelled<_, L>`.
+///     pub LabelledKind<L> for Labelled<_, L>
+/// );
+///
+/// impl<A, B, L> Functor<A, B> for LabelledKind<L> {
+///     fn map(input: Labelled<A, L>, mut func: impl FnMut(A) -> B + Clone + 'static) -> Labelled<B, L> {
+///         Labelled(func(input.0), input.1)
+///     }
+/// }
+///
+/// fn kind_of<T: HasKind>(_: &T) -> &'static str {
+///     std::any::type_name::<T::Kind>()
+/// }
+///
+/// let value = Labelled(20, "age");
+/// assert!(kind_of(&value).ends_with("LabelledKind<&str>"));
+/// assert_eq!(LabelledKind::map(value, |x: i32| x + 1), Labelled(21, "age"));
+/// ```
+#[macro_export]
+macro_rules! kind {
+    ($(#[$meta:meta])* $vis:vis $marker:ident $(<$($param:ident),+>)? for $($ty:tt)+) => {
+        $crate::kind!(@subst ($(#[$meta])* $vis $marker $(<$($param),+>)?) [] $($ty)+);
+    };
+    // Replaces the `_` of the type with the parameter of `Of`.
+    (@subst $head:tt [$($done:tt)*] _ $($rest:tt)*) => {
+        $crate::kind!(@subst $head [$($done)* __T] $($rest)*);
+    };
+    (@subst $head:tt [$($done:tt)*] $next:tt $($rest:tt)*) => {
+        $crate::kind!(@subst $head [$($done)* $next] $($rest)*);
+    };
+    (@subst $head:tt [$($done:tt)*]) => {
+        $crate::kind!(@emit $head __T [$($done)*]);
+    };
+    (@emit ($(#[$meta:meta])* $vis:vis $marker:ident) $arg:ident [$($of:tt)*]) => {
+        $(#[$meta])*
+        #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+        $vis struct $marker;
+
+        $crate::kind!(@impls $marker [] $arg [$($of)*]);
+    };
+    (@emit ($(#[$meta:meta])* $vis:vis $marker:ident <$($param:ident),+>) $arg:ident [$($of:tt)*]) => {
+        $(#[$meta])*
+        #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+        $vis struct $marker<$($param),+>(::std::marker::PhantomData<($($param,)+)>);
+
+        $crate::kind!(@impls $marker [$($param),+] $arg [$($of)*]);
+    };
+    (@impls $marker:ident [$($param:ident),*] $arg:ident [$($of:tt)*]) => {
+        impl<$($param),*> $crate::kind_based::kind::Kind for $marker<$($param),*> {
+            type Of<$arg> = $($of)*;
+        }
+
+        impl<$arg, $($param),*> $crate::kind_based::kind::HasKind for $($of)* {
+            type Kind = $marker<$($param),*>;
+            type Arg = $arg;
+        }
+    };
+}
+
+/// Defines a type together with its Kind marker, and optionally its `Functor` instance.
+///
+/// The first line declares the marker, like [`kind!`] but naming the varying parameter
+/// instead of writing `_`: `pub kind OutcomeKind<E> over T;`, where the marker's
+/// parameters are the type's other parameters. It is followed by the definition of the
+/// type, which is emitted unchanged. The marker must be at least as visible as the type,
+/// since the type's [`HasKind`] impl names it.
+///
+/// Ending the first line with `: Functor` also implements
+/// [`Functor`](crate::functor::kind::Functor) on the marker, mapping every field whose type
+/// is exactly the varying parameter and keeping the others. This is supported for
+///
+/// - newtypes: tuple structs with a single field, and
+/// - enums whose variants are unit variants or tuple variants with a single field.
+///
+/// A field whose type only contains the parameter, like `Vec<T>`, can't be mapped this way
+/// and fails to type-check; implement `Functor` by hand for such types.
+///
+/// # Examples
+/// ```
+/// use monadify::derive_kind;
+/// use monadify::functor::kind::Functor;
+/// use monadify::FunctorExt;
+///
+/// derive_kind! {
+///     pub kind OutcomeKind<E> over T: Functor;
+///
+///     #[derive(Debug, PartialEq)]
+///     pub enum Outcome<T, E> {
+///         Done(T),
+///         Failed(E),
+///         Pending,
+///     }
+/// }
+///
+/// let done: Outcome<i32, String> = Outcome::Done(2);
+/// assert_eq!(done.fmap(|x| x * 10), Outcome::Done(20));
+/// let failed: Outcome<i32, &str> = Outcome::Failed("timeout");
+/// assert_eq!(OutcomeKind::map(failed, |x: i32| x * 10), Outcome::Failed("timeout"));
+/// ```
+#[macro_export]
+macro_rules! derive_kind {
+    (
+        $(#[$kmeta:meta])* $kvis:vis kind $marker:ident $(<$($param:ident),+>)? over $arg:ident
+            : Functor;
+        $($item:tt)+
+    ) => {
+        $crate::derive_kind!($(#[$kmeta])* $kvis kind $marker $(<$($param),+>)? over $arg; $($item)+);
+        $crate::derive_kind!(@functor ($) $marker [$($($param),+)?] $arg $($item)+);
+    };
+    (
+        $(#[$kmeta:meta])* $kvis:vis kind $marker:ident $(<$($param:ident),+>)? over $arg:ident;
+        $(#[$meta:meta])* $vis:vis $what:ident $name:ident <$($gen:ident),+> $($body:tt)+
+    ) => {
+        $(#[$meta])*
+        $vis $what $name<$($gen),+> $($body)+
+
+        $crate::kind!(@emit ($(#[$kmeta])* $kvis $marker $(<$($param),+>)?) $arg [$name<$($gen),+>]);
+    };
+    // `$d` is a `$`, for the metavariables of the helper macros defined in `map`.
+    (@functor ($d:tt) $marker:ident [$($param:ident),*] $arg:ident
+        $(#[$meta:meta])* $vis:vis struct $name:ident <$($gen:ident),+> ($($field:tt)+);
+    ) => {
+        $crate::derive_kind!(@newtype ($d $marker [$($param),*] $arg) $name $($field)+);
+    };
+    // Strips the attributes and visibility of a newtype's field, leaving its type.
+    (@newtype $ctx:tt $name:ident # [$($fmeta:tt)*] $($rest:tt)+) => {
+        $crate::derive_kind!(@newtype $ctx $name $($rest)+);
+    };
+    (@newtype $ctx:tt $name:ident pub ($($restriction:tt)+) $($rest:tt)+) => {
+        $crate::derive_kind!(@newtype $ctx $name $($rest)+);
+    };
+    (@newtype $ctx:tt $name:ident pub $($rest:tt)+) => {
+        $crate::derive_kind!(@newtype $ctx $name $($rest)+);
+    };
+    (@newtype ($d:tt $marker:ident [$($param:ident),*] $arg:ident) $name:ident $($fty:tt)+) => {
+        $crate::derive_kind!(@impl_functor ($d) $marker [$($param),*] $arg input, func => {
+            let $name(value) = input;
+            $name(__kind_field!([$($fty)+] value))
+        });
+    };
+    (@functor ($d:tt) $marker:ident [$($param:ident),*] $arg:ident
+        $(#[$meta:meta])* $vis:vis enum $name:ident <$($gen:ident),+> {
+            $($(#[$vmeta:meta])* $variant:ident $(($($vty:tt)+))?),* $(,)?
+        }
+    ) => {
+        $crate::derive_kind!(@impl_functor ($d) $marker [$($param),*] $arg input, func => {
+            match input {
+                $(
+                    $name::$variant $((__kind_bind!([$($vty)+] value)))? =>
+                        $name::$variant $((__kind_field!([$($vty)+] value)))?,
+                )*
+            }
+        });
+    };
+    (@impl_functor ($d:tt) $marker:ident [$($param:ident),*] $arg:ident
+        $input:ident, $func:ident => $body:block
+    ) => {
+        impl<__A, __B, $($param),*> $crate::functor::kind::Functor<__A, __B> for $marker<$($param),*> {
+            #[allow(unused_mut)]
+            fn map(
+                $input: Self::Of<__A>,
+                mut $func: impl FnMut(__A) -> __B + Clone + 'static,
+            ) -> Self::Of<__B> {
+                // Maps a field whose type is the varying parameter, and keeps any other.
+                #[allow(unused_macros)]
+                macro_rules! __kind_field {
+                    ([$arg] $d value:expr) => { $func($d value) };
+                    ([$d ($d other:tt)+] $d value:expr) => { $d value };
+                }
+                // Binds a field in a pattern.
+                #[allow(unused_macros)]
+                macro_rules! __kind_bind {
+                    ([$d ($d ty:tt)+] $d name:ident) => { $d name };
+                }
+                $body
+            }
+        }
+    };
+}
+
 // If Kinds with more complex arities were needed, e.g., for Bifunctor `F<A, B>`:
 // pub trait Kind2 {
 //     type Of<Arg1, Arg2>: Sized;
@@ -0,0 +1,600 @@
+//! # Kleisli arrows for the `monadify` library
+//!
+//! A Kleisli arrow `Kleisli<MKind, A, B>` wraps an effectful function
+//! `A -> MKind::Of<B>`, such as `A -> Option<B>` or `A -> Result<B, E>`. Kleisli arrows
+//! compose like plain functions, with the monad's `bind` threading the effect from one
+//! step into the next.
+//!
+//! For any effect with a [`Bind`] instance:
+//! - `f >> g` composes `f: Kleisli<M, A, B>` with `g: Kleisli<M, B, C>` (the "fish"
+//!   operator `>=>`), and `g << f` is the same composition written backwards.
+//! - [`KleisliKind<MKind, A>`] is a [`Functor`], [`Apply`], [`Applicative`], [`Bind`] and
+//!   [`Monad`] over the output, like `ReaderTKind<A, MKind>`: `bind` passes the same input
+//!   to both steps.
+//!
+//! `Kleisli` is also a [`Profunctor`], [`Strong`] and [`Choice`] for every effect the crate
+//! ships with a `Bind` instance: `OptionKind`, `ResultKind<E>`, `IdentityKind`, `VecKind`,
+//! the function Kinds `CFnKind<R>`, `RcFnKind<R>`, `CFnOnceKind<R>` and `CFnMutKind<R>`,
+//! and `ReaderTKind<R, M>` and `KleisliKind<M, R>` with `M` one of `OptionKind`,
+//! `ResultKind<E>`, `IdentityKind` or `VecKind`. Where the effect can give several results
+//! or runs once per environment, `first` and `left` clone the untouched half of the pair
+//! (or the other side of the `Result`) for each one. For `OptionKind`, `ResultKind<E>` and
+//! `IdentityKind` it also implements [`Semigroupoid`], [`Category`], [`Arrow`] and
+//! [`ArrowChoice`]. As with [`crate::profunctor::Star`], these instances are written per
+//! effect because `dimap` and `first` choose their result types per call, so the effect's
+//! `map` can't be required up front.
+//!
+//! ## Example
+//! ```
+//! use monadify::arrow::{Arrow, Semigroupoid};
+//! use monadify::kind_based::kind::OptionKind;
+//! use monadify::kleisli::Kleisli;
+//!
+//! let parse: Kleisli<OptionKind, &str, i32> = Kleisli::new(|s: &str| s.parse().ok());
+//! let halve: Kleisli<OptionKind, i32, i32> =
+//!     Kleisli::new(|n: i32| (n % 2 == 0).then_some(n / 2));
+//!
+//! let parse_and_halve = halve.clone().compose(parse.clone());
+//! assert_eq!(parse_and_halve.run("10"), Some(5));
+//! assert_eq!(parse_and_halve.run("7"), None);
+//! assert_eq!(parse_and_halve.run("x"), None);
+//!
+//! let checked = Kleisli::<OptionKind, i32, i32>::arr(|n| n + 1);
+//! assert_eq!(checked.run(1), Some(2));
+//!
+//! // The same pipeline with the fish operator: parse, then halve.
+//! assert_eq!((parse >> halve).run("10"), Some(5));
+//! ```
+
+use crate::applicative::kind::Applicative;
+use crate::apply::kind::Apply;
+use crate::arrow::{Arrow, ArrowChoice, Category, Semigroupoid};
+use crate::function::{CFn, CFnMut, CFnOnce, RcFn};
+use crate::functor::kind::Functor;
+use crate::identity::{Identity, IdentityKind};
+use crate::kind_based::kind::{
+    CFnKind, CFnMutKind, CFnOnceKind, Kind, OptionKind, RcFnKind, ResultKind, VecKind,
+};
+use crate::monad::kind::{Bind, Monad};
+use crate::profunctor::{Choice, Profunctor, Strong};
+use crate::transformers::reader::{ReaderT, ReaderTKind};
+use std::marker::PhantomData;
+use std::rc::Rc;
+
+/// An effectful function `A -> MKind::Of<B>`, viewed as an arrow from `A` to `B`.
+///
+/// # Type Parameters
+/// - `MKind`: The Kind marker of the effect (e.g., [`OptionKind`]).
+/// - `A`: The input type.
+/// - `B`: The type of the value produced within the effect.
+pub struct Kleisli<MKind: Kind, A, B> {
+    /// The wrapped function `A -> MKind::Of<B>`.
+    pub run_kleisli: RcFn<A, MKind::Of<B>>,
+}
+
+impl<MKind: Kind, A, B> Kleisli<MKind, A, B> {
+    /// Creates a new `Kleisli` from a function `A -> MKind::Of<B>`.
+    pub fn new<F>(f: F) -> Self
+    where
+        F: Fn(A) -> MKind::Of<B> + 'static,
+    {
+        Kleisli {
+            run_kleisli: RcFn::new(f),
+        }
+    }
+
+    /// Runs the wrapped function.
+    pub fn run(&self, a: A) -> MKind::Of<B> {
+        self.run_kleisli.call(a)
+    }
+}
+
+/// Cloning a `Kleisli` shares the wrapped function.
+impl<MKind: Kind, A, B> Clone for Kleisli<MKind, A, B> {
+    fn clone(&self) -> Self {
+        Kleisli {
+            run_kleisli: self.run_kleisli.clone(),
+        }
+    }
+}
+
+/// Composes Kleisli arrows left to right (`>=>`): `(f >> g).run(a)` runs `f` on `a`, then
+/// binds its result into `g`.
+impl<MKind, A, B, C> std::ops::Shr<Kleisli<MKind, B, C>> for Kleisli<MKind, A, B>
+where
+    MKind: Bind<B, C> + 'static,
+    A: 'static,
+    B: 'static,
+    C: 'static,
+{
+    type Output = Kleisli<MKind, A, C>;
+    fn shr(self, rhs: Kleisli<MKind, B, C>) -> Self::Output {
+        Kleisli::new(move |a: A| {
+            let next = rhs.clone();
+            MKind::bind(self.run(a), move |b: B| next.run(b))
+        })
+    }
+}
+
+/// Composes Kleisli arrows right to left (`<=<`): `(g << f)` is `(f >> g)`.
+impl<MKind, A, B, C> std::ops::Shl<Kleisli<MKind, A, B>> for Kleisli<MKind, B, C>
+where
+    MKind: Bind<B, C> + 'static,
+    A: 'static,
+    B: 'static,
+    C: 'static,
+{
+    type Output = Kleisli<MKind, A, C>;
+    fn shl(self, rhs: Kleisli<MKind, A, B>) -> Self::Output {
+        std::ops::Shr::shr(rhs, self)
+    }
+}
+
+/// The Kind marker for `Kleisli<MKind, A, _>`, fixing the effect and the input type.
+#[derive(Default)]
+pub struct KleisliKind<MKind: Kind, A>(PhantomData<(MKind, A)>);
+
+impl<MKind: Kind, A> Kind for KleisliKind<MKind, A> {
+    type Of<B> = Kleisli<MKind, A, B>;
+}
+
+impl<MKind, A, B, C> Functor<B, C> for KleisliKind<MKind, A>
+where
+    MKind: Functor<B, C> + 'static,
+    A: 'static,
+    B: 'static,
+    C: 'static,
+{
+    /// Maps `func` over the output, within the effect.
+    fn map(
+        input: Kleisli<MKind, A, B>,
+        func: impl FnMut(B) -> C + Clone + 'static,
+    ) -> Kleisli<MKind, A, C> {
+        Kleisli::new(move |a: A| MKind::map(input.run(a), func.clone()))
+    }
+}
+
+impl<MKind, A, B, C> Apply<B, C> for KleisliKind<MKind, A>
+where
+    MKind: Apply<B, C> + 'static,
+    A: Clone + 'static,
+    B: 'static,
+    C: 'static,
+{
+    /// Runs both arrows on the same input and applies within the effect.
+    fn apply(
+        value_container: Kleisli<MKind, A, B>,
+        function_container: Kleisli<MKind, A, CFn<B, C>>,
+    ) -> Kleisli<MKind, A, C> {
+        Kleisli::new(move |a: A| {
+            MKind::apply(value_container.run(a.clone()), function_container.run(a))
+        })
+    }
+}
+
+impl<MKind, A, T> Applicative<T> for KleisliKind<MKind, A>
+where
+    MKind: Applicative<T> + 'static,
+    A: Clone + 'static,
+    T: Clone + 'static,
+{
+    /// Ignores the input and returns `MKind::pure(value)`.
+    fn pure(value: T) -> Kleisli<MKind, A, T> {
+        Kleisli::new(move |_| MKind::pure(value.clone()))
+    }
+}
+
+impl<MKind, A, B, C> Bind<B, C> for KleisliKind<MKind, A>
+where
+    MKind: Bind<B, C> + 'static,
+    A: Clone + 'static,
+    B: 'static,
+    C: 'static,
+{
+    /// Runs `input`, then the arrow `func` picks, on the same input.
+    fn bind(
+        input: Kleisli<MKind, A, B>,
+        func: impl FnMut(B) -> Kleisli<MKind, A, C> + Clone + 'static,
+    ) -> Kleisli<MKind, A, C> {
+        Kleisli::new(move |a: A| {
+            let mut func = func.clone();
+            let next_a = a.clone();
+            MKind::bind(input.run(a), move |b| func(b).run(next_a.clone()))
+        })
+    }
+}
+
+impl<MKind, A, B> Monad<B> for KleisliKind<MKind, A>
+where
+    MKind: Monad<B> + Bind<Kleisli<MKind, A, B>, B> + 'static,
+    A: Clone + 'static,
+    B: Clone + 'static,
+{
+    /// Runs the outer arrow, then the inner arrow it returns, on the same input.
+    fn join(mma: Kleisli<MKind, A, Kleisli<MKind, A, B>>) -> Kleisli<MKind, A, B> {
+        Kleisli::new(move |a: A| {
+            let inner_a = a.clone();
+            <MKind as Bind<Kleisli<MKind, A, B>, B>>::bind(
+                mma.run(a),
+                move |inner: Kleisli<MKind, A, B>| inner.run(inner_a.clone()),
+            )
+        })
+    }
+}
+
+// Implements Profunctor and Choice for `Kleisli<$kind, _, _>`, given how to map a
+// `$kind::Of<_>` with a `FnOnce` and how to lift a plain value into it.
+macro_rules! impl_kleisli_profunctor {
+    ([$($gen:tt)*] $kind:ty, map: |$fa:ident, $f:ident| $map:expr, pure: |$x:ident| $pure:expr) => {
+        impl<$($gen)* B, C> Profunctor<B, C> for Kleisli<$kind, B, C> {
+            type Pro<T, U> = Kleisli<$kind, T, U>;
+            fn dimap<A, D, A2B, C2D>(self, a2b: A2B, c2d: C2D) -> Self::Pro<A, D>
+            where
+                A2B: Fn(A) -> B + 'static,
+                C2D: Fn(C) -> D + 'static,
+                A: 'static,
+                B: 'static,
+                C: 'static,
+                D: 'static,
+            {
+                Kleisli::new(move |a: A| {
+                    let $fa = self.run(a2b(a));
+                    let $f = |c: C| c2d(c);
+                    $map
+                })
+            }
+        }
+
+        impl<$($gen)* A: 'static, B: 'static> Choice<A, B> for Kleisli<$kind, A, B> {
+            fn left<C>(self) -> Self::Pro<Result<C, A>, Result<C, B>> {
+                Kleisli::new(move |r: Result<C, A>| match r {
+                    Ok(c) => {
+                        let $x = Ok(c);
+                        $pure
+                    }
+                    Err(a) => {
+                        let $fa = self.run(a);
+                        let $f = Err;
+                        $map
+                    }
+                })
+            }
+
+            fn right<C>(self) -> Self::Pro<Result<A, C>, Result<B, C>> {
+                Kleisli::new(move |r: Result<A, C>| match r {
+                    Ok(a) => {
+                        let $fa = self.run(a);
+                        let $f = Ok;
+                        $map
+                    }
+                    Err(c) => {
+                        let $x = Err(c);
+                        $pure
+                    }
+                })
+            }
+        }
+    };
+}
+
+// Implements Strong and the arrow traits for `Kleisli<$kind, _, _>`, for effects holding
+// at most one value, given how to map and bind a `$kind::Of<_>` with a `FnOnce` and how
+// to lift a plain value into it.
+macro_rules! impl_kleisli_arrow {
+    (
+        [$($gen:tt)*] $kind:ty,
+        map: |$fa:ident, $f:ident| $map:expr,
+        bind: |$ma:ident, $k:ident| $bind:expr,
+        pure: |$x:ident| $pure:expr
+    ) => {
+        impl<$($gen)* A: 'static, B: 'static> Strong<A, B> for Kleisli<$kind, A, B> {
+            fn first<C: Clone + 'static>(self) -> Self::Pro<(A, C), (B, C)> {
+                Kleisli::new(move |(a, c): (A, C)| {
+                    let $fa = self.run(a);
+                    let $f = move |b: B| (b, c);
+                    $map
+                })
+            }
+
+            fn second<C: Clone + 'static>(self) -> Self::Pro<(C, A), (C, B)> {
+                Kleisli::new(move |(c, a): (C, A)| {
+                    let $fa = self.run(a);
+                    let $f = move |b: B| (c, b);
+                    $map
+                })
+            }
+        }
+
+        impl<$($gen)* B: 'static, C: 'static> Semigroupoid<B, C> for Kleisli<$kind, B, C> {
+            fn compose<A: 'static>(self, before: Kleisli<$kind, A, B>) -> Kleisli<$kind, A, C> {
+                Kleisli::new(move |a: A| {
+                    let $ma = before.run(a);
+                    let $k = |b: B| self.run(b);
+                    $bind
+                })
+            }
+        }
+
+        impl<$($gen)* A: 'static> Category<A> for Kleisli<$kind, A, A> {
+            fn id() -> Self {
+                Kleisli::new(|a: A| {
+                    let $x = a;
+                    $pure
+                })
+            }
+        }
+
+        impl<$($gen)* B: 'static, C: 'static> Arrow<B, C> for Kleisli<$kind, B, C> {
+            fn arr(f: impl Fn(B) -> C + 'static) -> Self {
+                Kleisli::new(move |b: B| {
+                    let $x = f(b);
+                    $pure
+                })
+            }
+
+            fn split<B2: 'static, C2: 'static>(
+                self,
+                other: Kleisli<$kind, B2, C2>,
+            ) -> Kleisli<$kind, (B, B2), (C, C2)> {
+                Kleisli::new(move |(b, b2): (B, B2)| {
+                    let second = other.run(b2);
+                    let $ma = self.run(b);
+                    let $k = move |c: C| {
+                        let $fa = second;
+                        let $f = move |c2: C2| (c, c2);
+                        $map
+                    };
+                    $bind
+                })
+            }
+
+            fn fanout<C2: 'static>(self, other: Kleisli<$kind, B, C2>) -> Kleisli<$kind, B, (C, C2)>
+            where
+                B: Clone,
+            {
+                Kleisli::new(move |b: B| {
+                    let second = other.run(b.clone());
+                    let $ma = self.run(b);
+                    let $k = move |c: C| {
+                        let $fa = second;
+                        let $f = move |c2: C2| (c, c2);
+                        $map
+                    };
+                    $bind
+                })
+            }
+        }
+
+        impl<$($gen)* B: 'static, C: 'static> ArrowChoice<B, C> for Kleisli<$kind, B, C> {
+            fn choose<B2: 'static, C2: 'static>(
+                self,
+                other: Kleisli<$kind, B2, C2>,
+            ) -> Kleisli<$kind, Result<B2, B>, Result<C2, C>> {
+                Kleisli::new(move |r: Result<B2, B>| match r {
+                    Ok(b2) => {
+                        let $fa = other.run(b2);
+                        let $f = Ok;
+                        $map
+                    }
+                    Err(b) => {
+                        let $fa = self.run(b);
+                        let $f = Err;
+                        $map
+                    }
+                })
+            }
+
+            fn fanin<B2: 'static>(
+                self,
+                other: Kleisli<$kind, B2, C>,
+            ) -> Kleisli<$kind, Result<B2, B>, C> {
+                Kleisli::new(move |r: Result<B2, B>| match r {
+                    Ok(b2) => other.run(b2),
+                    Err(b) => self.run(b),
+                })
+            }
+        }
+    };
+}
+
+impl_kleisli_profunctor!([] OptionKind, map: |fa, f| fa.map(f), pure: |x| Some(x));
+impl_kleisli_arrow!(
+    [] OptionKind,
+    map: |fa, f| fa.map(f),
+    bind: |ma, k| ma.and_then(k),
+    pure: |x| Some(x)
+);
+impl_kleisli_profunctor!([E: 'static,] ResultKind<E>, map: |fa, f| fa.map(f), pure: |x| Ok(x));
+impl_kleisli_arrow!(
+    [E: 'static,] ResultKind<E>,
+    map: |fa, f| fa.map(f),
+    bind: |ma, k| ma.and_then(k),
+    pure: |x| Ok(x)
+);
+impl_kleisli_profunctor!([] IdentityKind, map: |fa, f| Identity(f(fa.0)), pure: |x| Identity(x));
+impl_kleisli_arrow!(
+    [] IdentityKind,
+    map: |fa, f| Identity(f(fa.0)),
+    bind: |ma, k| k(ma.0),
+    pure: |x| Identity(x)
+);
+impl_kleisli_profunctor!(
+    [] VecKind,
+    map: |fa, f| fa.into_iter().map(f).collect(),
+    pure: |x| vec![x]
+);
+
+/// Every result of the wrapped function is paired with its own copy of the untouched half.
+impl<A: 'static, B: 'static> Strong<A, B> for Kleisli<VecKind, A, B> {
+    fn first<C: Clone + 'static>(self) -> Self::Pro<(A, C), (B, C)> {
+        Kleisli::new(move |(a, c): (A, C)| {
+            let bs = self.run(a).into_iter();
+            bs.map(|b| (b, c.clone())).collect()
+        })
+    }
+
+    fn second<C: Clone + 'static>(self) -> Self::Pro<(C, A), (C, B)> {
+        Kleisli::new(move |(c, a): (C, A)| {
+            let bs = self.run(a).into_iter();
+            bs.map(|b| (c.clone(), b)).collect()
+        })
+    }
+}
+
+// Implements Profunctor, Strong and Choice for `Kleisli<$kind, _, _>`, where `$kind`
+// wraps a function from an environment `$env` to an `$inner` effect: given how to run a
+// wrapped value `$w` at the environment `$r`, how to wrap a closure back up, and how to
+// map and lift values of `$inner`. The function may run more than once, so the untouched
+// half of a pair, or the other side of a `Result`, is cloned for every run.
+macro_rules! impl_kleisli_reader {
+    (
+        [$($gen:tt)*] $kind:ty, env: $env:ty,
+        run: |$w:pat, $r:ident| $run:expr,
+        wrap: $wrap:path,
+        map: |$fa:ident, $f:ident| $map:expr,
+        pure: |$x:ident| $pure:expr
+    ) => {
+        impl<$($gen)* B, C> Profunctor<B, C> for Kleisli<$kind, B, C> {
+            type Pro<T, U> = Kleisli<$kind, T, U>;
+            fn dimap<A, D, A2B, C2D>(self, a2b: A2B, c2d: C2D) -> Self::Pro<A, D>
+            where
+                A2B: Fn(A) -> B + 'static,
+                C2D: Fn(C) -> D + 'static,
+                A: 'static,
+                B: 'static,
+                C: 'static,
+                D: 'static,
+            {
+                let c2d = Rc::new(c2d);
+                Kleisli::new(move |a: A| {
+                    let $w = self.run(a2b(a));
+                    let c2d = c2d.clone();
+                    $wrap(move |$r: $env| {
+                        let $fa = $run;
+                        let $f = |c: C| c2d(c);
+                        $map
+                    })
+                })
+            }
+        }
+
+        impl<$($gen)* A: 'static, B: 'static> Strong<A, B> for Kleisli<$kind, A, B> {
+            fn first<C: Clone + 'static>(self) -> Self::Pro<(A, C), (B, C)> {
+                Kleisli::new(move |(a, c): (A, C)| {
+                    let $w = self.run(a);
+                    $wrap(move |$r: $env| {
+                        let $fa = $run;
+                        let $f = |b: B| (b, c.clone());
+                        $map
+                    })
+                })
+            }
+
+            fn second<C: Clone + 'static>(self) -> Self::Pro<(C, A), (C, B)> {
+                Kleisli::new(move |(c, a): (C, A)| {
+                    let $w = self.run(a);
+                    $wrap(move |$r: $env| {
+                        let $fa = $run;
+                        let $f = |b: B| (c.clone(), b);
+                        $map
+                    })
+                })
+            }
+        }
+
+        impl<$($gen)* A: 'static, B: 'static> Choice<A, B> for Kleisli<$kind, A, B> {
+            fn left<C: Clone + 'static>(self) -> Self::Pro<Result<C, A>, Result<C, B>> {
+                Kleisli::new(move |r: Result<C, A>| match r {
+                    Ok(c) => $wrap(move |_: $env| {
+                        let $x = Ok(c.clone());
+                        $pure
+                    }),
+                    Err(a) => {
+                        let $w = self.run(a);
+                        $wrap(move |$r: $env| {
+                            let $fa = $run;
+                            let $f = Err;
+                            $map
+                        })
+                    }
+                })
+            }
+
+            fn right<C: Clone + 'static>(self) -> Self::Pro<Result<A, C>, Result<B, C>> {
+                Kleisli::new(move |r: Result<A, C>| match r {
+                    Ok(a) => {
+                        let $w = self.run(a);
+                        $wrap(move |$r: $env| {
+                            let $fa = $run;
+                            let $f = Ok;
+                            $map
+                        })
+                    }
+                    Err(c) => $wrap(move |_: $env| {
+                        let $x = Err(c.clone());
+                        $pure
+                    }),
+                })
+            }
+        }
+    };
+}
+
+impl_kleisli_reader!(
+    [R: 'static,] CFnKind<R>, env: R,
+    run: |w, r| w.call(r),
+    wrap: CFn::new,
+    map: |fa, f| f(fa),
+    pure: |x| x
+);
+impl_kleisli_reader!(
+    [R: 'static,] RcFnKind<R>, env: R,
+    run: |w, r| w.call(r),
+    wrap: RcFn::new,
+    map: |fa, f| f(fa),
+    pure: |x| x
+);
+impl_kleisli_reader!(
+    [R: 'static,] CFnOnceKind<R>, env: R,
+    run: |w, r| w.call_once(r),
+    wrap: CFnOnce::new,
+    map: |fa, f| f(fa),
+    pure: |x| x
+);
+impl_kleisli_reader!(
+    [R: 'static,] CFnMutKind<R>, env: R,
+    run: |mut w, r| w.call_mut(r),
+    wrap: CFnMut::new,
+    map: |fa, f| f(fa),
+    pure: |x| x
+);
+
+// Implements the reader instances for `ReaderTKind<R, $inner>` and
+// `KleisliKind<$inner, R>`, which both wrap a function `R -> $inner::Of<_>`.
+macro_rules! impl_kleisli_reader_over {
+    ([$($gen:tt)*] $inner:ty, map: |$fa:ident, $f:ident| $map:expr, pure: |$x:ident| $pure:expr) => {
+        impl_kleisli_reader!(
+            [$($gen)* R: 'static,] ReaderTKind<R, $inner>, env: R,
+            run: |w, r| (w.run_reader_t)(r),
+            wrap: ReaderT::new,
+            map: |$fa, $f| $map,
+            pure: |$x| $pure
+        );
+        impl_kleisli_reader!(
+            [$($gen)* R: 'static,] KleisliKind<$inner, R>, env: R,
+            run: |w, r| w.run(r),
+            wrap: Kleisli::new,
+            map: |$fa, $f| $map,
+            pure: |$x| $pure
+        );
+    };
+}
+
+impl_kleisli_reader_over!([] OptionKind, map: |fa, f| fa.map(f), pure: |x| Some(x));
+impl_kleisli_reader_over!([E: 'static,] ResultKind<E>, map: |fa, f| fa.map(f), pure: |x| Ok(x));
+impl_kleisli_reader_over!([] IdentityKind, map: |fa, f| Identity(f(fa.0)), pure: |x| Identity(x));
+impl_kleisli_reader_over!(
+    [] VecKind,
+    map: |fa, f| fa.into_iter().map(f).collect(),
+    pure: |x| vec![x]
+);
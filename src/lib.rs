@@ -8,22 +8,52 @@
 pub mod applicative;
 /// Provides the Kind-based `Apply` trait (an extension of `Functor`) and its implementations.
 pub mod apply;
-/// Defines `CFn` and `CFnOnce` for heap-allocated, callable function wrappers.
+/// Provides the `Semigroupoid`, `Category`, `Arrow` and `ArrowChoice` traits for composable arrows.
+pub mod arrow;
+/// Provides the Kind-based `Extend` and `Comonad` traits, with `NonEmpty`, `Store`, `Env` and `Traced`.
+pub mod comonad;
+/// Composite Kinds: Functor composition (`ComposeKind`), products and coproducts of Kinds.
+pub mod compose;
+/// Defines the `Const` functor and its Kind marker.
+pub mod constant;
+/// Provides the Kind-based `Contravariant`, `Divisible` and `Decidable` traits, with
+/// `Predicate`, `Comparison`, `Equivalence` and `Op`.
+pub mod contravariant;
+/// Extension methods (`fmap`, `ap`, `flat_map`, `pipe`) and free functions (`map`, `bind`,
+/// `apply`, `pure_as`) that infer the Kind through `HasKind`.
+pub mod ext;
+/// Defines `CFn`, `CFnOnce` and the stateful `CFnMut` for heap-allocated, callable function
+/// wrappers, with the shared `RcFn` and the thread-safe `ArcFn` and `SendFnOnce`.
 pub mod function;
 /// Provides the Kind-based `Functor` trait and its implementations.
 pub mod functor;
 /// Defines the `Identity` monad and its Kind marker.
 pub mod identity;
+/// Indexed optics: the `Indexed` profunctor, indexed traversals of `Vec` and maps, `at` and `ix`.
+pub mod indexed;
 /// Core infrastructure for Kind-based programming (Higher-Kinded Types), including `Kind` and `Kind1` traits,
 /// and various Kind marker types (e.g., `OptionKind`).
 pub mod kind_based;
+/// Defines `Kleisli`, an arrow wrapping an effectful function `A -> MKind::Of<B>`.
+pub mod kleisli;
+/// Memoizing wrappers for pure `CFn`s: `memoize`, the bounded `memoize_lru` and `memo_fix`.
+pub mod memo;
 /// Provides the Kind-based `Monad` and `Bind` traits and their implementations.
 pub mod monad;
-/// Implements `Profunctor`, `Strong`, and `Choice` traits, primarily for function types.
+/// Defines the `Semigroup` and `Monoid` traits and their standard instances.
+pub mod monoid;
+/// Defines the `NaturalTransformation` trait and standard conversions between Kinds.
+pub mod natural;
+/// Implements `Profunctor`, `Strong`, and `Choice` traits, primarily for function types,
+/// and the `Lens`, `Prism`, `Iso` and `Traversal` optics built on them.
 pub mod profunctor;
-/// Contains monad transformers like `ReaderT`.
+/// `Send + Sync` counterparts of the Kind traits, for `ArcFn`, `SendFnOnce` and `SendReaderT`.
+pub mod send;
+/// Contains monad transformers like `ReaderT` and its thread-safe `SendReaderT`.
 pub mod transformers;
-/// Utility functions and macros, including `fn0!`, `fn1!`, etc.
+/// Provides the Kind-based `Traversable` trait and helpers deriving `map`/`fold_map` from it.
+pub mod traversable;
+/// Utility functions and macros: `curry2`..`curry8`, `flip`, `on`, `partial!`, `fn0!`, etc.
 pub mod utils;
 
 /// Contains legacy (non-Kind-based, associated type-based) implementations of functional traits.
@@ -34,22 +64,33 @@ pub mod legacy;
 // Public re-exports of core traits (now default to Kind-based versions)
 pub use applicative::Applicative; // Points to applicative::kind::Applicative
 pub use apply::Apply; // Points to apply::kind::Apply
+pub use arrow::{Arrow, ArrowChoice, Category, Semigroupoid};
+pub use comonad::{Comonad, Extend};
+pub use contravariant::{Contravariant, Decidable, Decide, Divide, Divisible};
+pub use ext::{ApplyExt, BindExt, FunctorExt, Pipe};
 pub use functor::Functor; // Points to functor::kind::Functor
 pub use monad::{Bind, Monad}; // Points to monad::kind::Bind and monad::kind::Monad
-pub use profunctor::{Choice, Profunctor, Strong};
+pub use monoid::{Monoid, Semigroup};
+pub use natural::NaturalTransformation;
+pub use profunctor::{Choice, Closed, Cochoice, Costrong, Profunctor, Strong};
 pub use transformers::reader::MonadReader; // Points to transformers::reader::kind::MonadReader
+pub use traversable::Traversable;
 
 // Public re-exports of key structs/types (optional, but can be convenient)
+pub use constant::Const;
 pub use function::{CFn, CFnOnce};
 pub use identity::Identity; // Points to identity::kind::Identity
 pub use transformers::reader::{Reader, ReaderT}; // Points to transformers::reader::kind::ReaderT etc.
 
 // Re-export Kind markers and core Kind traits by default
+pub use crate::compose::{ComposeKind, CoproductKind, ProductKind};
+pub use crate::constant::ConstKind;
 pub use crate::identity::IdentityKind; // Changed from IdentityHKTMarker
 pub use crate::transformers::reader::ReaderTKind;
 pub use kind_based::kind::{
     CFnKind,
     CFnOnceKind,
+    HasKind,
     Kind,
     Kind1, // Core Kind traits
     OptionKind,
@@ -0,0 +1,184 @@
+//! # Memoization for the `monadify` library
+//!
+//! A pure [`CFn`] returns the same result whenever it's given the same argument, so an
+//! expensive one can cache its results and skip the work on later calls:
+//!
+//! - [`memoize`]: caches every result in a `HashMap`.
+//! - [`memoize_lru`]: keeps only the `capacity` most recently used results.
+//! - [`memo_fix`]: memoizes a recursive definition, caching the recursive calls too.
+//!
+//! The returned `CFn` owns its cache: results are shared by every call to that `CFn`, and
+//! dropped with it. The cache lives in a `RefCell`, so the memoized function, like the
+//! `CFn` it wraps, stays on one thread. Only memoize pure functions: a function with side
+//! effects runs them once per key, on the first call.
+//!
+//! ## Example
+//! ```
+//! use monadify::function::CFn;
+//! use monadify::memo::memoize;
+//! use std::cell::Cell;
+//! use std::rc::Rc;
+//!
+//! let runs = Rc::new(Cell::new(0));
+//! let counter = runs.clone();
+//! let slow_square = CFn::new(move |x: u64| {
+//!     counter.set(counter.get() + 1);
+//!     x * x
+//! });
+//!
+//! let square = memoize(slow_square);
+//! assert_eq!(square.call(12), 144);
+//! assert_eq!(square.call(12), 144);
+//! assert_eq!(runs.get(), 1);
+//! ```
+
+use crate::function::CFn;
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap};
+use std::hash::Hash;
+
+/// Caches the results of the pure function `f` by argument.
+///
+/// `f` runs at most once per distinct argument; later calls clone the cached result.
+pub fn memoize<A, B>(f: CFn<A, B>) -> CFn<A, B>
+where
+    A: Hash + Eq + Clone + 'static,
+    B: Clone + 'static,
+{
+    let cache: RefCell<HashMap<A, B>> = RefCell::new(HashMap::new());
+    CFn::new(move |a: A| {
+        if let Some(b) = cache.borrow().get(&a) {
+            return b.clone();
+        }
+        // The cache isn't borrowed while `f` runs, in case `f` calls back into it.
+        let b = f.call(a.clone());
+        cache.borrow_mut().insert(a, b.clone());
+        b
+    })
+}
+
+/// Caches the results of the pure function `f`, keeping at most `capacity` of them.
+///
+/// When the cache is full, the least recently used result is evicted to make room. With a
+/// `capacity` of zero nothing is cached and every call runs `f`.
+///
+/// # Examples
+/// ```
+/// use monadify::function::CFn;
+/// use monadify::memo::memoize_lru;
+///
+/// let shout = memoize_lru(CFn::new(|s: String| s.to_uppercase()), 2);
+/// assert_eq!(shout.call("hi".to_string()), "HI");
+/// ```
+pub fn memoize_lru<A, B>(f: CFn<A, B>, capacity: usize) -> CFn<A, B>
+where
+    A: Hash + Eq + Clone + 'static,
+    B: Clone + 'static,
+{
+    let cache = RefCell::new(LruCache::new(capacity));
+    CFn::new(move |a: A| {
+        if let Some(b) = cache.borrow_mut().get(&a) {
+            return b;
+        }
+        let b = f.call(a.clone());
+        cache.borrow_mut().insert(a, b.clone());
+        b
+    })
+}
+
+/// Memoizes a recursive function, caching its recursive calls as well.
+///
+/// `f` receives the memoized function itself as its first argument and must recurse
+/// through it rather than calling itself directly. Each argument is computed once, which
+/// turns exponential recursions such as the naive Fibonacci into linear ones.
+///
+/// # Examples
+/// ```
+/// use monadify::memo::memo_fix;
+///
+/// let fib = memo_fix(|fib: &dyn Fn(u64) -> u64, n: u64| {
+///     if n < 2 {
+///         n
+///     } else {
+///         fib(n - 1) + fib(n - 2)
+///     }
+/// });
+/// assert_eq!(fib.call(90), 2_880_067_194_370_816_120);
+/// ```
+pub fn memo_fix<A, B>(f: impl Fn(&dyn Fn(A) -> B, A) -> B + 'static) -> CFn<A, B>
+where
+    A: Hash + Eq + Clone + 'static,
+    B: Clone + 'static,
+{
+    fn go<A, B, F>(f: &F, cache: &RefCell<HashMap<A, B>>, a: A) -> B
+    where
+        A: Hash + Eq + Clone,
+        B: Clone,
+        F: Fn(&dyn Fn(A) -> B, A) -> B,
+    {
+        if let Some(b) = cache.borrow().get(&a) {
+            return b.clone();
+        }
+        let b = f(&|next: A| go(f, cache, next), a.clone());
+        cache.borrow_mut().insert(a, b.clone());
+        b
+    }
+
+    let cache: RefCell<HashMap<A, B>> = RefCell::new(HashMap::new());
+    CFn::new(move |a: A| go(&f, &cache, a))
+}
+
+/// A map of at most `capacity` entries that evicts the least recently used one.
+///
+/// Every access stamps the entry with a new tick; `by_tick` orders the keys by their last
+/// use, so the oldest is its first entry.
+struct LruCache<K, V> {
+    capacity: usize,
+    tick: u64,
+    entries: HashMap<K, (V, u64)>,
+    by_tick: BTreeMap<u64, K>,
+}
+
+impl<K: Hash + Eq + Clone, V: Clone> LruCache<K, V> {
+    fn new(capacity: usize) -> Self {
+        LruCache {
+            capacity,
+            tick: 0,
+            entries: HashMap::new(),
+            by_tick: BTreeMap::new(),
+        }
+    }
+
+    fn next_tick(&mut self) -> u64 {
+        self.tick += 1;
+        self.tick
+    }
+
+    /// Returns the cached value for `key`, marking it as the most recently used.
+    fn get(&mut self, key: &K) -> Option<V> {
+        let tick = self.next_tick();
+        let (value, last_used) = self.entries.get_mut(key)?;
+        let key = self.by_tick.remove(last_used)?;
+        *last_used = tick;
+        let value = value.clone();
+        self.by_tick.insert(tick, key);
+        Some(value)
+    }
+
+    /// Stores `value` for `key`, evicting the least recently used entry if full.
+    fn insert(&mut self, key: K, value: V) {
+        if self.capacity == 0 {
+            return;
+        }
+        if let Some((_, last_used)) = self.entries.remove(&key) {
+            self.by_tick.remove(&last_used);
+        } else if self.entries.len() == self.capacity {
+            if let Some((_, oldest)) = self.by_tick.pop_first() {
+                self.entries.remove(&oldest);
+            }
+        }
+        let tick = self.next_tick();
+        self.by_tick.insert(tick, key.clone());
+        self.entries.insert(key, (value, tick));
+    }
+}
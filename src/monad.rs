@@ -43,9 +43,9 @@ pub mod kind {
 
     use crate::applicative::kind::Applicative; // Kind-based Applicative
     use crate::apply::kind::Apply; // Kind-based Apply
-    use crate::function::{CFn, CFnOnce};
+    use crate::function::{CFn, CFnMut, CFnOnce, RcFn};
     use crate::kind_based::kind::{
-        CFnKind, CFnOnceKind, Kind, Kind1, OptionKind, ResultKind, VecKind,
+        CFnKind, CFnMutKind, CFnOnceKind, Kind, Kind1, OptionKind, RcFnKind, ResultKind, VecKind,
     };
 
     /// Kind-based `Monad` trait.
@@ -220,6 +220,25 @@ pub mod kind {
         }
     }
 
+    // Bind for RcFnKind<R>: the same Kleisli composition as for CFnKind<R>
+    impl<R, A, B> Bind<A, B> for RcFnKind<R>
+    where
+        R: 'static + Clone,
+        A: 'static,
+        B: 'static,
+    {
+        /// Given `input: R -> A` and `func: A -> (R -> B)`, produces `r -> func(input(r))(r)`.
+        fn bind(
+            input: Self::Of<A>,
+            func: impl FnMut(A) -> Self::Of<B> + Clone + 'static,
+        ) -> Self::Of<B> {
+            RcFn::new(move |r: R| {
+                let a_val = input.call(r.clone());
+                func.clone()(a_val).call(r)
+            })
+        }
+    }
+
     impl<R, A, B: 'static> Bind<A, B> for CFnOnceKind<R>
     // Changed CFnOnceHKTMarker to CFnOnceKind
     where
@@ -247,6 +266,25 @@ pub mod kind {
         }
     }
 
+    // Bind for CFnMutKind<R>: Kleisli composition of stateful functions. `input` keeps its
+    // state between calls; each function returned by `func` is called once and dropped.
+    impl<R, A, B> Bind<A, B> for CFnMutKind<R>
+    where
+        R: 'static + Clone,
+        A: 'static,
+        B: 'static,
+    {
+        fn bind(
+            mut input: Self::Of<A>,
+            mut func: impl FnMut(A) -> Self::Of<B> + Clone + 'static,
+        ) -> Self::Of<B> {
+            CFnMut::new(move |r: R| {
+                let a_val = input.call_mut(r.clone());
+                func(a_val).call_mut(r)
+            })
+        }
+    }
+
     // --- Monad Implementations ---
 
     impl<A: 'static> Monad<A> for OptionKind {
@@ -303,6 +341,17 @@ pub mod kind {
         }
     }
 
+    impl<R, A> Monad<A> for RcFnKind<R>
+    where
+        R: 'static + Clone,
+        A: 'static + Clone, // From Applicative supertrait for RcFnKind<R>
+    {
+        /// Flattens `RcFn<R, RcFn<R, A>>` to `RcFn<R, A>`, passing the same `r` to both layers.
+        fn join(mma: Self::Of<Self::Of<A>>) -> Self::Of<A> {
+            <Self as Bind<Self::Of<A>, A>>::bind(mma, |ma: Self::Of<A>| ma)
+        }
+    }
+
     impl<R, A> Monad<A> for CFnOnceKind<R>
     // Changed CFnOnceHKTMarker to CFnOnceKind
     where
@@ -0,0 +1,124 @@
+//! # Semigroups and Monoids for the `monadify` library
+//!
+//! A [`Semigroup`] is a type with an associative binary operation, [`Semigroup::combine`].
+//! A [`Monoid`] is a `Semigroup` with an identity element, [`Monoid::empty`].
+//!
+//! Monoids are what allow "summary" structures such as [`crate::constant::Const`]
+//! to accumulate results, e.g. when folding a structure with `fold_map`.
+//!
+//! ## Laws
+//! - **Associativity**: `a.combine(b).combine(c) == a.combine(b.combine(c))`
+//! - **Left identity**: `M::empty().combine(a) == a`
+//! - **Right identity**: `a.combine(M::empty()) == a`
+//!
+//! ## Example
+//! ```
+//! use monadify::monoid::{concat_all, First, Monoid, Semigroup, Sum};
+//!
+//! assert_eq!("ab".to_string().combine("cd".to_string()), "abcd");
+//! assert_eq!(Vec::<i32>::empty(), vec![]);
+//! assert_eq!(concat_all(vec![Sum(1), Sum(2), Sum(3)]), Sum(6));
+//! assert_eq!(concat_all(vec![None, Some(First(1)), Some(First(2))]), Some(First(1)));
+//! ```
+
+use std::ops::Add;
+
+/// A type with an associative binary operation.
+pub trait Semigroup {
+    /// Combines `self` with `other`. `self` is the left operand.
+    fn combine(self, other: Self) -> Self;
+}
+
+/// A [`Semigroup`] with an identity element.
+pub trait Monoid: Semigroup {
+    /// The identity element for [`Semigroup::combine`].
+    fn empty() -> Self;
+}
+
+impl Semigroup for String {
+    /// Concatenates the two strings.
+    fn combine(mut self, other: Self) -> Self {
+        self.push_str(&other);
+        self
+    }
+}
+
+impl Monoid for String {
+    fn empty() -> Self {
+        String::new()
+    }
+}
+
+impl<T> Semigroup for Vec<T> {
+    /// Appends `other` to the end of `self`.
+    fn combine(mut self, mut other: Self) -> Self {
+        self.append(&mut other);
+        self
+    }
+}
+
+impl<T> Monoid for Vec<T> {
+    fn empty() -> Self {
+        Vec::new()
+    }
+}
+
+impl Semigroup for () {
+    fn combine(self, _other: Self) -> Self {}
+}
+
+impl Monoid for () {
+    fn empty() -> Self {}
+}
+
+/// `Option<S>` is a monoid for any semigroup `S`: `None` is the identity and two
+/// `Some` values are combined with `S::combine`.
+impl<S: Semigroup> Semigroup for Option<S> {
+    fn combine(self, other: Self) -> Self {
+        match (self, other) {
+            (Some(a), Some(b)) => Some(a.combine(b)),
+            (Some(a), None) => Some(a),
+            (None, b) => b,
+        }
+    }
+}
+
+impl<S: Semigroup> Monoid for Option<S> {
+    fn empty() -> Self {
+        None
+    }
+}
+
+/// A wrapper whose monoid is addition, with `T::default()` (zero for numbers) as identity.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Sum<T>(pub T);
+
+impl<T: Add<Output = T>> Semigroup for Sum<T> {
+    fn combine(self, other: Self) -> Self {
+        Sum(self.0 + other.0)
+    }
+}
+
+impl<T: Add<Output = T> + Default> Monoid for Sum<T> {
+    fn empty() -> Self {
+        Sum(T::default())
+    }
+}
+
+/// A wrapper whose semigroup keeps the left operand.
+///
+/// `First<T>` has no identity element, but `Option<First<T>>` is a monoid that
+/// keeps the first `Some` value, which is how the first match of a fold is found.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct First<T>(pub T);
+
+impl<T> Semigroup for First<T> {
+    fn combine(self, _other: Self) -> Self {
+        self
+    }
+}
+
+/// Combines every element of `items` from left to right, starting from [`Monoid::empty`].
+pub fn concat_all<M: Monoid>(items: impl IntoIterator<Item = M>) -> M {
+    items.into_iter().fold(M::empty(), M::combine)
+}
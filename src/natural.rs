@@ -0,0 +1,166 @@
+//! # Natural Transformations for the `monadify` library
+//!
+//! A natural transformation `F ~> G` converts a structure `F::Of<A>` into a structure
+//! `G::Of<A>` without looking at (or changing) the values of type `A` inside it.
+//! Examples are `Option<A> -> Vec<A>` or `Result<A, E> -> Option<A>`.
+//!
+//! [`NaturalTransformation::transform`] is generic over the element type `A`, so a
+//! transformation works for every `A` at once. That is what code taking a transformation
+//! as a parameter relies on, e.g. to swap the base monad of a transformer or to interpret
+//! a program into another monad. Instances therefore can't put requirements on `A`:
+//! [`IdentityToApplicative`] asks for a [`Pointed`] target, which can lift any `A`.
+//! Applicatives that need `A: Clone` (functions, readers, [`Kleisli`](crate::kleisli::Kleisli))
+//! are reached through [`IdentityToApplicative::lift`] instead.
+//!
+//! `Self` is a small marker struct naming the transformation, e.g. [`OptionToVec`].
+//!
+//! ## Naturality Law
+//! For any transformation `N: F ~> G` and any function `f: A -> B`:
+//! `N::transform(F::map(fa, f)) == G::map(N::transform(fa), f)`.
+//!
+//! ## Example
+//! ```
+//! use monadify::natural::{NaturalTransformation, OptionToVec, ResultToOption, Composed};
+//! use monadify::kind_based::kind::{OptionKind, ResultKind, VecKind};
+//!
+//! let v: Vec<i32> = OptionToVec::transform(Some(3));
+//! assert_eq!(v, vec![3]);
+//!
+//! let o: Option<i32> = ResultToOption::transform(Err::<i32, _>("boom".to_string()));
+//! assert_eq!(o, None);
+//!
+//! // Result ~> Option ~> Vec
+//! type ResultToVec = Composed<ResultToOption, OptionToVec, OptionKind>;
+//! let v: Vec<i32> =
+//!     <ResultToVec as NaturalTransformation<ResultKind<String>, VecKind>>::transform(Ok(7));
+//! assert_eq!(v, vec![7]);
+//! ```
+
+use crate::applicative::kind::Applicative;
+use crate::compose::ComposeKind;
+use crate::constant::{Const, ConstKind};
+use crate::identity::{Identity, IdentityKind};
+use crate::kind_based::kind::{Kind, OptionKind, ResultKind, VecKind};
+use crate::monoid::Monoid;
+use std::marker::PhantomData;
+
+/// A transformation from the type constructor `F` to the type constructor `G`
+/// that preserves the element type.
+///
+/// # Type Parameters
+/// - `F`: The Kind marker of the source structure (e.g., [`OptionKind`]).
+/// - `G`: The Kind marker of the target structure (e.g., [`VecKind`]).
+pub trait NaturalTransformation<F: Kind, G: Kind> {
+    /// Converts `F::Of<A>` into `G::Of<A>`, for any element type `A`.
+    fn transform<A>(fa: F::Of<A>) -> G::Of<A>;
+}
+
+/// A Kind that can lift a value of any type into its structure.
+///
+/// `point` agrees with [`Applicative::pure`], but is generic over the element on the
+/// method rather than on the trait. It is implemented for every Kind whose `pure` needs
+/// nothing from the element; Kinds whose `pure` clones it (functions, readers and
+/// [`ProductKind`](crate::compose::ProductKind), which keeps two copies) can't be.
+pub trait Pointed: Kind {
+    /// Lifts `value` into the structure.
+    fn point<A>(value: A) -> Self::Of<A>;
+}
+
+impl Pointed for OptionKind {
+    fn point<A>(value: A) -> Option<A> {
+        Some(value)
+    }
+}
+
+impl<E> Pointed for ResultKind<E> {
+    fn point<A>(value: A) -> Result<A, E> {
+        Ok(value)
+    }
+}
+
+impl Pointed for VecKind {
+    fn point<A>(value: A) -> Vec<A> {
+        vec![value]
+    }
+}
+
+impl Pointed for IdentityKind {
+    fn point<A>(value: A) -> Identity<A> {
+        Identity(value)
+    }
+}
+
+impl<C: Monoid> Pointed for ConstKind<C> {
+    /// Drops `value` and stores `C::empty()`.
+    fn point<A>(_value: A) -> Const<C, A> {
+        Const::new(C::empty())
+    }
+}
+
+impl<F: Pointed, G: Pointed> Pointed for ComposeKind<F, G> {
+    fn point<A>(value: A) -> F::Of<G::Of<A>> {
+        F::point(G::point(value))
+    }
+}
+
+/// `Option ~> Vec`: `Some(a)` becomes `vec![a]`, `None` becomes `vec![]`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct OptionToVec;
+
+impl NaturalTransformation<OptionKind, VecKind> for OptionToVec {
+    fn transform<A>(fa: Option<A>) -> Vec<A> {
+        fa.into_iter().collect()
+    }
+}
+
+/// `Result<_, E> ~> Option`: `Ok(a)` becomes `Some(a)`, any `Err` becomes `None`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct ResultToOption;
+
+impl<E> NaturalTransformation<ResultKind<E>, OptionKind> for ResultToOption {
+    fn transform<A>(fa: Result<A, E>) -> Option<A> {
+        fa.ok()
+    }
+}
+
+/// `Identity ~> G` for any [`Pointed`] `G`: unwraps the value and lifts it with `G::point`.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct IdentityToApplicative;
+
+impl IdentityToApplicative {
+    /// Unwraps the value and lifts it with `G::pure`, for any [`Applicative`] `G`.
+    ///
+    /// Unlike [`NaturalTransformation::transform`] this is fixed to one element type, so
+    /// it also covers Applicatives whose `pure` needs `A: Clone`, such as
+    /// [`ReaderT`](crate::transformers::reader::ReaderT) or `CFnKind`.
+    pub fn lift<G: Applicative<A>, A: 'static>(fa: Identity<A>) -> G::Of<A> {
+        G::pure(fa.0)
+    }
+}
+
+impl<G: Pointed> NaturalTransformation<IdentityKind, G> for IdentityToApplicative {
+    fn transform<A>(fa: Identity<A>) -> G::Of<A> {
+        G::point(fa.0)
+    }
+}
+
+/// The composition of two natural transformations `N1: F ~> G` and `N2: G ~> H`,
+/// giving `F ~> H`.
+///
+/// `G` is the intermediate Kind; it is part of the type so that the pair of
+/// transformations to chain is unambiguous.
+pub struct Composed<N1, N2, G>(PhantomData<(N1, N2, G)>);
+
+impl<N1, N2, F, G, H> NaturalTransformation<F, H> for Composed<N1, N2, G>
+where
+    F: Kind,
+    G: Kind,
+    H: Kind,
+    N1: NaturalTransformation<F, G>,
+    N2: NaturalTransformation<G, H>,
+{
+    /// Applies `N1` and then `N2`.
+    fn transform<A>(fa: F::Of<A>) -> H::Of<A> {
+        N2::transform(N1::transform(fa))
+    }
+}
@@ -1,6 +1,14 @@
-use std::{marker::PhantomData, ops::Deref};
+use std::{marker::PhantomData, ops::Deref, rc::Rc};
 
-use crate::function::{CFn, CFnOnce};
+use crate::comonad::{Env, EnvKind, NonEmpty, NonEmptyKind};
+use crate::constant::{Const, ConstKind};
+use crate::function::{CFn, CFnMut, CFnOnce, RcFn};
+use crate::functor::kind::Functor;
+use crate::identity::{Identity, IdentityKind};
+use crate::kind_based::kind::{Kind, OptionKind, ResultKind, VecKind};
+use crate::monoid::{First, Monoid};
+use crate::transformers::reader::{ReaderT, ReaderTKind};
+use crate::traversable::Traversable;
 
 /// A `Profunctor` is a bifunctor that is contravariant in its first type parameter
 /// and covariant in its second type parameter.
@@ -73,6 +81,22 @@ impl<B, C> Profunctor<B, C> for CFn<B, C> {
     }
 }
 
+/// `RcFn<B, C>` (a shared function `B -> C`) as a `Profunctor`, exactly like `CFn`.
+impl<B, C> Profunctor<B, C> for RcFn<B, C> {
+    type Pro<T, U> = RcFn<T, U>;
+    fn dimap<A, D, A2B, C2D>(self, a2b: A2B, c2d: C2D) -> Self::Pro<A, D>
+    where
+        A2B: Fn(A) -> B + 'static,
+        C2D: Fn(C) -> D + 'static,
+        C: 'static,
+        B: 'static,
+        A: 'static,
+        D: 'static,
+    {
+        RcFn::new(move |a: A| c2d(self.call(a2b(a))))
+    }
+}
+
 /// `CFnOnce<B, C>` (a boxed, once-callable function `B -> C`) as a `Profunctor`.
 ///
 /// Similar to `CFn`, `dimap` on `h: CFnOnce<B,C>` with `f: A->B` and `g: C->D`
@@ -92,6 +116,25 @@ impl<B, C> Profunctor<B, C> for CFnOnce<B, C> {
     }
 }
 
+/// `CFnMut<B, C>` (a boxed, stateful function `B -> C`) as a `Profunctor`.
+///
+/// `dimap` wraps the function without resetting it: the state it has built up so far
+/// carries over into the new `CFnMut<A,D>`.
+impl<B, C> Profunctor<B, C> for CFnMut<B, C> {
+    type Pro<T, U> = CFnMut<T, U>;
+    fn dimap<A, D, A2B, C2D>(mut self, a2b: A2B, c2d: C2D) -> Self::Pro<A, D>
+    where
+        A2B: Fn(A) -> B + 'static,
+        C2D: Fn(C) -> D + 'static,
+        C: 'static,
+        B: 'static,
+        A: 'static,
+        D: 'static,
+    {
+        CFnMut::new(move |a: A| c2d(self.call_mut(a2b(a))))
+    }
+}
+
 /// `Strong` profunctors are profunctors that can operate on product types (tuples).
 ///
 /// `Strong` extends `Profunctor` with `first` and `second` methods.
@@ -100,15 +143,18 @@ impl<B, C> Profunctor<B, C> for CFnOnce<B, C> {
 /// - `second`: Given `P<A, B>`, produces `P<(C, A), (C, B)>`. It processes the second
 ///   element of a pair, leaving the first untouched.
 ///
+/// The untouched half `C` must be `Clone`: a profunctor whose effect can give more than one
+/// result, such as [`Star`] over `VecKind` or a reader, pairs each result with its own copy.
+///
 /// This is particularly useful for optics like Lenses.
 pub trait Strong<A, B>: Profunctor<A, B> {
     /// Adapts the profunctor to operate on the first component of a pair.
     /// If `self` is `P<A,B>`, `first` returns `P<(A,C), (B,C)>`.
-    fn first<C: 'static>(self) -> Self::Pro<(A, C), (B, C)>;
+    fn first<C: Clone + 'static>(self) -> Self::Pro<(A, C), (B, C)>;
 
     /// Adapts the profunctor to operate on the second component of a pair.
     /// If `self` is `P<A,B>`, `second` returns `P<(C,A), (C,B)>`.
-    fn second<C: 'static>(self) -> Self::Pro<(C, A), (C, B)>;
+    fn second<C: Clone + 'static>(self) -> Self::Pro<(C, A), (C, B)>;
 }
 
 /// `CFn<A, B>` as a `Strong` profunctor.
@@ -126,6 +172,41 @@ impl<A: 'static, B: 'static> Strong<A, B> for CFn<A, B> {
     }
 }
 
+/// `RcFn<A, B>` as a `Strong` profunctor, exactly like `CFn`.
+impl<A: 'static, B: 'static> Strong<A, B> for RcFn<A, B> {
+    fn first<C: 'static>(self) -> Self::Pro<(A, C), (B, C)> {
+        RcFn::new(move |(a, c)| (self.call(a), c))
+    }
+
+    fn second<C: 'static>(self) -> Self::Pro<(C, A), (C, B)> {
+        RcFn::new(move |(c, a)| (c, self.call(a)))
+    }
+}
+
+/// `CFnOnce<A, B>` as a `Strong` profunctor: the untouched half of the pair is moved
+/// into the result.
+impl<A: 'static, B: 'static> Strong<A, B> for CFnOnce<A, B> {
+    fn first<C: 'static>(self) -> Self::Pro<(A, C), (B, C)> {
+        CFnOnce::new(move |(a, c)| (self.call_once(a), c))
+    }
+
+    fn second<C: 'static>(self) -> Self::Pro<(C, A), (C, B)> {
+        CFnOnce::new(move |(c, a)| (c, self.call_once(a)))
+    }
+}
+
+/// `CFnMut<A, B>` as a `Strong` profunctor: the function sees only its component of
+/// each pair, and keeps its state across calls.
+impl<A: 'static, B: 'static> Strong<A, B> for CFnMut<A, B> {
+    fn first<C: 'static>(mut self) -> Self::Pro<(A, C), (B, C)> {
+        CFnMut::new(move |(a, c)| (self.call_mut(a), c))
+    }
+
+    fn second<C: 'static>(mut self) -> Self::Pro<(C, A), (C, B)> {
+        CFnMut::new(move |(c, a)| (c, self.call_mut(a)))
+    }
+}
+
 /// `Choice` profunctors are profunctors that can operate on sum types (`Result`).
 ///
 /// `Choice` extends `Profunctor` with `left` and `right` methods.
@@ -134,17 +215,20 @@ impl<A: 'static, B: 'static> Strong<A, B> for CFn<A, B> {
 /// - `right`: Given `P<A, B>`, produces `P<Result<A, C>, Result<B, C>>`. It processes
 ///   the `Ok` part of a `Result`, leaving `Err` untouched. (Note: standard `Choice` often maps `Right`, here it's `Ok`).
 ///
+/// As with [`Strong`], the untouched side `C` must be `Clone`, so that an effect run more
+/// than once, such as a reader, can hand it back every time.
+///
 /// This is useful for optics like Prisms.
 pub trait Choice<A, B>: Profunctor<A, B> {
     /// Adapts the profunctor to operate on the `Err` variant of a `Result`.
     /// If `self` is `P<A,B>`, `left` returns `P<Result<C,A>, Result<C,B>>`.
     /// (Mapping the second type param of Result, typically `Err` if `Result<Good, Bad>`)
-    fn left<C>(self) -> Self::Pro<Result<C, A>, Result<C, B>>;
+    fn left<C: Clone + 'static>(self) -> Self::Pro<Result<C, A>, Result<C, B>>;
 
     /// Adapts the profunctor to operate on the `Ok` variant of a `Result`.
     /// If `self` is `P<A,B>`, `right` returns `P<Result<A,C>, Result<B,C>>`.
     /// (Mapping the first type param of Result, typically `Ok` if `Result<Good, Bad>`)
-    fn right<C>(self) -> Self::Pro<Result<A, C>, Result<B, C>>;
+    fn right<C: Clone + 'static>(self) -> Self::Pro<Result<A, C>, Result<B, C>>;
 }
 
 /// `CFn<A, B>` as a `Choice` profunctor.
@@ -170,6 +254,189 @@ impl<A: 'static, B: 'static> Choice<A, B> for CFn<A, B> {
     }
 }
 
+/// `RcFn<A, B>` as a `Choice` profunctor, exactly like `CFn`.
+impl<A: 'static, B: 'static> Choice<A, B> for RcFn<A, B> {
+    fn left<C>(self) -> Self::Pro<Result<C, A>, Result<C, B>> {
+        RcFn::new(move |r: Result<C, A>| r.map_err(|a| self.call(a)))
+    }
+
+    fn right<C>(self) -> Self::Pro<Result<A, C>, Result<B, C>> {
+        RcFn::new(move |r: Result<A, C>| r.map(|a| self.call(a)))
+    }
+}
+
+/// `CFnOnce<A, B>` as a `Choice` profunctor, exactly like `CFn`.
+impl<A: 'static, B: 'static> Choice<A, B> for CFnOnce<A, B> {
+    fn left<C>(self) -> Self::Pro<Result<C, A>, Result<C, B>> {
+        CFnOnce::new(move |r: Result<C, A>| r.map_err(|a| self.call_once(a)))
+    }
+
+    fn right<C>(self) -> Self::Pro<Result<A, C>, Result<B, C>> {
+        CFnOnce::new(move |r: Result<A, C>| r.map(|a| self.call_once(a)))
+    }
+}
+
+/// `Closed` profunctors can operate on the results of functions.
+///
+/// `closed`: Given `P<A, B>`, produces `P<X -> A, X -> B>`, post-composing with every
+/// function out of `X`. This is what [`Grate`]s are built from.
+///
+/// Functions are `CFn`s here. `X: Clone` because an instance may need to feed the same
+/// argument to more than one function (see [`Zipping`]).
+pub trait Closed<A, B>: Profunctor<A, B> {
+    /// Adapts the profunctor to operate on the results of functions from `X`.
+    /// If `self` is `P<A,B>`, `closed` returns `P<CFn<X,A>, CFn<X,B>>`.
+    fn closed<X: Clone + 'static>(self) -> Self::Pro<CFn<X, A>, CFn<X, B>>;
+}
+
+/// `CFn<A, B>` as a `Closed` profunctor.
+impl<A: 'static, B: 'static> Closed<A, B> for CFn<A, B> {
+    /// If `self` is `f: A -> B`, `closed` returns `g -> (f . g)`.
+    fn closed<X: Clone + 'static>(self) -> Self::Pro<CFn<X, A>, CFn<X, B>> {
+        let f = Rc::new(self);
+        CFn::new(move |g: CFn<X, A>| {
+            let f = f.clone();
+            CFn::new(move |x| f.call(g.call(x)))
+        })
+    }
+}
+
+/// `Costrong` is the dual of `Strong`: it removes a component from a pair that the
+/// profunctor passes through unchanged.
+///
+/// - `unfirst`: Given `P<(A, C), (B, C)>`, produces `P<A, B>`.
+/// - `unsecond`: Given `P<(C, A), (C, B)>`, produces `P<A, B>`.
+///
+/// Functions are not `Costrong` here: it would take a lazily defined `C` (a fixpoint),
+/// which strict Rust cannot build.
+pub trait Costrong<A, B>: Profunctor<A, B> + Sized {
+    /// Removes the second component of a pair from `p`.
+    fn unfirst<C: 'static>(p: Self::Pro<(A, C), (B, C)>) -> Self;
+
+    /// Removes the first component of a pair from `p`.
+    fn unsecond<C: 'static>(p: Self::Pro<(C, A), (C, B)>) -> Self;
+}
+
+/// `Cochoice` is the dual of `Choice`: it removes a case of a `Result` that the
+/// profunctor never produces from the case it is given.
+///
+/// - `unleft`: Given `P<Result<C, A>, Result<C, B>>`, produces `P<A, B>`.
+/// - `unright`: Given `P<Result<A, C>, Result<B, C>>`, produces `P<A, B>`.
+pub trait Cochoice<A, B>: Profunctor<A, B> + Sized {
+    /// Restricts `p` to the `Err` variant of a `Result`.
+    fn unleft<C: 'static>(p: Self::Pro<Result<C, A>, Result<C, B>>) -> Self;
+
+    /// Restricts `p` to the `Ok` variant of a `Result`.
+    fn unright<C: 'static>(p: Self::Pro<Result<A, C>, Result<B, C>>) -> Self;
+}
+
+/// `CFn<A, B>` as a `Cochoice` profunctor.
+impl<A: 'static, B: 'static> Cochoice<A, B> for CFn<A, B> {
+    /// If `p` is `Result<C,A> -> Result<C,B>`, `unleft` feeds it `Err(a)`, and feeds any
+    /// `Ok(c)` it returns back in until it returns `Err(b)`.
+    fn unleft<C: 'static>(p: CFn<Result<C, A>, Result<C, B>>) -> Self {
+        CFn::new(move |a| {
+            let mut r = p.call(Err(a));
+            loop {
+                match r {
+                    Ok(c) => r = p.call(Ok(c)),
+                    Err(b) => return b,
+                }
+            }
+        })
+    }
+
+    /// If `p` is `Result<A,C> -> Result<B,C>`, `unright` feeds it `Ok(a)`, and feeds any
+    /// `Err(c)` it returns back in until it returns `Ok(b)`.
+    fn unright<C: 'static>(p: CFn<Result<A, C>, Result<B, C>>) -> Self {
+        CFn::new(move |a| {
+            let mut r = p.call(Ok(a));
+            loop {
+                match r {
+                    Ok(b) => return b,
+                    Err(c) => r = p.call(Err(c)),
+                }
+            }
+        })
+    }
+}
+
+/// A traversal function `S -> G::Of<T>`, given an effectful function `A -> G::Of<B>`,
+/// for an Applicative effect `G`.
+///
+/// Rust has no rank-2 types, so a traversal that works for *every* Applicative is
+/// written as a type implementing `Traversing<G, ...>` for all the `G`s it supports.
+/// [`Traversed`] is the traversal of any [`Traversable`] structure.
+pub trait Traversing<G: Kind, S, T, A, B> {
+    /// Runs `func` on every focus `A` of `s`, collecting the effects into `G::Of<T>`.
+    fn traverse_with(&self, s: S, func: impl FnMut(A) -> G::Of<B> + Clone + 'static) -> G::Of<T>;
+}
+
+/// The traversal of a [`Traversable`] structure, visiting every element.
+///
+/// `Traversed<TKind>` implements [`Traversing`] for every Applicative `G` that
+/// `TKind` can be traversed with.
+#[derive(Default)]
+pub struct Traversed<TKind>(PhantomData<TKind>);
+
+impl<TKind> Clone for Traversed<TKind> {
+    fn clone(&self) -> Self {
+        Traversed::new()
+    }
+}
+
+impl<TKind> Traversed<TKind> {
+    /// Creates the traversal of the structure `TKind`.
+    pub fn new() -> Self {
+        Traversed(PhantomData)
+    }
+}
+
+impl<G: Kind, TKind: Traversable<G, A, B>, A, B> Traversing<G, TKind::Of<A>, TKind::Of<B>, A, B>
+    for Traversed<TKind>
+{
+    fn traverse_with(
+        &self,
+        s: TKind::Of<A>,
+        func: impl FnMut(A) -> G::Of<B> + Clone + 'static,
+    ) -> G::Of<TKind::Of<B>> {
+        TKind::traverse(s, func)
+    }
+}
+
+/// `Wander` profunctors are `Strong` and `Choice` profunctors that can also be
+/// lifted through a traversal, focusing on many parts at once.
+///
+/// This is what `Traversal` optics are built from.
+pub trait Wander<A, B>: Strong<A, B> + Choice<A, B> {
+    /// The Applicative effect this profunctor runs traversals with, e.g.
+    /// `IdentityKind` for `CFn` and `ConstKind<R>` for `Forget<R, _, _>`.
+    type Effect: Kind;
+
+    /// Lifts `self: P<A, B>` through `traversal` to `P<S, T>`.
+    fn wander<S: 'static, T: 'static, W>(self, traversal: W) -> Self::Pro<S, T>
+    where
+        W: Traversing<Self::Effect, S, T, A, B> + 'static;
+}
+
+/// `CFn<A, B>` as a `Wander` profunctor: every focus is updated with the function.
+impl<A: 'static, B: 'static> Wander<A, B> for CFn<A, B> {
+    type Effect = IdentityKind;
+
+    fn wander<S: 'static, T: 'static, W>(self, traversal: W) -> Self::Pro<S, T>
+    where
+        W: Traversing<IdentityKind, S, T, A, B> + 'static,
+    {
+        let f = Rc::new(self);
+        CFn::new(move |s: S| {
+            let f = f.clone();
+            traversal
+                .traverse_with(s, move |a: A| Identity(f.call(a)))
+                .0
+        })
+    }
+}
+
 /// Represents a general Optic using Profunctor encoding.
 ///
 /// An Optic `Optic<POuter, PInner, S, T, A, B>` transforms an "inner" profunctor `PInner<A, B>`
@@ -182,17 +449,63 @@ impl<A: 'static, B: 'static> Choice<A, B> for CFn<A, B> {
 /// - `PInner`: The type of profunctor focused on the part `A -> B`.
 /// - `POuter`: The type of profunctor focused on the whole `S -> T`.
 ///
-/// The `optic` field holds a boxed closure that performs this transformation.
+/// The `optic` field holds a shared closure that performs this transformation, so an
+/// optic can be cloned, run any number of times, and composed with [`Optic::then`].
 pub struct Optic<POuter: Profunctor<S, T>, PInner: Profunctor<A, B>, S, T, A, B> {
     /// The function that transforms an inner profunctor to an outer profunctor.
-    /// `Box<dyn FnOnce(PInner) -> POuter>`
-    pub optic: Box<dyn FnOnce(PInner) -> POuter>,
+    /// `Rc<dyn Fn(PInner) -> POuter>`
+    pub optic: Rc<dyn Fn(PInner) -> POuter>,
     _s: PhantomData<S>,
     _t: PhantomData<T>,
     _a: PhantomData<A>,
     _b: PhantomData<B>,
 }
 
+impl<POuter: Profunctor<S, T>, PInner: Profunctor<A, B>, S, T, A, B>
+    Optic<POuter, PInner, S, T, A, B>
+{
+    /// Creates a new `Optic` from a function turning `PInner` into `POuter`.
+    pub fn new(optic: impl Fn(PInner) -> POuter + 'static) -> Self {
+        Optic {
+            optic: Rc::new(optic),
+            _s: PhantomData,
+            _t: PhantomData,
+            _a: PhantomData,
+            _b: PhantomData,
+        }
+    }
+
+    /// Composes `self` (focusing `S` on `A`) with `inner` (focusing `A` on `X`),
+    /// producing an optic focusing `S` on `X`.
+    ///
+    /// The inner profunctor of `self` must be the outer profunctor of `inner`.
+    pub fn then<PI2, X, Y>(
+        self,
+        inner: Optic<PInner, PI2, A, B, X, Y>,
+    ) -> Optic<POuter, PI2, S, T, X, Y>
+    where
+        POuter: 'static,
+        PInner: 'static,
+        PI2: Profunctor<X, Y> + 'static,
+    {
+        Optic::new(move |p: PI2| (self.optic)((inner.optic)(p)))
+    }
+}
+
+impl<POuter: Profunctor<S, T>, PInner: Profunctor<A, B>, S, T, A, B> Clone
+    for Optic<POuter, PInner, S, T, A, B>
+{
+    fn clone(&self) -> Self {
+        Optic {
+            optic: self.optic.clone(),
+            _s: PhantomData,
+            _t: PhantomData,
+            _a: PhantomData,
+            _b: PhantomData,
+        }
+    }
+}
+
 /// A `Lens` is a type of Optic that focuses on a part `A` of a whole `S`,
 /// allowing both getting the part and setting it (which might change `S` to `T`
 /// and `A` to `B`).
@@ -211,124 +524,417 @@ impl<PO: Strong<S, T>, PI: Strong<A, B>, S, T, A, B> Deref for Lens<PO, PI, S, T
     }
 }
 
-/// A `Fold` is an Optic that can extract multiple pieces of data `A` from a structure `S`,
-/// and fold them using a monoid. It's a generalization of a Getter.
-///
-/// Here, it's defined using `Optic` with `Forget` profunctors.
-/// `Forget<R, X, Y>` is a profunctor that "forgets" its `Y` type parameter and
-/// maps its `X` input to a monoidal result `R`.
-///
-/// - `R`: The result type of the fold (often the type `A` itself, or a monoid built from `A`).
-/// - `S`, `T`: Outer structure types (T is often phantom for folds).
-/// - `A`, `B`: Inner part types (B is often phantom for folds).
-pub type Fold<R, S, T, A, B> = Optic<Forget<R, S, T>, Forget<R, A, B>, S, T, A, B>;
+impl<PO: Strong<S, T>, PI: Strong<A, B>, S, T, A, B> Clone for Lens<PO, PI, S, T, A, B> {
+    fn clone(&self) -> Self {
+        Lens(self.0.clone())
+    }
+}
 
-/// An `AGetter` (or simply `Getter`) is a specialized `Fold` that extracts a single part `A`
-/// from a structure `S`.
+impl<PO: Strong<S, T>, PI: Strong<A, B>, S, T, A, B> Lens<PO, PI, S, T, A, B> {
+    /// Composes `self` with `inner`, which focuses further inside the part `A`.
+    /// Composing two lenses gives a lens, e.g. onto a field of a field.
+    pub fn then<PI2, X, Y>(self, inner: Lens<PI, PI2, A, B, X, Y>) -> Lens<PO, PI2, S, T, X, Y>
+    where
+        PO: 'static,
+        PI: 'static,
+        PI2: Strong<X, Y> + 'static,
+    {
+        Lens(self.0.then(inner.0))
+    }
+}
+
+/// A `Prism` is a type of Optic that focuses on one case `A` of a sum type `S`.
+/// The focus may be absent (previewing can fail), but a `B` can always be
+/// turned back into a whole `T` (reviewing).
 ///
-/// It's a `Fold<A, S, T, A, B>`, meaning the fold result `R` is the same as the part type `A`.
-/// `T` and `B` are typically phantom types for getters.
-pub type AGetter<S, T, A, B> = Fold<A, S, T, A, B>;
+/// Prisms are typically built from `Choice` profunctors.
+/// This `Prism` struct wraps an `Optic`.
+pub struct Prism<PO: Choice<S, T>, PI: Choice<A, B>, S, T, A, B>(
+    /// The underlying `Optic` representation of the prism.
+    pub Optic<PO, PI, S, T, A, B>,
+);
 
-impl<PA: Strong<S, T>, PB: Strong<A, B>, S: 'static, T: 'static, A: 'static, B: 'static>
-    From<Lens<PA, PB, S, T, A, B>> for Optic<PA, PB, S, T, A, B>
+impl<PO: Choice<S, T>, PI: Choice<A, B>, S, T, A, B> Deref for Prism<PO, PI, S, T, A, B> {
+    type Target = Optic<PO, PI, S, T, A, B>;
+    fn deref(&self) -> &Optic<PO, PI, S, T, A, B> {
+        &self.0
+    }
+}
+
+impl<PO: Choice<S, T>, PI: Choice<A, B>, S, T, A, B> Clone for Prism<PO, PI, S, T, A, B> {
+    fn clone(&self) -> Self {
+        Prism(self.0.clone())
+    }
+}
+
+impl<PO: Choice<S, T>, PI: Choice<A, B>, S, T, A, B> Prism<PO, PI, S, T, A, B> {
+    /// Composes `self` with `inner`, which focuses further inside the part `A`.
+    /// Composing two prisms gives a prism, e.g. `_some().then(_ok())` focuses on `Some(Ok(_))`.
+    pub fn then<PI2, X, Y>(self, inner: Prism<PI, PI2, A, B, X, Y>) -> Prism<PO, PI2, S, T, X, Y>
+    where
+        PO: 'static,
+        PI: 'static,
+        PI2: Choice<X, Y> + 'static,
+    {
+        Prism(self.0.then(inner.0))
+    }
+}
+
+impl<PO: Choice<S, T>, PI: Choice<A, B>, S, T, A, B> From<Prism<PO, PI, S, T, A, B>>
+    for Optic<PO, PI, S, T, A, B>
 {
-    fn from(value: Lens<PA, PB, S, T, A, B>) -> Self {
+    fn from(value: Prism<PO, PI, S, T, A, B>) -> Self {
         value.0
     }
 }
 
-/// Extracts a value `A` from a structure `S` using a `AGetter`.
-///
-/// # Parameters
-/// - `lens`: The `AGetter<S, T, A, B>` used to focus on the part `A`.
-///   `T` and `B` are typically phantom.
-/// - `s`: The structure `S` from which to extract the part.
+/// An `Iso` is a type of Optic witnessing that `S` and `A` (and `T` and `B`) are
+/// equivalent representations of the same data.
 ///
-/// # Returns
-/// The extracted part `A`.
-pub fn view<S: 'static, T: 'static, A: 'static, B: 'static>(
-    getter: AGetter<S, T, A, B>, // Changed lens to getter for clarity
-    s: S,
-) -> A {
-    // To view with a getter (which is Optic<Forget<A,S,T>, Forget<A,A,B>>),
-    // we provide an "inner" profunctor Forget<A,A,B>.
-    // Forget<A,A,B> needs a function A -> A (identity) for its `inner` field.
-    let inner_profunctor = Forget {
-        inner: CFn::new(|x: A| x), // Identity function A -> A
-        _forget: PhantomData,      // Phantom for B
-    };
-    // Applying the getter's optic transformation:
-    // (getter.optic)(Forget<A,A,B>) results in Forget<A,S,T>.
-    // This Forget<A,S,T> has an `inner` field which is CFn<S,A>.
-    // Calling this CFn<S,A> with `s` gives the result `A`.
-    (getter.optic)(inner_profunctor).inner.call(s)
-}
+/// Isos only need `dimap`, so they work with any `Profunctor`, and every `Iso`
+/// can be converted into a `Lens` or a `Prism`.
+/// This `Iso` struct wraps an `Optic`.
+pub struct Iso<PO: Profunctor<S, T>, PI: Profunctor<A, B>, S, T, A, B>(
+    /// The underlying `Optic` representation of the iso.
+    pub Optic<PO, PI, S, T, A, B>,
+);
 
-/// A `Profunctor` that "forgets" its second type parameter (`BPhantom`) and maps its
-/// first type parameter (`AInput`) to a fixed result type `R`.
-///
-/// `Forget<R, AInput, BPhantom>` takes `AInput -> R` as its core.
-/// When used in `dimap(f: X -> AInput, g: BPhantom -> Y)`, it effectively becomes
-/// `Forget<R, X, Y>` where the new core function is `x -> original_core(f(x))`.
-/// The `g` function and `Y` type are ignored.
-///
-/// This is used in defining `Fold` and `Getter` optics.
-pub struct Forget<R, AInput, BPhantom> {
-    /// The function `AInput -> R` that Forget wraps.
-    pub inner: CFn<AInput, R>, // Made public for construction/inspection
-    _forget: PhantomData<BPhantom>,
+impl<PO: Profunctor<S, T>, PI: Profunctor<A, B>, S, T, A, B> Deref for Iso<PO, PI, S, T, A, B> {
+    type Target = Optic<PO, PI, S, T, A, B>;
+    fn deref(&self) -> &Optic<PO, PI, S, T, A, B> {
+        &self.0
+    }
 }
 
-// AInput here is ProfB from Profunctor<ProfB, ProfC>
-// BPhantom here is ProfC from Profunctor<ProfB, ProfC>
-impl<R: 'static, ProfB: 'static, ProfC> Profunctor<ProfB, ProfC> for Forget<R, ProfB, ProfC> {
-    type Pro<NextA, NextC> = Forget<R, NextA, NextC>;
+impl<PO: Profunctor<S, T>, PI: Profunctor<A, B>, S, T, A, B> Clone for Iso<PO, PI, S, T, A, B> {
+    fn clone(&self) -> Self {
+        Iso(self.0.clone())
+    }
+}
 
-    // self: Forget<R, ProfB, ProfC>
-    // a2b: ImplA -> ProfB
-    // _c2d: ProfC -> ImplD (ignored)
-    // Returns: Forget<R, ImplA, ImplD>
-    fn dimap<ImplA: 'static, ImplD: 'static, A2B, C2D>(
-        self,
-        a2b: A2B,
-        _c2d: C2D, // This function is ignored by Forget's dimap
-    ) -> Self::Pro<ImplA, ImplD>
+impl<PO: Profunctor<S, T>, PI: Profunctor<A, B>, S, T, A, B> Iso<PO, PI, S, T, A, B> {
+    /// Composes `self` with `inner`, which focuses further inside the part `A`.
+    /// Composing two isos gives an iso.
+    pub fn then<PI2, X, Y>(self, inner: Iso<PI, PI2, A, B, X, Y>) -> Iso<PO, PI2, S, T, X, Y>
     where
-        A2B: Fn(ImplA) -> ProfB + 'static,
-        C2D: Fn(ProfC) -> ImplD + 'static, // Type D for _c2d is ImplD
+        PO: 'static,
+        PI: 'static,
+        PI2: Profunctor<X, Y> + 'static,
     {
-        // Original inner: ProfB -> R
-        // New inner: ImplA -> R, via ImplA -> ProfB -> R
-        Forget {
-            inner: CFn::new(a2b) >> self.inner, // Compose a2b with self.inner
-            _forget: PhantomData,               // Phantom for ImplD
-        }
+        Iso(self.0.then(inner.0))
     }
 }
 
-// AInput here is AStrong from Strong<AStrong, BStrong>
-// BPhantom here is BStrong from Strong<AStrong, BStrong>
-impl<R: 'static, AStrong: 'static, BStrong: 'static> Strong<AStrong, BStrong>
-    for Forget<R, AStrong, BStrong>
+impl<PO: Profunctor<S, T>, PI: Profunctor<A, B>, S, T, A, B> From<Iso<PO, PI, S, T, A, B>>
+    for Optic<PO, PI, S, T, A, B>
 {
-    // self: Forget<R, AStrong, BStrong>
-    // first<C: 'static> should return Pro<(AStrong, C), (BStrong, C)>
-    // which is Forget<R, (AStrong, C), (BStrong, C)>
-    fn first<C: 'static>(self) -> Self::Pro<(AStrong, C), (BStrong, C)> {
-        // Original inner: AStrong -> R
-        // New inner: (AStrong, C) -> R. We only care about AStrong.
-        Forget {
-            inner: CFn::new(|(a, _c): (AStrong, C)| a) >> self.inner,
-            _forget: PhantomData, // Phantom for (BStrong, C)
-        }
+    fn from(value: Iso<PO, PI, S, T, A, B>) -> Self {
+        value.0
     }
+}
 
-    // second<C: 'static> should return Pro<(C, AStrong), (C, BStrong)>
-    // which is Forget<R, (C, AStrong), (C, BStrong)>
-    fn second<C: 'static>(self) -> Self::Pro<(C, AStrong), (C, BStrong)> {
-        // Original inner: AStrong -> R
-        // New inner: (C, AStrong) -> R. We only care about AStrong.
+impl<PO: Strong<S, T>, PI: Strong<A, B>, S, T, A, B> From<Iso<PO, PI, S, T, A, B>>
+    for Lens<PO, PI, S, T, A, B>
+{
+    fn from(value: Iso<PO, PI, S, T, A, B>) -> Self {
+        Lens(value.0)
+    }
+}
+
+impl<PO: Choice<S, T>, PI: Choice<A, B>, S, T, A, B> From<Iso<PO, PI, S, T, A, B>>
+    for Prism<PO, PI, S, T, A, B>
+{
+    fn from(value: Iso<PO, PI, S, T, A, B>) -> Self {
+        Prism(value.0)
+    }
+}
+
+/// A `Traversal` is a type of Optic that focuses on zero or more parts `A` of a whole `S`,
+/// e.g. every element of a `Vec`.
+///
+/// Traversals are built from `Wander` profunctors, and every `Lens`, `Prism` and `Iso`
+/// can be converted into one.
+/// This `Traversal` struct wraps an `Optic`.
+pub struct Traversal<PO: Wander<S, T>, PI: Wander<A, B>, S, T, A, B>(
+    /// The underlying `Optic` representation of the traversal.
+    pub Optic<PO, PI, S, T, A, B>,
+);
+
+impl<PO: Wander<S, T>, PI: Wander<A, B>, S, T, A, B> Deref for Traversal<PO, PI, S, T, A, B> {
+    type Target = Optic<PO, PI, S, T, A, B>;
+    fn deref(&self) -> &Optic<PO, PI, S, T, A, B> {
+        &self.0
+    }
+}
+
+impl<PO: Wander<S, T>, PI: Wander<A, B>, S, T, A, B> Clone for Traversal<PO, PI, S, T, A, B> {
+    fn clone(&self) -> Self {
+        Traversal(self.0.clone())
+    }
+}
+
+impl<PO: Wander<S, T>, PI: Wander<A, B>, S, T, A, B> Traversal<PO, PI, S, T, A, B> {
+    /// Composes `self` with `inner`, which focuses further inside the part `A`.
+    /// Composing two traversals gives a traversal over every nested focus.
+    pub fn then<PI2, X, Y>(
+        self,
+        inner: Traversal<PI, PI2, A, B, X, Y>,
+    ) -> Traversal<PO, PI2, S, T, X, Y>
+    where
+        PO: 'static,
+        PI: 'static,
+        PI2: Wander<X, Y> + 'static,
+    {
+        Traversal(self.0.then(inner.0))
+    }
+}
+
+impl<PO: Wander<S, T>, PI: Wander<A, B>, S, T, A, B> From<Traversal<PO, PI, S, T, A, B>>
+    for Optic<PO, PI, S, T, A, B>
+{
+    fn from(value: Traversal<PO, PI, S, T, A, B>) -> Self {
+        value.0
+    }
+}
+
+impl<PO: Wander<S, T>, PI: Wander<A, B>, S, T, A, B> From<Lens<PO, PI, S, T, A, B>>
+    for Traversal<PO, PI, S, T, A, B>
+{
+    fn from(value: Lens<PO, PI, S, T, A, B>) -> Self {
+        Traversal(value.0)
+    }
+}
+
+impl<PO: Wander<S, T>, PI: Wander<A, B>, S, T, A, B> From<Prism<PO, PI, S, T, A, B>>
+    for Traversal<PO, PI, S, T, A, B>
+{
+    fn from(value: Prism<PO, PI, S, T, A, B>) -> Self {
+        Traversal(value.0)
+    }
+}
+
+impl<PO: Wander<S, T>, PI: Wander<A, B>, S, T, A, B> From<Iso<PO, PI, S, T, A, B>>
+    for Traversal<PO, PI, S, T, A, B>
+{
+    fn from(value: Iso<PO, PI, S, T, A, B>) -> Self {
+        Traversal(value.0)
+    }
+}
+
+/// A `Grate` is a type of Optic that focuses on every part `A` of a structure `S` whose
+/// parts can all be read with one shared "index" (e.g. both halves of a pair, or the result
+/// of a function). Unlike a `Traversal`, a `Grate` can combine several wholes part by part
+/// (see [`zip_with_of`]).
+///
+/// Grates are built from `Closed` profunctors, and every `Iso` can be converted into one.
+/// This `Grate` struct wraps an `Optic`.
+pub struct Grate<PO: Closed<S, T>, PI: Closed<A, B>, S, T, A, B>(
+    /// The underlying `Optic` representation of the grate.
+    pub Optic<PO, PI, S, T, A, B>,
+);
+
+impl<PO: Closed<S, T>, PI: Closed<A, B>, S, T, A, B> Deref for Grate<PO, PI, S, T, A, B> {
+    type Target = Optic<PO, PI, S, T, A, B>;
+    fn deref(&self) -> &Optic<PO, PI, S, T, A, B> {
+        &self.0
+    }
+}
+
+impl<PO: Closed<S, T>, PI: Closed<A, B>, S, T, A, B> Clone for Grate<PO, PI, S, T, A, B> {
+    fn clone(&self) -> Self {
+        Grate(self.0.clone())
+    }
+}
+
+impl<PO: Closed<S, T>, PI: Closed<A, B>, S, T, A, B> Grate<PO, PI, S, T, A, B> {
+    /// Composes `self` with `inner`, which focuses further inside the part `A`.
+    /// Composing two grates gives a grate over every nested focus.
+    pub fn then<PI2, X, Y>(self, inner: Grate<PI, PI2, A, B, X, Y>) -> Grate<PO, PI2, S, T, X, Y>
+    where
+        PO: 'static,
+        PI: 'static,
+        PI2: Closed<X, Y> + 'static,
+    {
+        Grate(self.0.then(inner.0))
+    }
+}
+
+impl<PO: Closed<S, T>, PI: Closed<A, B>, S, T, A, B> From<Grate<PO, PI, S, T, A, B>>
+    for Optic<PO, PI, S, T, A, B>
+{
+    fn from(value: Grate<PO, PI, S, T, A, B>) -> Self {
+        value.0
+    }
+}
+
+impl<PO: Closed<S, T>, PI: Closed<A, B>, S, T, A, B> From<Iso<PO, PI, S, T, A, B>>
+    for Grate<PO, PI, S, T, A, B>
+{
+    fn from(value: Iso<PO, PI, S, T, A, B>) -> Self {
+        Grate(value.0)
+    }
+}
+
+/// A `Fold` is an Optic that can extract multiple pieces of data `A` from a structure `S`,
+/// and fold them using a monoid. It's a generalization of a Getter.
+///
+/// Here, it's defined using `Optic` with `Forget` profunctors.
+/// `Forget<R, X, Y>` is a profunctor that "forgets" its `Y` type parameter and
+/// maps its `X` input to a monoidal result `R`.
+///
+/// - `R`: The result type of the fold (often the type `A` itself, or a monoid built from `A`).
+/// - `S`, `T`: Outer structure types (T is often phantom for folds).
+/// - `A`, `B`: Inner part types (B is often phantom for folds).
+pub type Fold<R, S, T, A, B> = Optic<Forget<R, S, T>, Forget<R, A, B>, S, T, A, B>;
+
+/// An `AGetter` (or simply `Getter`) is a specialized `Fold` that extracts a single part `A`
+/// from a structure `S`.
+///
+/// It's a `Fold<A, S, T, A, B>`, meaning the fold result `R` is the same as the part type `A`.
+/// `T` and `B` are typically phantom types for getters.
+pub type AGetter<S, T, A, B> = Fold<A, S, T, A, B>;
+
+/// An `APreview` is a `Fold` that collects the first focus `A` of a structure `S`, if any.
+/// It is what [`preview`] runs a `Prism` as.
+pub type APreview<S, T, A, B> = Fold<Option<First<A>>, S, T, A, B>;
+
+/// An `AReview` is an Optic over [`Tagged`] profunctors, which builds a `T` from a `B`.
+/// It is what [`review`] runs a `Prism` as.
+pub type AReview<S, T, A, B> = Optic<Tagged<S, T>, Tagged<A, B>, S, T, A, B>;
+
+/// An `AnIso` is an `Iso` over [`Exchange`] profunctors, the form in which an iso
+/// can be taken apart by [`with_iso`] and reversed by [`re`].
+pub type AnIso<S, T, A, B> = Iso<Exchange<A, B, S, T>, Exchange<A, B, A, B>, S, T, A, B>;
+
+/// A `Setter` is an Optic over `CFn` profunctors: it turns a function `A -> B`
+/// on the parts into a function `S -> T` on the whole. It is what [`over`] and
+/// [`set`] run an optic as.
+pub type Setter<S, T, A, B> = Optic<CFn<S, T>, CFn<A, B>, S, T, A, B>;
+
+/// A projection `S -> A` out of a whole, shared so that a `Closed` profunctor can pass it
+/// to several functions. It is the index type [`grate`] runs `closed` at.
+pub type Projection<S, A> = Rc<CFn<S, A>>;
+
+/// An `AZipping` is an Optic over [`Zipping`] profunctors, which combines two wholes
+/// part by part. It is what [`zip_with_of`] runs a `Grate` as.
+pub type AZipping<S, T, A, B> = Optic<Zipping<S, T>, Zipping<A, B>, S, T, A, B>;
+
+/// An optic run with `Star<FKind, _, _>`, turning `A -> FKind::Of<B>` into `S -> FKind::Of<T>`
+/// (see [`traverse_of`]).
+pub type ATraversal<FKind, S, T, A, B> = Optic<Star<FKind, S, T>, Star<FKind, A, B>, S, T, A, B>;
+
+impl<PA: Strong<S, T>, PB: Strong<A, B>, S: 'static, T: 'static, A: 'static, B: 'static>
+    From<Lens<PA, PB, S, T, A, B>> for Optic<PA, PB, S, T, A, B>
+{
+    fn from(value: Lens<PA, PB, S, T, A, B>) -> Self {
+        value.0
+    }
+}
+
+/// Extracts a value `A` from a structure `S` using a `AGetter`.
+///
+/// # Parameters
+/// - `lens`: The `AGetter<S, T, A, B>` used to focus on the part `A`.
+///   `T` and `B` are typically phantom.
+/// - `s`: The structure `S` from which to extract the part.
+///
+/// # Returns
+/// The extracted part `A`.
+pub fn view<S: 'static, T: 'static, A: 'static, B: 'static>(
+    getter: AGetter<S, T, A, B>, // Changed lens to getter for clarity
+    s: S,
+) -> A {
+    // To view with a getter (which is Optic<Forget<A,S,T>, Forget<A,A,B>>),
+    // we provide an "inner" profunctor Forget<A,A,B>.
+    // Forget<A,A,B> needs a function A -> A (identity) for its `inner` field.
+    let inner_profunctor = Forget {
+        inner: CFn::new(|x: A| x), // Identity function A -> A
+        _forget: PhantomData,      // Phantom for B
+    };
+    // Applying the getter's optic transformation:
+    // (getter.optic)(Forget<A,A,B>) results in Forget<A,S,T>.
+    // This Forget<A,S,T> has an `inner` field which is CFn<S,A>.
+    // Calling this CFn<S,A> with `s` gives the result `A`.
+    (getter.optic)(inner_profunctor).inner.call(s)
+}
+
+/// A `Profunctor` that "forgets" its second type parameter (`BPhantom`) and maps its
+/// first type parameter (`AInput`) to a fixed result type `R`.
+///
+/// `Forget<R, AInput, BPhantom>` takes `AInput -> R` as its core.
+/// When used in `dimap(f: X -> AInput, g: BPhantom -> Y)`, it effectively becomes
+/// `Forget<R, X, Y>` where the new core function is `x -> original_core(f(x))`.
+/// The `g` function and `Y` type are ignored.
+///
+/// This is used in defining `Fold` and `Getter` optics.
+pub struct Forget<R, AInput, BPhantom> {
+    /// The function `AInput -> R` that Forget wraps.
+    pub inner: CFn<AInput, R>, // Made public for construction/inspection
+    _forget: PhantomData<BPhantom>,
+}
+
+impl<R, AInput, BPhantom> Forget<R, AInput, BPhantom> {
+    /// Creates a new `Forget` from a function `AInput -> R`.
+    pub fn new<F>(f: F) -> Self
+    where
+        F: Fn(AInput) -> R + 'static,
+    {
+        Forget {
+            inner: CFn::new(f),
+            _forget: PhantomData,
+        }
+    }
+}
+
+// AInput here is ProfB from Profunctor<ProfB, ProfC>
+// BPhantom here is ProfC from Profunctor<ProfB, ProfC>
+impl<R: 'static, ProfB: 'static, ProfC> Profunctor<ProfB, ProfC> for Forget<R, ProfB, ProfC> {
+    type Pro<NextA, NextC> = Forget<R, NextA, NextC>;
+
+    // self: Forget<R, ProfB, ProfC>
+    // a2b: ImplA -> ProfB
+    // _c2d: ProfC -> ImplD (ignored)
+    // Returns: Forget<R, ImplA, ImplD>
+    fn dimap<ImplA: 'static, ImplD: 'static, A2B, C2D>(
+        self,
+        a2b: A2B,
+        _c2d: C2D, // This function is ignored by Forget's dimap
+    ) -> Self::Pro<ImplA, ImplD>
+    where
+        A2B: Fn(ImplA) -> ProfB + 'static,
+        C2D: Fn(ProfC) -> ImplD + 'static, // Type D for _c2d is ImplD
+    {
+        // Original inner: ProfB -> R
+        // New inner: ImplA -> R, via ImplA -> ProfB -> R
+        Forget {
+            inner: CFn::new(a2b) >> self.inner, // Compose a2b with self.inner
+            _forget: PhantomData,               // Phantom for ImplD
+        }
+    }
+}
+
+// AInput here is AStrong from Strong<AStrong, BStrong>
+// BPhantom here is BStrong from Strong<AStrong, BStrong>
+impl<R: 'static, AStrong: 'static, BStrong: 'static> Strong<AStrong, BStrong>
+    for Forget<R, AStrong, BStrong>
+{
+    // self: Forget<R, AStrong, BStrong>
+    // first<C: 'static> should return Pro<(AStrong, C), (BStrong, C)>
+    // which is Forget<R, (AStrong, C), (BStrong, C)>
+    fn first<C: 'static>(self) -> Self::Pro<(AStrong, C), (BStrong, C)> {
+        // Original inner: AStrong -> R
+        // New inner: (AStrong, C) -> R. We only care about AStrong.
+        Forget {
+            inner: CFn::new(|(a, _c): (AStrong, C)| a) >> self.inner,
+            _forget: PhantomData, // Phantom for (BStrong, C)
+        }
+    }
+
+    // second<C: 'static> should return Pro<(C, AStrong), (C, BStrong)>
+    // which is Forget<R, (C, AStrong), (C, BStrong)>
+    fn second<C: 'static>(self) -> Self::Pro<(C, AStrong), (C, BStrong)> {
+        // Original inner: AStrong -> R
+        // New inner: (C, AStrong) -> R. We only care about AStrong.
         Forget {
             inner: CFn::new(|(_c, a): (C, AStrong)| a) >> self.inner,
             _forget: PhantomData, // Phantom for (C, BStrong)
@@ -336,6 +942,677 @@ impl<R: 'static, AStrong: 'static, BStrong: 'static> Strong<AStrong, BStrong>
     }
 }
 
+// Forget can only be Choice when R is a Monoid: an input in the other case
+// has nothing to map, so it produces `R::empty()`.
+impl<R: Monoid + 'static, AChoice: 'static, BChoice: 'static> Choice<AChoice, BChoice>
+    for Forget<R, AChoice, BChoice>
+{
+    fn left<C>(self) -> Self::Pro<Result<C, AChoice>, Result<C, BChoice>> {
+        Forget {
+            inner: CFn::new(move |r: Result<C, AChoice>| match r {
+                Ok(_) => R::empty(),
+                Err(a) => self.inner.call(a),
+            }),
+            _forget: PhantomData,
+        }
+    }
+
+    fn right<C>(self) -> Self::Pro<Result<AChoice, C>, Result<BChoice, C>> {
+        Forget {
+            inner: CFn::new(move |r: Result<AChoice, C>| match r {
+                Ok(a) => self.inner.call(a),
+                Err(_) => R::empty(),
+            }),
+            _forget: PhantomData,
+        }
+    }
+}
+
+/// `Forget<R, A, B>` as a `Cochoice` profunctor: the restricted fold runs `p` on `Err(a)`
+/// (for `unleft`) or `Ok(a)` (for `unright`).
+impl<R: 'static, A: 'static, B: 'static> Cochoice<A, B> for Forget<R, A, B> {
+    fn unleft<C: 'static>(p: Forget<R, Result<C, A>, Result<C, B>>) -> Self {
+        Forget::new(move |a| p.inner.call(Err(a)))
+    }
+
+    fn unright<C: 'static>(p: Forget<R, Result<A, C>, Result<B, C>>) -> Self {
+        Forget::new(move |a| p.inner.call(Ok(a)))
+    }
+}
+
+/// `Forget<R, A, B>` as a `Wander` profunctor: the traversal is run with `Const<R, _>`,
+/// combining the results for every focus with `R`'s `Monoid` instance.
+impl<R: Monoid + 'static, A: 'static, B: 'static> Wander<A, B> for Forget<R, A, B> {
+    type Effect = ConstKind<R>;
+
+    fn wander<S: 'static, T: 'static, W>(self, traversal: W) -> Self::Pro<S, T>
+    where
+        W: Traversing<ConstKind<R>, S, T, A, B> + 'static,
+    {
+        let inner = Rc::new(self.inner);
+        Forget {
+            inner: CFn::new(move |s: S| {
+                let inner = inner.clone();
+                traversal
+                    .traverse_with(s, move |a: A| Const::new(inner.call(a)))
+                    .get_const()
+            }),
+            _forget: PhantomData,
+        }
+    }
+}
+
+/// A `Profunctor` that ignores its input type `A` and just holds an output value `B`.
+///
+/// `Tagged<A, B>` is how an optic is run "backwards": feeding `Tagged(b)` through a
+/// `Prism` rebuilds the whole structure from `b` (see [`review`]).
+pub struct Tagged<A, B> {
+    /// The value carried by the profunctor.
+    pub value: B,
+    _tag: PhantomData<A>,
+}
+
+impl<A, B> Tagged<A, B> {
+    /// Creates a new `Tagged` holding `value`.
+    pub fn new(value: B) -> Self {
+        Tagged {
+            value,
+            _tag: PhantomData,
+        }
+    }
+}
+
+impl<B, C> Profunctor<B, C> for Tagged<B, C> {
+    type Pro<T, U> = Tagged<T, U>;
+
+    // The input mapping `_a2b` is ignored; only the held value is mapped.
+    fn dimap<A, D, A2B, C2D>(self, _a2b: A2B, c2d: C2D) -> Self::Pro<A, D>
+    where
+        A2B: Fn(A) -> B + 'static,
+        C2D: Fn(C) -> D + 'static,
+        A: 'static,
+        B: 'static,
+        C: 'static,
+        D: 'static,
+    {
+        Tagged::new(c2d(self.value))
+    }
+}
+
+impl<A, B> Choice<A, B> for Tagged<A, B> {
+    /// Wraps the held value in `Err`.
+    fn left<C>(self) -> Self::Pro<Result<C, A>, Result<C, B>> {
+        Tagged::new(Err(self.value))
+    }
+
+    /// Wraps the held value in `Ok`.
+    fn right<C>(self) -> Self::Pro<Result<A, C>, Result<B, C>> {
+        Tagged::new(Ok(self.value))
+    }
+}
+
+/// `Tagged<A, B>` as a `Closed` profunctor: `closed` holds the constant function
+/// returning the carried value.
+impl<A, B: Clone + 'static> Closed<A, B> for Tagged<A, B> {
+    fn closed<X: Clone + 'static>(self) -> Self::Pro<CFn<X, A>, CFn<X, B>> {
+        let value = self.value;
+        Tagged::new(CFn::new(move |_: X| value.clone()))
+    }
+}
+
+/// `Tagged<A, B>` as a `Costrong` profunctor: the unused component is dropped from the
+/// carried pair.
+impl<A, B> Costrong<A, B> for Tagged<A, B> {
+    fn unfirst<C: 'static>(p: Tagged<(A, C), (B, C)>) -> Self {
+        Tagged::new(p.value.0)
+    }
+
+    fn unsecond<C: 'static>(p: Tagged<(C, A), (C, B)>) -> Self {
+        Tagged::new(p.value.1)
+    }
+}
+
+/// A `Profunctor` holding the two halves of an `Iso`: `to: S -> A` and `from: B -> T`.
+///
+/// Running an `Iso` with `Exchange<A, B, A, B>` (two identities) recovers its functions,
+/// which is how [`with_iso`] and [`re`] take an `Iso` apart.
+pub struct Exchange<A, B, S, T> {
+    /// The forward function `S -> A`.
+    pub to: CFn<S, A>,
+    /// The backward function `B -> T`.
+    pub from: CFn<B, T>,
+}
+
+impl<A: 'static, B: 'static, S, T> Profunctor<S, T> for Exchange<A, B, S, T> {
+    type Pro<X, Y> = Exchange<A, B, X, Y>;
+
+    // `to` is pre-composed with `x2s`; `from` is post-composed with `t2y`.
+    fn dimap<X, Y, X2S, T2Y>(self, x2s: X2S, t2y: T2Y) -> Self::Pro<X, Y>
+    where
+        X2S: Fn(X) -> S + 'static,
+        T2Y: Fn(T) -> Y + 'static,
+        X: 'static,
+        S: 'static,
+        T: 'static,
+        Y: 'static,
+    {
+        Exchange {
+            to: CFn::new(x2s) >> self.to,
+            from: self.from >> CFn::new(t2y),
+        }
+    }
+}
+
+/// A `Profunctor` wrapping a function that combines two inputs, `(A, A) -> B`.
+///
+/// Running a `Grate` with `Zipping` combines two wholes part by part (see [`zip_with_of`]).
+pub struct Zipping<A, B> {
+    /// The wrapped function `(A, A) -> B`.
+    pub zip: CFn<(A, A), B>,
+}
+
+impl<A, B> Zipping<A, B> {
+    /// Creates a new `Zipping` from a function `(A, A) -> B`.
+    pub fn new<F>(f: F) -> Self
+    where
+        F: Fn(A, A) -> B + 'static,
+    {
+        Zipping {
+            zip: CFn::new(move |(x, y)| f(x, y)),
+        }
+    }
+}
+
+impl<B, C> Profunctor<B, C> for Zipping<B, C> {
+    type Pro<T, U> = Zipping<T, U>;
+
+    // Both inputs are pre-composed with `a2b`.
+    fn dimap<A, D, A2B, C2D>(self, a2b: A2B, c2d: C2D) -> Self::Pro<A, D>
+    where
+        A2B: Fn(A) -> B + 'static,
+        C2D: Fn(C) -> D + 'static,
+        A: 'static,
+        B: 'static,
+        C: 'static,
+        D: 'static,
+    {
+        Zipping::new(move |x, y| c2d(self.zip.call((a2b(x), a2b(y)))))
+    }
+}
+
+/// `Zipping<A, B>` as a `Closed` profunctor: two functions are zipped pointwise, so
+/// each argument `x` is passed to both of them.
+impl<A: 'static, B: 'static> Closed<A, B> for Zipping<A, B> {
+    fn closed<X: Clone + 'static>(self) -> Self::Pro<CFn<X, A>, CFn<X, B>> {
+        let zip = Rc::new(self.zip);
+        Zipping::new(move |f: CFn<X, A>, g: CFn<X, A>| {
+            let zip = zip.clone();
+            CFn::new(move |x: X| zip.call((f.call(x.clone()), g.call(x))))
+        })
+    }
+}
+
+/// A `Profunctor` wrapping an effectful function `A -> FKind::Of<B>`, e.g.
+/// `A -> Option<B>` for `Star<OptionKind, A, B>`.
+///
+/// Running a `Traversal` with `Star<FKind, _, _>` traverses the structure with the
+/// effect `FKind`.
+///
+/// `Star` is a `Profunctor`, `Strong`, `Choice` and `Wander` for `OptionKind`,
+/// `ResultKind<E>`, `IdentityKind`, `ConstKind<R>` (with `R: Monoid`), `VecKind`, and
+/// `ReaderTKind<R, M>` with `M` one of `OptionKind`, `ResultKind<E>`, `IdentityKind` or
+/// `VecKind`. `VecKind` can give many results and a reader is re-run for every environment,
+/// so their `first` and `left` clone the untouched half of the pair (or the other side of
+/// the `Result`) for each one.
+///
+/// The instances are written out per effect rather than for every `Functor`: `dimap` is
+/// generic over its output type, and a bound can't ask for `FKind: Functor<C, D>` for every
+/// `D`. Other Kinds, including user-defined ones, get no `Star` instances. See
+/// [`traverse_of`] for running an optic with `Star`.
+pub struct Star<FKind: Kind, A, B> {
+    /// The wrapped function `A -> FKind::Of<B>`.
+    pub run_star: CFn<A, FKind::Of<B>>,
+}
+
+impl<FKind: Kind, A, B> Star<FKind, A, B> {
+    /// Creates a new `Star` from a function `A -> FKind::Of<B>`.
+    pub fn new<F>(f: F) -> Self
+    where
+        F: Fn(A) -> FKind::Of<B> + 'static,
+    {
+        Star {
+            run_star: CFn::new(f),
+        }
+    }
+
+    /// Runs the wrapped function.
+    pub fn run(&self, a: A) -> FKind::Of<B> {
+        self.run_star.call(a)
+    }
+}
+
+// Implements Profunctor and Choice for `Star<$kind, _, _>`, given how to map a
+// `$kind::Of<_>` with a `FnOnce` and how to lift a plain value into it.
+macro_rules! impl_star_profunctor {
+    ([$($gen:tt)*] $kind:ty, map: |$fa:ident, $f:ident| $map:expr, pure: |$x:ident| $pure:expr) => {
+        impl<$($gen)* B, C> Profunctor<B, C> for Star<$kind, B, C> {
+            type Pro<T, U> = Star<$kind, T, U>;
+            fn dimap<A, D, A2B, C2D>(self, a2b: A2B, c2d: C2D) -> Self::Pro<A, D>
+            where
+                A2B: Fn(A) -> B + 'static,
+                C2D: Fn(C) -> D + 'static,
+                A: 'static,
+                B: 'static,
+                C: 'static,
+                D: 'static,
+            {
+                Star::new(move |a: A| {
+                    let $fa = self.run(a2b(a));
+                    let $f = |c: C| c2d(c);
+                    $map
+                })
+            }
+        }
+
+        impl<$($gen)* A: 'static, B: 'static> Choice<A, B> for Star<$kind, A, B> {
+            fn left<C>(self) -> Self::Pro<Result<C, A>, Result<C, B>> {
+                Star::new(move |r: Result<C, A>| match r {
+                    Ok(c) => {
+                        let $x = Ok(c);
+                        $pure
+                    }
+                    Err(a) => {
+                        let $fa = self.run(a);
+                        let $f = Err;
+                        $map
+                    }
+                })
+            }
+
+            fn right<C>(self) -> Self::Pro<Result<A, C>, Result<B, C>> {
+                Star::new(move |r: Result<A, C>| match r {
+                    Ok(a) => {
+                        let $fa = self.run(a);
+                        let $f = Ok;
+                        $map
+                    }
+                    Err(c) => {
+                        let $x = Err(c);
+                        $pure
+                    }
+                })
+            }
+        }
+    };
+}
+
+// Implements Strong for `Star<$kind, _, _>`, for effects holding at most one value, so
+// that the untouched half of a pair can be moved into the result.
+macro_rules! impl_star_strong {
+    ([$($gen:tt)*] $kind:ty, map: |$fa:ident, $f:ident| $map:expr) => {
+        impl<$($gen)* A: 'static, B: 'static> Strong<A, B> for Star<$kind, A, B> {
+            fn first<C: Clone + 'static>(self) -> Self::Pro<(A, C), (B, C)> {
+                Star::new(move |(a, c): (A, C)| {
+                    let $fa = self.run(a);
+                    let $f = move |b: B| (b, c);
+                    $map
+                })
+            }
+
+            fn second<C: Clone + 'static>(self) -> Self::Pro<(C, A), (C, B)> {
+                Star::new(move |(c, a): (C, A)| {
+                    let $fa = self.run(a);
+                    let $f = move |b: B| (c, b);
+                    $map
+                })
+            }
+        }
+    };
+}
+
+// Implements Wander for `Star<$kind, _, _>`, traversing with the effect `$kind` itself.
+macro_rules! impl_star_wander {
+    ([$($gen:tt)*] $kind:ty) => {
+        impl<$($gen)* A: 'static, B: 'static> Wander<A, B> for Star<$kind, A, B> {
+            type Effect = $kind;
+
+            fn wander<S: 'static, T: 'static, W>(self, traversal: W) -> Self::Pro<S, T>
+            where
+                W: Traversing<$kind, S, T, A, B> + 'static,
+            {
+                let star = Rc::new(self);
+                Star::new(move |s: S| {
+                    let star = star.clone();
+                    traversal.traverse_with(s, move |a: A| star.run(a))
+                })
+            }
+        }
+    };
+}
+
+impl_star_profunctor!([] OptionKind, map: |fa, f| fa.map(f), pure: |x| Some(x));
+impl_star_strong!([] OptionKind, map: |fa, f| fa.map(f));
+impl_star_wander!([] OptionKind);
+impl_star_profunctor!([E: 'static,] ResultKind<E>, map: |fa, f| fa.map(f), pure: |x| Ok(x));
+impl_star_strong!([E: 'static,] ResultKind<E>, map: |fa, f| fa.map(f));
+impl_star_wander!([E: 'static,] ResultKind<E>);
+impl_star_profunctor!([] IdentityKind, map: |fa, f| Identity(f(fa.0)), pure: |x| Identity(x));
+impl_star_strong!([] IdentityKind, map: |fa, f| Identity(f(fa.0)));
+impl_star_wander!([] IdentityKind);
+impl_star_profunctor!(
+    [] VecKind,
+    map: |fa, f| fa.into_iter().map(f).collect(),
+    pure: |x| vec![x]
+);
+impl_star_wander!([] VecKind);
+impl_star_profunctor!(
+    [R: Monoid + 'static,] ConstKind<R>,
+    map: |fa, f| retag(fa, f),
+    pure: |x| retag(Const::new(R::empty()), |_: ()| x)
+);
+impl_star_strong!([R: Monoid + 'static,] ConstKind<R>, map: |fa, f| retag(fa, f));
+impl_star_wander!([R: Monoid + 'static,] ConstKind<R>);
+
+/// Every result of the wrapped function is paired with its own copy of the untouched half.
+impl<A: 'static, B: 'static> Strong<A, B> for Star<VecKind, A, B> {
+    fn first<C: Clone + 'static>(self) -> Self::Pro<(A, C), (B, C)> {
+        Star::new(move |(a, c): (A, C)| {
+            let bs = self.run(a).into_iter();
+            bs.map(|b| (b, c.clone())).collect()
+        })
+    }
+
+    fn second<C: Clone + 'static>(self) -> Self::Pro<(C, A), (C, B)> {
+        Star::new(move |(c, a): (C, A)| {
+            let bs = self.run(a).into_iter();
+            bs.map(|b| (c.clone(), b)).collect()
+        })
+    }
+}
+
+// Implements Profunctor, Strong and Choice for `Star<ReaderTKind<R, $inner>, _, _>`,
+// given how to map the result of a run of the reader with a shared `Fn` and how to lift
+// a plain value into `$inner`. A reader runs once per environment, so the untouched half
+// of a pair, or the other side of a `Result`, is cloned for every run.
+macro_rules! impl_star_reader_profunctor {
+    ([$($gen:tt)*] $inner:ty, map: |$fa:ident, $f:ident| $map:expr, pure: |$x:ident| $pure:expr) => {
+        impl<$($gen)* R: 'static, B, C> Profunctor<B, C> for Star<ReaderTKind<R, $inner>, B, C> {
+            type Pro<T, U> = Star<ReaderTKind<R, $inner>, T, U>;
+            fn dimap<A, D, A2B, C2D>(self, a2b: A2B, c2d: C2D) -> Self::Pro<A, D>
+            where
+                A2B: Fn(A) -> B + 'static,
+                C2D: Fn(C) -> D + 'static,
+                A: 'static,
+                B: 'static,
+                C: 'static,
+                D: 'static,
+            {
+                let c2d = Rc::new(c2d);
+                Star::new(move |a: A| {
+                    let run = self.run(a2b(a)).run_reader_t;
+                    let c2d = c2d.clone();
+                    ReaderT::new(move |r: R| {
+                        let $fa = run(r);
+                        let $f = |c: C| c2d(c);
+                        $map
+                    })
+                })
+            }
+        }
+
+        impl<$($gen)* R: 'static, A: 'static, B: 'static> Strong<A, B>
+            for Star<ReaderTKind<R, $inner>, A, B>
+        {
+            fn first<C: Clone + 'static>(self) -> Self::Pro<(A, C), (B, C)> {
+                Star::new(move |(a, c): (A, C)| {
+                    let run = self.run(a).run_reader_t;
+                    ReaderT::new(move |r: R| {
+                        let $fa = run(r);
+                        let $f = |b: B| (b, c.clone());
+                        $map
+                    })
+                })
+            }
+
+            fn second<C: Clone + 'static>(self) -> Self::Pro<(C, A), (C, B)> {
+                Star::new(move |(c, a): (C, A)| {
+                    let run = self.run(a).run_reader_t;
+                    ReaderT::new(move |r: R| {
+                        let $fa = run(r);
+                        let $f = |b: B| (c.clone(), b);
+                        $map
+                    })
+                })
+            }
+        }
+
+        impl<$($gen)* R: 'static, A: 'static, B: 'static> Choice<A, B>
+            for Star<ReaderTKind<R, $inner>, A, B>
+        {
+            fn left<C: Clone + 'static>(self) -> Self::Pro<Result<C, A>, Result<C, B>> {
+                Star::new(move |r: Result<C, A>| match r {
+                    Ok(c) => ReaderT::new(move |_: R| {
+                        let $x = Ok(c.clone());
+                        $pure
+                    }),
+                    Err(a) => {
+                        let run = self.run(a).run_reader_t;
+                        ReaderT::new(move |r: R| {
+                            let $fa = run(r);
+                            let $f = Err;
+                            $map
+                        })
+                    }
+                })
+            }
+
+            fn right<C: Clone + 'static>(self) -> Self::Pro<Result<A, C>, Result<B, C>> {
+                Star::new(move |r: Result<A, C>| match r {
+                    Ok(a) => {
+                        let run = self.run(a).run_reader_t;
+                        ReaderT::new(move |r: R| {
+                            let $fa = run(r);
+                            let $f = Ok;
+                            $map
+                        })
+                    }
+                    Err(c) => ReaderT::new(move |_: R| {
+                        let $x = Err(c.clone());
+                        $pure
+                    }),
+                })
+            }
+        }
+
+        impl_star_wander!([$($gen)* R: 'static,] ReaderTKind<R, $inner>);
+    };
+}
+
+impl_star_reader_profunctor!([] OptionKind, map: |fa, f| fa.map(f), pure: |x| Some(x));
+impl_star_reader_profunctor!(
+    [E: 'static,] ResultKind<E>,
+    map: |fa, f| fa.map(f),
+    pure: |x| Ok(x)
+);
+impl_star_reader_profunctor!(
+    [] IdentityKind,
+    map: |fa, f| Identity(f(fa.0)),
+    pure: |x| Identity(x)
+);
+impl_star_reader_profunctor!(
+    [] VecKind,
+    map: |fa, f| fa.into_iter().map(f).collect(),
+    pure: |x| vec![x]
+);
+
+// Changes the phantom type of a `Const` to the result type of `_f`, which is never called.
+fn retag<R, X, Y>(fx: Const<R, X>, _f: impl FnOnce(X) -> Y) -> Const<R, Y> {
+    Const::new(fx.get_const())
+}
+
+/// A `Profunctor` wrapping a function that consumes a structure, `FKind::Of<A> -> B`,
+/// e.g. `Vec<A> -> B` for `Costar<VecKind, A, B>`. It is the dual of [`Star`].
+///
+/// `Costar` is a `Profunctor` for `OptionKind`, `ResultKind<E>`, `IdentityKind`,
+/// `EnvKind<E>`, `VecKind` and `NonEmptyKind`. It is `Strong` and `Choice` for the comonads
+/// among them, which can always `extract` the untouched half of a pair (or the other side
+/// of a `Result`): `IdentityKind`, `EnvKind<E>` and `NonEmptyKind`.
+pub struct Costar<FKind: Kind, A, B> {
+    /// The wrapped function `FKind::Of<A> -> B`.
+    pub run_costar: CFn<FKind::Of<A>, B>,
+}
+
+impl<FKind: Kind, A, B> Costar<FKind, A, B> {
+    /// Creates a new `Costar` from a function `FKind::Of<A> -> B`.
+    pub fn new<F>(f: F) -> Self
+    where
+        F: Fn(FKind::Of<A>) -> B + 'static,
+    {
+        Costar {
+            run_costar: CFn::new(f),
+        }
+    }
+
+    /// Runs the wrapped function.
+    pub fn run(&self, fa: FKind::Of<A>) -> B {
+        self.run_costar.call(fa)
+    }
+}
+
+// Implements Profunctor for `Costar<$kind, _, _>`, given how to map a `$kind::Of<_>`
+// with a shared `Fn`.
+macro_rules! impl_costar_profunctor {
+    ([$($gen:tt)*] $kind:ty, map: |$fa:ident, $f:ident| $map:expr) => {
+        impl<$($gen)* B, C> Profunctor<B, C> for Costar<$kind, B, C> {
+            type Pro<T, U> = Costar<$kind, T, U>;
+            fn dimap<A, D, A2B, C2D>(self, a2b: A2B, c2d: C2D) -> Self::Pro<A, D>
+            where
+                A2B: Fn(A) -> B + 'static,
+                C2D: Fn(C) -> D + 'static,
+                A: 'static,
+                B: 'static,
+                C: 'static,
+                D: 'static,
+            {
+                Costar::new(move |$fa: <$kind as Kind>::Of<A>| {
+                    let $f = &a2b;
+                    c2d(self.run($map))
+                })
+            }
+        }
+    };
+}
+
+impl_costar_profunctor!([] OptionKind, map: |fa, f| fa.map(f));
+impl_costar_profunctor!([E: 'static,] ResultKind<E>, map: |fa, f| fa.map(f));
+impl_costar_profunctor!([] IdentityKind, map: |fa, f| Identity(f(fa.0)));
+impl_costar_profunctor!([E: 'static,] EnvKind<E>, map: |fa, f| Env::new(fa.env, f(fa.value)));
+impl_costar_profunctor!([] VecKind, map: |fa, f| fa.into_iter().map(f).collect());
+impl_costar_profunctor!([] NonEmptyKind, map: |fa, f| NonEmpty::new(
+    f(fa.head),
+    fa.tail.into_iter().map(f).collect()
+));
+
+impl<A: 'static, B: 'static> Strong<A, B> for Costar<IdentityKind, A, B> {
+    fn first<C: 'static>(self) -> Self::Pro<(A, C), (B, C)> {
+        Costar::new(move |Identity((a, c)): Identity<(A, C)>| (self.run(Identity(a)), c))
+    }
+
+    fn second<C: 'static>(self) -> Self::Pro<(C, A), (C, B)> {
+        Costar::new(move |Identity((c, a)): Identity<(C, A)>| (c, self.run(Identity(a))))
+    }
+}
+
+impl<A: 'static, B: 'static> Choice<A, B> for Costar<IdentityKind, A, B> {
+    fn left<C>(self) -> Self::Pro<Result<C, A>, Result<C, B>> {
+        Costar::new(move |Identity(r): Identity<Result<C, A>>| r.map_err(|a| self.run(Identity(a))))
+    }
+
+    fn right<C>(self) -> Self::Pro<Result<A, C>, Result<B, C>> {
+        Costar::new(move |Identity(r): Identity<Result<A, C>>| r.map(|a| self.run(Identity(a))))
+    }
+}
+
+impl<E: 'static, A: 'static, B: 'static> Strong<A, B> for Costar<EnvKind<E>, A, B> {
+    fn first<C: 'static>(self) -> Self::Pro<(A, C), (B, C)> {
+        Costar::new(move |w: Env<E, (A, C)>| {
+            let (a, c) = w.value;
+            (self.run(Env::new(w.env, a)), c)
+        })
+    }
+
+    fn second<C: 'static>(self) -> Self::Pro<(C, A), (C, B)> {
+        Costar::new(move |w: Env<E, (C, A)>| {
+            let (c, a) = w.value;
+            (c, self.run(Env::new(w.env, a)))
+        })
+    }
+}
+
+impl<E: 'static, A: 'static, B: 'static> Choice<A, B> for Costar<EnvKind<E>, A, B> {
+    fn left<C>(self) -> Self::Pro<Result<C, A>, Result<C, B>> {
+        Costar::new(move |w: Env<E, Result<C, A>>| {
+            let env = w.env;
+            w.value.map_err(|a| self.run(Env::new(env, a)))
+        })
+    }
+
+    fn right<C>(self) -> Self::Pro<Result<A, C>, Result<B, C>> {
+        Costar::new(move |w: Env<E, Result<A, C>>| {
+            let env = w.env;
+            w.value.map(|a| self.run(Env::new(env, a)))
+        })
+    }
+}
+
+/// The untouched half of the pair is taken from the head; the others are dropped.
+impl<A: 'static, B: 'static> Strong<A, B> for Costar<NonEmptyKind, A, B> {
+    fn first<C: 'static>(self) -> Self::Pro<(A, C), (B, C)> {
+        Costar::new(move |w: NonEmpty<(A, C)>| {
+            let (a, c) = w.head;
+            let tail = w.tail.into_iter().map(|(a, _)| a).collect();
+            (self.run(NonEmpty::new(a, tail)), c)
+        })
+    }
+
+    fn second<C: 'static>(self) -> Self::Pro<(C, A), (C, B)> {
+        Costar::new(move |w: NonEmpty<(C, A)>| {
+            let (c, a) = w.head;
+            let tail = w.tail.into_iter().map(|(_, a)| a).collect();
+            (c, self.run(NonEmpty::new(a, tail)))
+        })
+    }
+}
+
+/// The elements are sequenced like a `Traversable`: the first one on the other side is
+/// returned as is, and the wrapped function only runs when every element is focused.
+impl<A: 'static, B: 'static> Choice<A, B> for Costar<NonEmptyKind, A, B> {
+    fn left<C>(self) -> Self::Pro<Result<C, A>, Result<C, B>> {
+        Costar::new(move |w: NonEmpty<Result<C, A>>| {
+            let swap = |r: Result<C, A>| match r {
+                Ok(c) => Err(c),
+                Err(a) => Ok(a),
+            };
+            let focused = swap(w.head).and_then(|head| {
+                let tail = w.tail.into_iter().map(swap).collect::<Result<_, _>>()?;
+                Ok(NonEmpty::new(head, tail))
+            });
+            match focused {
+                Ok(w) => Err(self.run(w)),
+                Err(c) => Ok(c),
+            }
+        })
+    }
+
+    fn right<C>(self) -> Self::Pro<Result<A, C>, Result<B, C>> {
+        Costar::new(move |w: NonEmpty<Result<A, C>>| {
+            let head = w.head?;
+            let tail = w.tail.into_iter().collect::<Result<_, _>>()?;
+            Ok(self.run(NonEmpty::new(head, tail)))
+        })
+    }
+}
+
 /// Internal helper to construct a Lens using the "van Laarhoven" representation.
 /// Not typically called directly by users. `lens` function is preferred.
 ///
@@ -348,39 +1625,35 @@ impl<R: 'static, AStrong: 'static, BStrong: 'static> Strong<AStrong, BStrong>
 /// This function is highly generic and relies on the compiler to infer complex
 /// profunctor relationships.
 pub fn lens_<PO, PI, PBC, S: 'static, T: 'static, A: 'static, B: 'static>(
-    to: CFn<S, (A, CFn<B, T>)>,
+    to: CFn<S, (A, RcFn<B, T>)>,
 ) -> Lens<PO, PBC, S, T, A, B>
 where
     PO: Strong<S, T>, // Outer profunctor for S -> T
     // PI is the profunctor that results from dimap on PBC.first.
-    // It needs to be Profunctor<(A, CFn<B,T>), (B, CFn<B,T>)>
+    // It needs to be Profunctor<(A, RcFn<B,T>), (B, RcFn<B,T>)>
     // and its Pro<S,T> must be PO.
-    PI: Profunctor<(A, CFn<B, T>), (B, CFn<B, T>), Pro<S, T> = PO>,
+    PI: Profunctor<(A, RcFn<B, T>), (B, RcFn<B, T>), Pro<S, T> = PO>,
     // PBC is the "inner" profunctor for A -> B, which must be Strong.
     // Its .first operation results in a profunctor of type PI.
-    PBC: Strong<A, B, Pro<(A, CFn<B, T>), (B, CFn<B, T>)> = PI>,
+    PBC: Strong<A, B, Pro<(A, RcFn<B, T>), (B, RcFn<B, T>)> = PI>,
 {
     // pbc: PBC (Strong<A,B>)
-    // PBC::first(pbc): PI (Profunctor for ((A, CFn<B,T>), (B, CFn<B,T>)))
+    // PBC::first(pbc): PI (Profunctor for ((A, RcFn<B,T>), (B, RcFn<B,T>)))
     // PI::dimap(PI_instance, map_fn_S_to_InnerInput, map_fn_InnerOutput_to_T)
-    //   map_fn_S_to_InnerInput: S -> (A, CFn<B,T>)  (this is `to`)
-    //   map_fn_InnerOutput_to_T: (B, CFn<B,T>) -> T (this is `|(b, f)| f(b)`)
+    //   map_fn_S_to_InnerInput: S -> (A, RcFn<B,T>)  (this is `to`)
+    //   map_fn_InnerOutput_to_T: (B, RcFn<B,T>) -> T (this is `|(b, f)| f(b)`)
     // The result of dimap is PO (Strong<S,T>)
-    let optics_fn = |pbc: PBC| {
+    let to = Rc::new(to); // Shared by every run of the optic
+    let optics_fn = move |pbc: PBC| {
         let pi_instance = PBC::first(pbc); // pi_instance is PI
+        let to = to.clone();
         PI::dimap(
             pi_instance,
-            move |s_val| to.call(s_val), // S -> (A, CFn<B,T>)
-            |(b_val, f_b_to_t)| f_b_to_t.call(b_val), // (B, CFn<B,T>) -> T
+            move |s_val| to.call(s_val), // S -> (A, RcFn<B,T>)
+            |(b_val, f_b_to_t)| f_b_to_t.call(b_val), // (B, RcFn<B,T>) -> T
         )
     };
-    Lens(Optic {
-        optic: Box::new(optics_fn),
-        _s: PhantomData,
-        _t: PhantomData,
-        _a: PhantomData,
-        _b: PhantomData,
-    })
+    Lens(Optic::new(optics_fn))
 }
 
 /// Constructs a `Lens` from a getter function and a setter function.
@@ -389,11 +1662,11 @@ where
 /// - `s2a`: A "getter" function `S -> A` that extracts the part `A` from the whole `S`.
 /// - `s2b2t`: A "setter" function `S -> (B -> T)`. It takes the original whole `S`,
 ///   returns a new function that takes the new part `B` and produces the new whole `T`.
-///   `S` must be `Copy` because it's used by both the getter and to capture in the setter closure.
+///   `S` must be `Clone` because it's used by both the getter and to capture in the setter closure.
 ///
 /// # Returns
 /// A `Lens<PO, PI, S, T, A, B>`. The profunctor types `PO` and `PI` are usually inferred.
-pub fn lens<PO, PI, S: Copy + 'static, T: 'static, A: 'static, B: 'static>(
+pub fn lens<PO, PI, S: Clone + 'static, T: 'static, A: 'static, B: 'static>(
     s2a: CFn<S, A>,
     s2b2t: CFn<S, CFn<B, T>>,
 ) -> Lens<PO, PI, S, T, A, B>
@@ -401,13 +1674,13 @@ where
     PO: Strong<S, T>,
     PI: Strong<A, B>,
     // This constraint links the result of PI.first.dimap to PO.
-    <PI as Profunctor<A, B>>::Pro<(A, CFn<B, T>), (B, CFn<B, T>)>:
-        Profunctor<(A, CFn<B, T>), (B, CFn<B, T>), Pro<S, T> = PO>,
+    <PI as Profunctor<A, B>>::Pro<(A, RcFn<B, T>), (B, RcFn<B, T>)>:
+        Profunctor<(A, RcFn<B, T>), (B, RcFn<B, T>), Pro<S, T> = PO>,
 {
     // Combine getter and setter into the S -> (A, B -> T) form required by lens_
     let combined_fn = CFn::new(move |s: S| {
-        let part_a = s2a.call(s);
-        let setter_fn_for_s = s2b2t.call(s); // This is CFn<B,T>
+        let part_a = s2a.call(s.clone());
+        let setter_fn_for_s = RcFn::from(s2b2t.call(s)); // Shared, so `first` can clone it
         (part_a, setter_fn_for_s)
     });
     lens_(combined_fn)
@@ -420,17 +1693,11 @@ where
 pub fn _1<
     A: 'static,
     B: 'static,
-    C: 'static,
+    C: Clone + 'static,
     PA: Strong<A, B, Pro<(A, C), (B, C)> = PS> + 'static, // PA is PInner for A->B
     PS: Strong<(A, C), (B, C)>,                           // PS is POuter for (A,C)->(B,C)
 >() -> Lens<PS, PA, (A, C), (B, C), A, B> {
-    Lens(Optic {
-        optic: Box::new(Strong::first), // Strong::first transforms PA to PS
-        _s: PhantomData,
-        _t: PhantomData,
-        _a: PhantomData,
-        _b: PhantomData,
-    })
+    Lens(Optic::new(Strong::first))
 }
 
 /// A `Lens` that focuses on the second element of a pair `(C, A)`.
@@ -440,17 +1707,11 @@ pub fn _1<
 pub fn _2<
     A: 'static,
     B: 'static,
-    C: 'static,
+    C: Clone + 'static,
     PA: Strong<A, B, Pro<(C, A), (C, B)> = PS> + 'static, // PA is PInner for A->B
     PS: Strong<(C, A), (C, B)>,                           // PS is POuter for (C,A)->(C,B)
 >() -> Lens<PS, PA, (C, A), (C, B), A, B> {
-    Lens(Optic {
-        optic: Box::new(Strong::second), // Strong::second transforms PA to PS
-        _s: PhantomData,
-        _t: PhantomData,
-        _a: PhantomData,
-        _b: PhantomData,
-    })
+    Lens(Optic::new(Strong::second))
 }
 
 /// Example helper function to create a lens for the first element of a pair.
@@ -465,19 +1726,19 @@ where
     PO: Strong<(A, BTuple), (A, BTuple)>, // Outer profunctor for (A,BTuple) -> (A,BTuple)
     // Constraint linking PI.first.dimap to PO
     // The inner profunctor PI operates on A -> A.
-    // PI.first takes a C type, which for lens_ construction is CFn<A_NewPartVal, T_Whole>.
-    // Here, A_NewPartVal is A, and T_Whole is (A, BTuple). So C = CFn<A, (A, BTuple)>.
-    // PI.first gives PI::Pro<(A, CFn<A, (A, BTuple)>), (A, CFn<A, (A, BTuple)>)>. Let this be P_intermediate.
+    // PI.first takes a C type, which for lens_ construction is RcFn<A_NewPartVal, T_Whole>.
+    // Here, A_NewPartVal is A, and T_Whole is (A, BTuple). So C = RcFn<A, (A, BTuple)>.
+    // PI.first gives PI::Pro<(A, RcFn<A, (A, BTuple)>), (A, RcFn<A, (A, BTuple)>)>. Let this be P_intermediate.
     // This P_intermediate is then dimap'd.
     // The dimap inputs are:
     //   Outer S: (A, BTuple)
     //   Outer T: (A, BTuple)
-    //   Inner A (P_intermediate's input): (A, CFn<A, (A, BTuple)>)
-    //   Inner B (P_intermediate's output): (A, CFn<A, (A, BTuple)>)
+    //   Inner A (P_intermediate's input): (A, RcFn<A, (A, BTuple)>)
+    //   Inner B (P_intermediate's output): (A, RcFn<A, (A, BTuple)>)
     // So, P_intermediate must be Profunctor where its Pro<(A,BTuple), (A,BTuple)> = PO.
-    <PI as Profunctor<A, A>>::Pro<(A, CFn<A, (A, BTuple)>), (A, CFn<A, (A, BTuple)>)>: Profunctor<
-        (A, CFn<A, (A, BTuple)>),
-        (A, CFn<A, (A, BTuple)>),
+    <PI as Profunctor<A, A>>::Pro<(A, RcFn<A, (A, BTuple)>), (A, RcFn<A, (A, BTuple)>)>: Profunctor<
+        (A, RcFn<A, (A, BTuple)>),
+        (A, RcFn<A, (A, BTuple)>),
         Pro<(A, BTuple), (A, BTuple)> = PO,
     >,
 {
@@ -494,37 +1755,468 @@ where
     )
 }
 
-/// A simple struct for demonstrating lenses.
-#[derive(Clone, Copy, Debug, PartialEq)] // Added Debug, PartialEq
-pub struct Check {
-    /// A field in the Check struct.
-    pub key: i8,
-    /// Another field in the Check struct.
-    pub other: i8,
+/// Defines a struct together with one `Lens` constructor per field.
+///
+/// For every field `f: A` of `struct S`, an associated function `S::f::<PO, PI>()`
+/// returning `Lens<PO, PI, S, S, A, A>` is generated, with the same visibility as the
+/// field. Like [`_key`], each constructor is generic over the profunctor types, so the
+/// same lens works with [`view`], [`set`], [`over`] and composition.
+///
+/// The struct must implement `Clone`, since setting a field clones the rest of the
+/// struct. Generic structs and tuple structs are not supported.
+///
+/// # Examples
+/// ```
+/// use monadify::derive_lenses;
+/// use monadify::profunctor::{over, set, view};
+///
+/// derive_lenses! {
+///     #[derive(Clone, Debug, PartialEq)]
+///     pub struct Person {
+///         pub name: String,
+///         pub age: u32,
+///     }
+/// }
+///
+/// let p = Person { name: "Ann".to_string(), age: 30 };
+/// assert_eq!(view(Person::name().0, p.clone()), "Ann");
+/// assert_eq!(set(Person::age().0, 31, p.clone()).age, 31);
+/// assert_eq!(over(Person::name().0, |n: String| n.to_uppercase(), p).name, "ANN");
+/// ```
+#[macro_export]
+macro_rules! derive_lenses {
+    (
+        $(#[$meta:meta])*
+        $vis:vis struct $name:ident {
+            $( $(#[$fmeta:meta])* $fvis:vis $field:ident : $ty:ty ),* $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        $vis struct $name {
+            $( $(#[$fmeta])* $fvis $field: $ty, )*
+        }
+
+        impl $name {
+            $(
+                #[doc = concat!(
+                    "A `Lens` that focuses on the `", stringify!($field),
+                    "` field of `", stringify!($name), "`."
+                )]
+                #[allow(dead_code)]
+                $fvis fn $field<PO, PI>() -> $crate::profunctor::Lens<PO, PI, $name, $name, $ty, $ty>
+                where
+                    PO: $crate::profunctor::Strong<$name, $name>,
+                    PI: $crate::profunctor::Strong<$ty, $ty>,
+                    <PI as $crate::profunctor::Profunctor<$ty, $ty>>::Pro<
+                        ($ty, $crate::function::RcFn<$ty, $name>),
+                        ($ty, $crate::function::RcFn<$ty, $name>),
+                    >: $crate::profunctor::Profunctor<
+                        ($ty, $crate::function::RcFn<$ty, $name>),
+                        ($ty, $crate::function::RcFn<$ty, $name>),
+                        Pro<$name, $name> = PO,
+                    >,
+                {
+                    $crate::profunctor::lens(
+                        $crate::function::CFn::new(|s: $name| s.$field),
+                        $crate::function::CFn::new(|s: $name| {
+                            $crate::function::CFn::new(move |value: $ty| {
+                                let mut s = s.clone();
+                                s.$field = value;
+                                s
+                            })
+                        }),
+                    )
+                }
+            )*
+        }
+    };
+}
+
+derive_lenses! {
+    /// A simple struct for demonstrating lenses.
+    ///
+    /// Its field lenses, `Check::key()` and `Check::other()`, are generated by [`derive_lenses!`].
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    pub struct Check {
+        /// A field in the Check struct.
+        pub key: i8,
+        /// Another field in the Check struct.
+        pub other: i8,
+    }
 }
 
 /// A `Lens` that focuses on the `key` field of a `Check` struct.
-/// Allows getting `check.key` and setting it.
+/// Allows getting `check.key` and setting it. This is the same as `Check::key()`.
 pub fn _key<PO, PI>() -> Lens<PO, PI, Check, Check, i8, i8>
 where
     PO: Strong<Check, Check>, // Outer profunctor for Check -> Check
     PI: Strong<i8, i8>,       // Inner profunctor for i8 -> i8 (identity for key field)
     // Constraint linking PI.first.dimap to PO
-    <PI as Profunctor<i8, i8>>::Pro<(i8, CFn<i8, Check>), (i8, CFn<i8, Check>)>:
-        Profunctor<(i8, CFn<i8, Check>), (i8, CFn<i8, Check>), Pro<Check, Check> = PO>,
+    <PI as Profunctor<i8, i8>>::Pro<(i8, RcFn<i8, Check>), (i8, RcFn<i8, Check>)>:
+        Profunctor<(i8, RcFn<i8, Check>), (i8, RcFn<i8, Check>), Pro<Check, Check> = PO>,
 {
-    lens(
-        CFn::new(|c: Check| c.key), // Getter: Check -> i8
-        CFn::new(|c: Check| {
-            // Setter: Check -> (i8_new -> Check_new)
-            CFn::new(move |new_key: i8| Check {
-                key: new_key,
-                other: c.other,
-            })
+    Check::key()
+}
+
+/// Constructs a `Prism` from a constructor and a matcher.
+///
+/// # Parameters
+/// - `build`: A function `B -> T` that builds the whole from the focused case.
+/// - `matcher`: A function `S -> Result<A, T>` that returns `Ok(a)` when `S` is in the
+///   focused case, and otherwise `Err(t)` with the whole converted to `T` unchanged.
+///
+/// `T` must be `Clone`, since [`Choice`] hands an unmatched whole back on every run of
+/// an effect such as a reader.
+///
+/// # Returns
+/// A `Prism<PO, PI, S, T, A, B>`. The profunctor types `PO` and `PI` are usually inferred.
+pub fn prism<PO, PI, S: 'static, T: Clone + 'static, A: 'static, B: 'static>(
+    build: CFn<B, T>,
+    matcher: CFn<S, Result<A, T>>,
+) -> Prism<PO, PI, S, T, A, B>
+where
+    PO: Choice<S, T>,
+    PI: Choice<A, B>,
+    // This constraint links the result of PI.right.dimap to PO.
+    <PI as Profunctor<A, B>>::Pro<Result<A, T>, Result<B, T>>:
+        Profunctor<Result<A, T>, Result<B, T>, Pro<S, T> = PO>,
+{
+    let (build, matcher) = (Rc::new(build), Rc::new(matcher));
+    let optic_fn = move |pi: PI| {
+        let (build, matcher) = (build.clone(), matcher.clone());
+        PI::right::<T>(pi).dimap(
+            move |s: S| matcher.call(s), // S -> Result<A, T>
+            move |r: Result<B, T>| match r {
+                Ok(b) => build.call(b),
+                Err(t) => t,
+            },
+        )
+    };
+    Prism(Optic::new(optic_fn))
+}
+
+/// A `Prism` that focuses on the value inside `Some`.
+pub fn _some<PO, PI, A: 'static, B: Clone + 'static>() -> Prism<PO, PI, Option<A>, Option<B>, A, B>
+where
+    PO: Choice<Option<A>, Option<B>>,
+    PI: Choice<A, B>,
+    <PI as Profunctor<A, B>>::Pro<Result<A, Option<B>>, Result<B, Option<B>>>:
+        Profunctor<Result<A, Option<B>>, Result<B, Option<B>>, Pro<Option<A>, Option<B>> = PO>,
+{
+    prism(CFn::new(Some), CFn::new(|s: Option<A>| s.ok_or(None)))
+}
+
+/// A `Prism` that focuses on the value inside `Ok`.
+pub fn _ok<PO, PI, A: 'static, B: Clone + 'static, E: Clone + 'static>(
+) -> Prism<PO, PI, Result<A, E>, Result<B, E>, A, B>
+where
+    PO: Choice<Result<A, E>, Result<B, E>>,
+    PI: Choice<A, B>,
+    <PI as Profunctor<A, B>>::Pro<Result<A, Result<B, E>>, Result<B, Result<B, E>>>: Profunctor<
+        Result<A, Result<B, E>>,
+        Result<B, Result<B, E>>,
+        Pro<Result<A, E>, Result<B, E>> = PO,
+    >,
+{
+    prism(CFn::new(Ok), CFn::new(|s: Result<A, E>| s.map_err(Err)))
+}
+
+/// A `Prism` that focuses on the value inside `Err`.
+pub fn _err<PO, PI, X: Clone + 'static, A: 'static, B: Clone + 'static>(
+) -> Prism<PO, PI, Result<X, A>, Result<X, B>, A, B>
+where
+    PO: Choice<Result<X, A>, Result<X, B>>,
+    PI: Choice<A, B>,
+    <PI as Profunctor<A, B>>::Pro<Result<A, Result<X, B>>, Result<B, Result<X, B>>>: Profunctor<
+        Result<A, Result<X, B>>,
+        Result<B, Result<X, B>>,
+        Pro<Result<X, A>, Result<X, B>> = PO,
+    >,
+{
+    prism(
+        CFn::new(Err),
+        CFn::new(|s: Result<X, A>| match s {
+            Err(a) => Ok(a),
+            Ok(x) => Err(Ok(x)),
         }),
     )
 }
 
+/// Extracts the focus `A` of an optic (typically a `Prism`), if present.
+///
+/// The optic is run with [`Forget`] into `Option<First<A>>`, which is `None` when
+/// `s` is not in the focused case.
+///
+/// # Parameters
+/// - `optic`: The optic used to focus on the part `A`. `T` and `B` are typically phantom.
+/// - `s`: The structure `S` to look into.
+///
+/// # Returns
+/// `Some(a)` if `s` contains a focus, otherwise `None`.
+pub fn preview<S: 'static, T: 'static, A: 'static, B: 'static>(
+    optic: APreview<S, T, A, B>,
+    s: S,
+) -> Option<A> {
+    let inner_profunctor = Forget {
+        inner: CFn::new(|a: A| Some(First(a))),
+        _forget: PhantomData,
+    };
+    (optic.optic)(inner_profunctor)
+        .inner
+        .call(s)
+        .map(|first| first.0)
+}
+
+/// Builds a whole `T` from a part `B` by running an optic (typically a `Prism` or `Iso`)
+/// backwards.
+///
+/// The optic is run with [`Tagged`], which carries `b` through to the outer structure.
+pub fn review<S: 'static, T: 'static, A: 'static, B: 'static>(
+    optic: AReview<S, T, A, B>,
+    b: B,
+) -> T {
+    (optic.optic)(Tagged::new(b)).value
+}
+
+/// Turns an optic that builds a `T` from a `B` (a `Prism`, `Iso` or `Grate` run with
+/// [`Tagged`]) into a getter from `B` to `T`, so `view(re_review(p), b) == review(p, b)`.
+///
+/// This is the `re` of Haskell's `lens` for reviews; [`re`] reverses a whole `Iso`.
+pub fn re_review<R: 'static, S: 'static, T: 'static, A: 'static, B: 'static>(
+    optic: AReview<S, T, A, B>,
+) -> Fold<R, B, B, T, T> {
+    Optic::new(move |inner: Forget<R, T, T>| {
+        let optic = optic.clone();
+        Forget::new(move |b: B| inner.inner.call(review(optic.clone(), b)))
+    })
+}
+
+/// Constructs an `Iso` from a pair of inverse functions.
+///
+/// # Parameters
+/// - `to`: The forward function `S -> A`.
+/// - `from`: The backward function `B -> T`.
+///
+/// # Returns
+/// An `Iso<PO, PI, S, T, A, B>`. The profunctor types `PO` and `PI` are usually inferred.
+pub fn iso<PO, PI, S: 'static, T: 'static, A: 'static, B: 'static>(
+    to: CFn<S, A>,
+    from: CFn<B, T>,
+) -> Iso<PO, PI, S, T, A, B>
+where
+    PO: Profunctor<S, T>,
+    PI: Profunctor<A, B, Pro<S, T> = PO>,
+{
+    let (to, from) = (Rc::new(to), Rc::new(from));
+    let optic_fn = move |pi: PI| {
+        let (to, from) = (to.clone(), from.clone());
+        pi.dimap(move |s: S| to.call(s), move |b: B| from.call(b))
+    };
+    Iso(Optic::new(optic_fn))
+}
+
+/// Takes an `Iso` apart into its forward `S -> A` and backward `B -> T` functions.
+pub fn with_iso<S: 'static, T: 'static, A: 'static, B: 'static>(
+    iso: AnIso<S, T, A, B>,
+) -> (CFn<S, A>, CFn<B, T>) {
+    let exchange = (iso.0.optic)(Exchange {
+        to: CFn::new(|a: A| a),
+        from: CFn::new(|b: B| b),
+    });
+    (exchange.to, exchange.from)
+}
+
+/// Reverses an `Iso`: an iso from `S` to `A` becomes an iso from `B` to `T`.
+pub fn re<PO, PI, S: 'static, T: 'static, A: 'static, B: 'static>(
+    iso: AnIso<S, T, A, B>,
+) -> Iso<PO, PI, B, A, T, S>
+where
+    PO: Profunctor<B, A>,
+    PI: Profunctor<T, S, Pro<B, A> = PO>,
+{
+    let (to, from) = with_iso(iso);
+    self::iso(from, to)
+}
+
+/// Reverses an `Iso`. This is the same as [`re`], under the name used by Haskell's `lens`.
+pub fn from<PO, PI, S: 'static, T: 'static, A: 'static, B: 'static>(
+    iso: AnIso<S, T, A, B>,
+) -> Iso<PO, PI, B, A, T, S>
+where
+    PO: Profunctor<B, A>,
+    PI: Profunctor<T, S, Pro<B, A> = PO>,
+{
+    re(iso)
+}
+
+/// Constructs a `Grate` from a function that builds a whole `T` given a way to turn any
+/// projection `S -> A` into a part `B`.
+///
+/// For a pair of `A`s, `build` would be `|f| (f(first), f(second))`, where `first` and
+/// `second` project out the halves.
+///
+/// # Parameters
+/// - `build`: Given `f: CFn<S, A> -> B`, builds the whole `T` by calling `f` once per part.
+///
+/// # Returns
+/// A `Grate<PO, PI, S, T, A, B>`. The profunctor types `PO` and `PI` are usually inferred.
+pub fn grate<PO, PI, S: Clone + 'static, T: 'static, A: 'static, B: 'static>(
+    build: impl Fn(&dyn Fn(CFn<S, A>) -> B) -> T + 'static,
+) -> Grate<PO, PI, S, T, A, B>
+where
+    PO: Closed<S, T>,
+    PI: Closed<A, B>,
+    <PI as Profunctor<A, B>>::Pro<CFn<Projection<S, A>, A>, CFn<Projection<S, A>, B>>:
+        Profunctor<CFn<Projection<S, A>, A>, CFn<Projection<S, A>, B>, Pro<S, T> = PO>,
+{
+    let build = Rc::new(build);
+    Grate(Optic::new(move |pi: PI| {
+        let build = build.clone();
+        pi.closed::<Projection<S, A>>().dimap(
+            |s: S| CFn::new(move |proj: Projection<S, A>| proj.call(s.clone())),
+            move |k: CFn<Projection<S, A>, B>| build(&|proj| k.call(Rc::new(proj))),
+        )
+    }))
+}
+
+/// Constructs a `Traversal` from a traversal function.
+///
+/// # Parameters
+/// - `traversal`: A [`Traversing`] value, which must support the `Effect` of the
+///   inner profunctor `PI` (e.g. [`Traversed`]). It is cloned for every run of the optic.
+///
+/// # Returns
+/// A `Traversal<PO, PI, S, T, A, B>`. The profunctor types `PO` and `PI` are usually inferred.
+pub fn traversal<PO, PI, W, S: 'static, T: 'static, A: 'static, B: 'static>(
+    traversal: W,
+) -> Traversal<PO, PI, S, T, A, B>
+where
+    PO: Wander<S, T>,
+    PI: Wander<A, B, Pro<S, T> = PO>,
+    W: Traversing<PI::Effect, S, T, A, B> + Clone + 'static,
+{
+    Traversal(Optic::new(move |pi: PI| pi.wander(traversal.clone())))
+}
+
+/// A `Traversal` that focuses on every element of a `Vec`.
+pub fn traversed<PO, PI, A: 'static, B: 'static>() -> Traversal<PO, PI, Vec<A>, Vec<B>, A, B>
+where
+    PO: Wander<Vec<A>, Vec<B>>,
+    PI: Wander<A, B, Pro<Vec<A>, Vec<B>> = PO>,
+    VecKind: Traversable<PI::Effect, A, B>,
+{
+    traversal(Traversed::<VecKind>::new())
+}
+
+/// Constructs a `Setter` from a function that lifts a modification of the parts
+/// (`A -> B`) into a modification of the whole (`S -> T`).
+///
+/// A `Setter` can only be written through, but it needs no `Strong` or `Choice`
+/// structure, so any "map-like" operation can be turned into one.
+pub fn sets<S, T, A, B>(lift: impl Fn(CFn<A, B>) -> CFn<S, T> + 'static) -> Setter<S, T, A, B> {
+    Optic::new(lift)
+}
+
+/// A `Setter` that focuses on every value inside a Kind-encoded `Functor`,
+/// e.g. the value of an `Option` or every element of a `Vec`.
+pub fn mapped<F, A: 'static, B: 'static>() -> Setter<F::Of<A>, F::Of<B>, A, B>
+where
+    F: Functor<A, B> + 'static,
+{
+    sets(|f: CFn<A, B>| {
+        let f = Rc::new(f);
+        CFn::new(move |fa: F::Of<A>| {
+            let f = f.clone();
+            F::map(fa, move |a: A| f.call(a))
+        })
+    })
+}
+
+/// Modifies every part `A` focused by an optic with `func`, producing the new whole `T`.
+///
+/// # Parameters
+/// - `optic`: The optic (a `Lens`, `Prism`, `Traversal`, ...) run as a [`Setter`].
+/// - `func`: The function `A -> B` applied to each focus.
+/// - `s`: The whole structure.
+pub fn over<S: 'static, T: 'static, A: 'static, B: 'static>(
+    optic: Setter<S, T, A, B>,
+    func: impl Fn(A) -> B + 'static,
+    s: S,
+) -> T {
+    (optic.optic)(CFn::new(func)).call(s)
+}
+
+/// Replaces every part focused by an optic with `b`, producing the new whole `T`.
+pub fn set<S: 'static, T: 'static, A: 'static, B: Clone + 'static>(
+    optic: Setter<S, T, A, B>,
+    b: B,
+    s: S,
+) -> T {
+    over(optic, move |_: A| b.clone(), s)
+}
+
+/// Collects every part `A` focused by an optic (typically a `Traversal`) into a `Vec`,
+/// from left to right.
+///
+/// The optic is run with [`Forget`] into the `Vec<A>` monoid.
+pub fn to_list_of<S: 'static, T: 'static, A: 'static, B: 'static>(
+    optic: Fold<Vec<A>, S, T, A, B>,
+    s: S,
+) -> Vec<A> {
+    let inner_profunctor = Forget {
+        inner: CFn::new(|a: A| vec![a]),
+        _forget: PhantomData,
+    };
+    (optic.optic)(inner_profunctor).inner.call(s)
+}
+
+/// Runs an optic with the effectful function `func`, giving `S -> FKind::Of<T>`.
+///
+/// With a `Lens` this applies `func` to the focus and rebuilds the structure inside the
+/// effect; with a `Traversal` the effects of every focus are sequenced, so a single
+/// `None` or `Err` fails the whole traversal.
+///
+/// # Example
+/// ```
+/// use monadify::kind_based::kind::OptionKind;
+/// use monadify::profunctor::{traverse_of, traversed, _1};
+///
+/// let checked = |x: i32| if x >= 0 { Some(x as u32) } else { None };
+/// assert_eq!(traverse_of::<OptionKind, _, _, _, _>(_1().0, checked, (4, "a")), Some((4, "a")));
+/// assert_eq!(traverse_of::<OptionKind, _, _, _, _>(traversed().0, checked, vec![1, -2]), None);
+/// ```
+pub fn traverse_of<FKind: Kind, S: 'static, T: 'static, A: 'static, B: 'static>(
+    optic: ATraversal<FKind, S, T, A, B>,
+    func: impl Fn(A) -> FKind::Of<B> + 'static,
+    s: S,
+) -> FKind::Of<T>
+where
+    Star<FKind, A, B>: Profunctor<A, B>,
+    Star<FKind, S, T>: Profunctor<S, T>,
+{
+    (optic.optic)(Star::new(func)).run(s)
+}
+
+/// Combines two wholes `s1` and `s2` by zipping their parts with `func`.
+///
+/// # Example
+/// ```
+/// use monadify::function::CFn;
+/// use monadify::profunctor::{grate, zip_with_of, Grate, Zipping};
+///
+/// type Both = Grate<Zipping<(i32, i32), (i32, i32)>, Zipping<i32, i32>, (i32, i32), (i32, i32), i32, i32>;
+/// let both: Both = grate(|f| (f(CFn::new(|p: (i32, i32)| p.0)), f(CFn::new(|p: (i32, i32)| p.1))));
+/// assert_eq!(zip_with_of(both.0, |x, y| x + y, (1, 2), (10, 20)), (11, 22));
+/// ```
+pub fn zip_with_of<S: 'static, T: 'static, A: 'static, B: 'static>(
+    optic: AZipping<S, T, A, B>,
+    func: impl Fn(A, A) -> B + 'static,
+    s1: S,
+    s2: S,
+) -> T {
+    (optic.optic)(Zipping::new(func)).zip.call((s1, s2))
+}
+
 /// Maps the input of a `Profunctor` (contravariant mapping).
 /// `lcmap(f, p)` is equivalent to `p.dimap(f, id)`.
 ///
@@ -0,0 +1,193 @@
+//! # Thread-safe Kind traits for the `monadify` library
+//!
+//! The Kind traits of [`crate::functor`], [`crate::apply`], [`crate::applicative`] and
+//! [`crate::monad`] accept any `'static` closure, so an instance that stores those
+//! closures (a function or a Reader) can never be `Send`. This module mirrors that
+//! stack with closures that are also `Send + Sync`, so the structures built from
+//! them can be moved into and shared between threads:
+//!
+//! - [`SendFunctor`]: `map`.
+//! - [`SendApply`]: `apply`, with the wrapped function an [`ArcFn`] rather than a `CFn`.
+//! - [`SendApplicative`]: `pure`, for `Send + Sync` values.
+//! - [`SendBind`]: `bind`.
+//! - [`SendMonad`]: `join`.
+//!
+//! The laws are those of the corresponding Kind traits.
+//!
+//! ## Key Components
+//! - [`ArcFnKind<X>`]: a Reader over `X` built from [`ArcFn`].
+//! - [`SendFnOnceKind<X>`]: its once-callable counterpart built from [`SendFnOnce`].
+//! - [`crate::transformers::send_reader::SendReaderTKind`]: a `Send + Sync` `ReaderT`.
+//!
+//! ## Example
+//! ```
+//! use monadify::function::ArcFn;
+//! use monadify::kind_based::kind::ArcFnKind;
+//! use monadify::send::{SendBind, SendFunctor};
+//!
+//! let scaled = ArcFnKind::<i32>::map(ArcFn::new(|env: i32| env * 10), |x| x + 1);
+//! let offset = ArcFnKind::<i32>::bind(scaled, |x| ArcFn::new(move |env: i32| x - env));
+//!
+//! let result = std::thread::scope(|s| s.spawn(|| offset.call(2)).join().unwrap());
+//! assert_eq!(result, 19);
+//! ```
+
+use crate::function::{ArcFn, SendFnOnce};
+use crate::kind_based::kind::{ArcFnKind, Kind1, SendFnOnceKind};
+
+/// The `Send + Sync` counterpart of [`crate::functor::kind::Functor`].
+pub trait SendFunctor<A, B>: Kind1 {
+    /// Maps a thread-safe function `A -> B` over `Self::Of<A>`.
+    fn map(
+        input: Self::Of<A>,
+        func: impl FnMut(A) -> B + Clone + Send + Sync + 'static,
+    ) -> Self::Of<B>;
+}
+
+/// The `Send + Sync` counterpart of [`crate::apply::kind::Apply`].
+///
+/// The wrapped functions are [`ArcFn`]s, so a container of them can itself be `Send`.
+pub trait SendApply<A, B>: SendFunctor<A, B>
+where
+    Self: Sized + Kind1,
+{
+    /// Applies the function(s) in `function_container` to the value(s) in `value_container`.
+    fn apply(
+        value_container: Self::Of<A>,
+        function_container: Self::Of<ArcFn<A, B>>,
+    ) -> Self::Of<B>;
+}
+
+/// The `Send + Sync` counterpart of [`crate::applicative::kind::Applicative`].
+pub trait SendApplicative<T>: SendApply<T, T>
+where
+    Self: Sized + Kind1,
+    T: 'static,
+{
+    /// Lifts a value into the context.
+    fn pure(value: T) -> Self::Of<T>;
+}
+
+/// The `Send + Sync` counterpart of [`crate::monad::kind::Bind`].
+pub trait SendBind<A, B>: SendApply<A, B>
+where
+    Self: Sized + Kind1,
+{
+    /// Sequences `input` with a thread-safe function returning the next computation.
+    fn bind(
+        input: Self::Of<A>,
+        func: impl FnMut(A) -> Self::Of<B> + Clone + Send + Sync + 'static,
+    ) -> Self::Of<B>;
+}
+
+/// The `Send + Sync` counterpart of [`crate::monad::kind::Monad`].
+pub trait SendMonad<A>: SendApplicative<A> + SendBind<A, A>
+where
+    Self: Sized + Kind1,
+    A: 'static,
+{
+    /// Flattens `Self::Of<Self::Of<A>>` into `Self::Of<A>`.
+    fn join(mma: Self::Of<Self::Of<A>>) -> Self::Of<A>;
+}
+
+// --- ArcFnKind: a Reader over `X` ---
+
+impl<X: 'static, A: 'static, B: 'static> SendFunctor<A, B> for ArcFnKind<X> {
+    /// Post-composes `func`: the result is `x -> func(input(x))`.
+    fn map(
+        input: ArcFn<X, A>,
+        func: impl FnMut(A) -> B + Clone + Send + Sync + 'static,
+    ) -> ArcFn<X, B> {
+        ArcFn::new(move |x| func.clone()(input.call(x)))
+    }
+}
+
+impl<X: Clone + 'static, A: 'static, B: 'static> SendApply<A, B> for ArcFnKind<X> {
+    /// Passes the same argument to both functions: `x -> function(x)(value(x))`.
+    fn apply(
+        value_container: ArcFn<X, A>,
+        function_container: ArcFn<X, ArcFn<A, B>>,
+    ) -> ArcFn<X, B> {
+        ArcFn::new(move |x: X| {
+            function_container
+                .call(x.clone())
+                .call(value_container.call(x))
+        })
+    }
+}
+
+impl<X: Clone + 'static, T: Clone + Send + Sync + 'static> SendApplicative<T> for ArcFnKind<X> {
+    /// A function that ignores its argument and returns `value`.
+    fn pure(value: T) -> ArcFn<X, T> {
+        ArcFn::new(move |_| value.clone())
+    }
+}
+
+impl<X: Clone + 'static, A: 'static, B: 'static> SendBind<A, B> for ArcFnKind<X> {
+    /// Runs `input`, then the function `func` picks, on the same argument.
+    fn bind(
+        input: ArcFn<X, A>,
+        func: impl FnMut(A) -> ArcFn<X, B> + Clone + Send + Sync + 'static,
+    ) -> ArcFn<X, B> {
+        ArcFn::new(move |x: X| func.clone()(input.call(x.clone())).call(x))
+    }
+}
+
+impl<X: Clone + 'static, A: Clone + Send + Sync + 'static> SendMonad<A> for ArcFnKind<X> {
+    /// Passes the argument to both the outer and the inner function.
+    fn join(mma: ArcFn<X, ArcFn<X, A>>) -> ArcFn<X, A> {
+        ArcFn::new(move |x: X| mma.call(x.clone()).call(x))
+    }
+}
+
+// --- SendFnOnceKind: a once-callable Reader over `X` ---
+
+impl<X: 'static, A: 'static, B: 'static> SendFunctor<A, B> for SendFnOnceKind<X> {
+    /// Post-composes `func`: the result is `x -> func(input(x))`.
+    fn map(
+        input: SendFnOnce<X, A>,
+        mut func: impl FnMut(A) -> B + Clone + Send + Sync + 'static,
+    ) -> SendFnOnce<X, B> {
+        SendFnOnce::new(move |x| func(input.call_once(x)))
+    }
+}
+
+impl<X: Clone + 'static, A: 'static, B: 'static> SendApply<A, B> for SendFnOnceKind<X> {
+    /// Passes the same argument to both functions: `x -> function(x)(value(x))`.
+    fn apply(
+        value_container: SendFnOnce<X, A>,
+        function_container: SendFnOnce<X, ArcFn<A, B>>,
+    ) -> SendFnOnce<X, B> {
+        SendFnOnce::new(move |x: X| {
+            function_container
+                .call_once(x.clone())
+                .call(value_container.call_once(x))
+        })
+    }
+}
+
+impl<X: Clone + 'static, T: Clone + Send + Sync + 'static> SendApplicative<T>
+    for SendFnOnceKind<X>
+{
+    /// A function that ignores its argument and returns `value`.
+    fn pure(value: T) -> SendFnOnce<X, T> {
+        SendFnOnce::new(move |_| value)
+    }
+}
+
+impl<X: Clone + 'static, A: 'static, B: 'static> SendBind<A, B> for SendFnOnceKind<X> {
+    /// Runs `input`, then the function `func` picks, on the same argument.
+    fn bind(
+        input: SendFnOnce<X, A>,
+        mut func: impl FnMut(A) -> SendFnOnce<X, B> + Clone + Send + Sync + 'static,
+    ) -> SendFnOnce<X, B> {
+        SendFnOnce::new(move |x: X| func(input.call_once(x.clone())).call_once(x))
+    }
+}
+
+impl<X: Clone + 'static, A: Clone + Send + Sync + 'static> SendMonad<A> for SendFnOnceKind<X> {
+    /// Passes the argument to both the outer and the inner function.
+    fn join(mma: SendFnOnce<X, SendFnOnce<X, A>>) -> SendFnOnce<X, A> {
+        SendFnOnce::new(move |x: X| mma.call_once(x.clone()).call_once(x))
+    }
+}
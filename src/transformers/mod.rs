@@ -1,3 +1,4 @@
 //! Monad transformers.
 
 pub mod reader;
+pub mod send_reader;
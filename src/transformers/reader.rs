@@ -104,7 +104,7 @@ pub mod kind {
     use crate::function::CFn; // For Apply's function container type
     use crate::functor::kind as functor_kind; // Renamed hkt to kind
     use crate::identity::kind::IdentityKind;
-    use crate::kind_based::kind::{Kind, Kind1}; // Changed HKT, HKT1 to Kind, Kind1
+    use crate::kind_based::kind::{HasKind, Kind, Kind1}; // Changed HKT, HKT1 to Kind, Kind1
     use crate::monad::kind as monad_kind; // Renamed hkt to kind
     use std::marker::PhantomData;
     use std::rc::Rc; // Changed IdentityHKTMarker to IdentityKind
@@ -166,6 +166,11 @@ pub mod kind {
         // Renamed ReaderTHKTMarker, MMarker to MKind, HKT to Kind, HKT1 to Kind1
         type Of<A> = ReaderT<R, MKind, A>; // Changed Applied to Of
     }
+
+    impl<R, MKind: Kind1, A> HasKind for ReaderT<R, MKind, A> {
+        type Kind = ReaderTKind<R, MKind>;
+        type Arg = A;
+    }
     // Kind1 is implemented by the blanket impl in kind_based/kind.rs for types that impl Kind.
 
     /// A type alias for `ReaderT` with [`IdentityKind`] as the inner monad.
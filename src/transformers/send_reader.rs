@@ -0,0 +1,223 @@
+//! # A thread-safe ReaderT for the `monadify` library
+//!
+//! [`crate::transformers::reader::ReaderT`] stores its computation in an `Rc`, so a Reader
+//! pipeline can't leave the thread that built it. [`SendReaderT<R, MKind, A>`] is the same
+//! transformer (a computation `R -> MKind::Of<A>`) stored in an `Arc<dyn Fn + Send + Sync>`:
+//! it is `Send + Sync` whatever `R`, `MKind` and `A` are, so one pipeline can be shared by
+//! several worker threads, each running it with its own environment.
+//!
+//! Its Kind marker [`SendReaderTKind<R, MKind>`] implements the thread-safe traits of
+//! [`crate::send`], delegating to the ordinary Kind traits of the inner monad `MKind`.
+//!
+//! ## Example
+//! ```
+//! use monadify::kind_based::kind::OptionKind;
+//! use monadify::send::{SendBind, SendFunctor};
+//! use monadify::transformers::send_reader::{SendReaderT, SendReaderTKind};
+//!
+//! type Job<A> = SendReaderT<u32, OptionKind, A>;
+//! type JobKind = SendReaderTKind<u32, OptionKind>;
+//!
+//! let halve: Job<u32> = JobKind::bind(SendReaderT::ask(), |n: u32| {
+//!     SendReaderT::new(move |_| (n % 2 == 0).then_some(n / 2))
+//! });
+//! let report: Job<String> = JobKind::map(halve, |n: u32| format!("half is {n}"));
+//!
+//! let job = &report;
+//! let results: Vec<Option<String>> = std::thread::scope(|s| {
+//!     let handles: Vec<_> = [10, 7]
+//!         .into_iter()
+//!         .map(|env| s.spawn(move || job.run(env)))
+//!         .collect();
+//!     handles.into_iter().map(|h| h.join().unwrap()).collect()
+//! });
+//! assert_eq!(results, vec![Some("half is 5".to_string()), None]);
+//! ```
+
+use crate::applicative::kind::Applicative;
+use crate::apply::kind::Apply;
+use crate::function::{ArcFn, CFn};
+use crate::functor::kind::Functor;
+use crate::identity::kind::IdentityKind;
+use crate::kind_based::kind::{Kind, Kind1};
+use crate::monad::kind::Bind;
+use crate::send::{SendApplicative, SendApply, SendBind, SendFunctor, SendMonad};
+use crate::transformers::reader::ReaderT;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+/// A `Send + Sync` computation that reads an environment `R` and returns `MKind::Of<A>`.
+///
+/// # Type Parameters
+/// - `R`: The type of the read-only environment.
+/// - `MKind`: The Kind marker for the inner monad (e.g., [`crate::kind_based::kind::OptionKind`]).
+/// - `A`: The type of the value produced within the inner monad.
+pub struct SendReaderT<R, MKind: Kind1, A> {
+    /// The computation: takes an environment `R` and returns the result in the inner monad.
+    pub run_reader_t: Arc<dyn Fn(R) -> MKind::Of<A> + Send + Sync + 'static>,
+}
+
+impl<R, MKind: Kind1, A> SendReaderT<R, MKind, A> {
+    /// Creates a new `SendReaderT` from a thread-safe function `R -> MKind::Of<A>`.
+    pub fn new<F>(f: F) -> Self
+    where
+        F: Fn(R) -> MKind::Of<A> + Send + Sync + 'static,
+    {
+        SendReaderT {
+            run_reader_t: Arc::new(f),
+        }
+    }
+
+    /// Runs the computation with the environment `env`.
+    pub fn run(&self, env: R) -> MKind::Of<A> {
+        (self.run_reader_t)(env)
+    }
+
+    /// Runs this computation with an environment modified by `f`.
+    pub fn local(self, f: impl Fn(R) -> R + Send + Sync + 'static) -> Self
+    where
+        R: 'static,
+        MKind: 'static,
+        A: 'static,
+    {
+        SendReaderT::new(move |env| self.run(f(env)))
+    }
+}
+
+impl<R, MKind> SendReaderT<R, MKind, R>
+where
+    MKind: Applicative<R> + Kind1 + 'static,
+    R: 'static,
+{
+    /// Retrieves the environment.
+    pub fn ask() -> Self {
+        SendReaderT::new(MKind::pure)
+    }
+}
+
+/// Cloning a `SendReaderT` shares the wrapped computation.
+impl<R, MKind: Kind1, A> Clone for SendReaderT<R, MKind, A> {
+    fn clone(&self) -> Self {
+        SendReaderT {
+            run_reader_t: self.run_reader_t.clone(),
+        }
+    }
+}
+
+/// Converts a `SendReaderT` into a `ReaderT` running the same (shared) computation.
+impl<R: 'static, MKind: Kind1 + 'static, A: 'static> From<SendReaderT<R, MKind, A>>
+    for ReaderT<R, MKind, A>
+{
+    fn from(reader: SendReaderT<R, MKind, A>) -> Self {
+        ReaderT::new(move |env| reader.run(env))
+    }
+}
+
+/// The Kind marker for `SendReaderT<R, MKind, _>`.
+#[derive(Default)]
+pub struct SendReaderTKind<R, MKind: Kind1>(PhantomData<(R, MKind)>);
+
+impl<R, MKind: Kind1> Kind for SendReaderTKind<R, MKind> {
+    type Of<A> = SendReaderT<R, MKind, A>;
+}
+
+/// A `SendReaderT` over [`IdentityKind`]: a plain, thread-safe Reader `R -> Identity<A>`.
+pub type SendReader<R, A> = SendReaderT<R, IdentityKind, A>;
+
+/// Turns the `ArcFn`s of a [`SendApply`] function container into the `CFn`s the inner
+/// monad's `Apply` expects.
+fn to_cfn<A: 'static, B: 'static>(f: ArcFn<A, B>) -> CFn<A, B> {
+    f.into()
+}
+
+impl<R, MKind, A, B> SendFunctor<A, B> for SendReaderTKind<R, MKind>
+where
+    R: 'static,
+    MKind: Functor<A, B> + 'static,
+    A: 'static,
+    B: 'static,
+{
+    /// Maps `func` over the result, within the inner monad.
+    fn map(
+        input: SendReaderT<R, MKind, A>,
+        func: impl FnMut(A) -> B + Clone + Send + Sync + 'static,
+    ) -> SendReaderT<R, MKind, B> {
+        SendReaderT::new(move |env| MKind::map(input.run(env), func.clone()))
+    }
+}
+
+impl<R, MKind, A, B> SendApply<A, B> for SendReaderTKind<R, MKind>
+where
+    R: Clone + 'static,
+    MKind: Apply<A, B> + Functor<ArcFn<A, B>, CFn<A, B>> + 'static,
+    A: 'static,
+    B: 'static,
+{
+    /// Runs both computations with the same environment and applies within the inner monad.
+    fn apply(
+        value_container: SendReaderT<R, MKind, A>,
+        function_container: SendReaderT<R, MKind, ArcFn<A, B>>,
+    ) -> SendReaderT<R, MKind, B> {
+        SendReaderT::new(move |env: R| {
+            let functions = <MKind as Functor<ArcFn<A, B>, CFn<A, B>>>::map(
+                function_container.run(env.clone()),
+                to_cfn,
+            );
+            MKind::apply(value_container.run(env), functions)
+        })
+    }
+}
+
+impl<R, MKind, T> SendApplicative<T> for SendReaderTKind<R, MKind>
+where
+    R: Clone + 'static,
+    MKind: Applicative<T> + Functor<ArcFn<T, T>, CFn<T, T>> + 'static,
+    T: Clone + Send + Sync + 'static,
+{
+    /// Ignores the environment and returns `MKind::pure(value)`.
+    fn pure(value: T) -> SendReaderT<R, MKind, T> {
+        SendReaderT::new(move |_| MKind::pure(value.clone()))
+    }
+}
+
+impl<R, MKind, A, B> SendBind<A, B> for SendReaderTKind<R, MKind>
+where
+    R: Clone + 'static,
+    MKind: Bind<A, B> + Functor<ArcFn<A, B>, CFn<A, B>> + 'static,
+    A: 'static,
+    B: 'static,
+{
+    /// Runs `input`, then the computation `func` picks, with the same environment.
+    fn bind(
+        input: SendReaderT<R, MKind, A>,
+        func: impl FnMut(A) -> SendReaderT<R, MKind, B> + Clone + Send + Sync + 'static,
+    ) -> SendReaderT<R, MKind, B> {
+        SendReaderT::new(move |env: R| {
+            let mut func = func.clone();
+            let next_env = env.clone();
+            <MKind as Bind<A, B>>::bind(input.run(env), move |a| func(a).run(next_env.clone()))
+        })
+    }
+}
+
+impl<R, MKind, A> SendMonad<A> for SendReaderTKind<R, MKind>
+where
+    R: Clone + 'static,
+    MKind: Applicative<A>
+        + Bind<A, A>
+        + Bind<SendReaderT<R, MKind, A>, A>
+        + Functor<ArcFn<A, A>, CFn<A, A>>
+        + 'static,
+    A: Clone + Send + Sync + 'static,
+{
+    /// Runs the outer computation, then the inner one it returns, with the same environment.
+    fn join(mma: SendReaderT<R, MKind, SendReaderT<R, MKind, A>>) -> SendReaderT<R, MKind, A> {
+        SendReaderT::new(move |env: R| {
+            let inner_env = env.clone();
+            <MKind as Bind<SendReaderT<R, MKind, A>, A>>::bind(
+                mma.run(env),
+                move |inner: SendReaderT<R, MKind, A>| inner.run(inner_env.clone()),
+            )
+        })
+    }
+}
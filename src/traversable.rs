@@ -0,0 +1,177 @@
+//! # Kind-based Traversable for the `monadify` library
+//!
+//! A Traversable structure `T` can be walked from left to right, running an
+//! effectful function `A -> G::Of<B>` on every element and collecting the
+//! effects into a single `G::Of<T::Of<B>>`, where `G` is an `Applicative`.
+//! For example, traversing a `Vec<&str>` with a parser returning `Option<i32>`
+//! yields `Option<Vec<i32>>`: `Some` only if every element parsed.
+//!
+//! The [`Traversable`] trait is generic over:
+//! - `Self`: The Kind marker of the structure being traversed (e.g., [`VecKind`]).
+//! - `G`: The Kind marker of the Applicative effect (e.g., [`OptionKind`]).
+//! - `A`: The element type before the traversal.
+//! - `B`: The element type after the traversal.
+//!
+//! Each instance states exactly which `Applicative`/`Apply` capabilities of `G` it needs.
+//!
+//! ## Deriving other classes
+//! `traverse` is powerful enough to recover `map` and `fold_map`:
+//! - [`map_default`] traverses with [`IdentityKind`], whose effect is trivial.
+//! - [`fold_map_default`] traverses with [`ConstKind<M>`], which ignores the rebuilt
+//!   structure and accumulates a [`Monoid`] `M` instead.
+//!
+//! ## Example
+//! ```
+//! use monadify::traversable::{fold_map_default, map_default, Traversable};
+//! use monadify::kind_based::kind::{OptionKind, VecKind};
+//!
+//! let parsed: Option<Vec<i32>> = <VecKind as Traversable<OptionKind, &str, i32>>::traverse(
+//!     vec!["1", "2", "3"],
+//!     |s: &str| s.parse().ok(),
+//! );
+//! assert_eq!(parsed, Some(vec![1, 2, 3]));
+//!
+//! let joined: String = fold_map_default::<VecKind, _, _, _>(vec![1, 2, 3], |x: i32| x.to_string());
+//! assert_eq!(joined, "123");
+//!
+//! let doubled: Vec<i32> = map_default::<VecKind, _, _, _>(vec![1, 2, 3], |x: i32| x * 2);
+//! assert_eq!(doubled, vec![2, 4, 6]);
+//! ```
+
+use crate::applicative::kind::Applicative;
+use crate::apply::kind::Apply;
+use crate::constant::{Const, ConstKind};
+use crate::function::CFn;
+use crate::functor::kind::Functor;
+use crate::identity::{Identity, IdentityKind};
+use crate::kind_based::kind::{Kind, Kind1, OptionKind, ResultKind, VecKind};
+use crate::monoid::Monoid;
+
+/// Represents a Kind-encoded structure that can be traversed with an Applicative effect `G`.
+///
+/// ## Traversable Laws
+/// 1.  **Identity**: `T::traverse(x, Identity) == Identity(x)`.
+/// 2.  **Naturality**: for a natural transformation `n: G ~> H`,
+///     `n(T::traverse(x, f)) == T::traverse(x, |a| n(f(a)))`.
+/// 3.  **Composition**: traversing with a composed Applicative equals two nested traversals.
+pub trait Traversable<G: Kind, A, B>: Kind1 {
+    /// Runs `func` on every element, left to right, and collects the effects.
+    ///
+    /// # Parameters
+    /// - `input`: The structure to traverse, e.g. `Vec<A>`.
+    /// - `func`: The effectful function `A -> G::Of<B>`.
+    ///
+    /// # Returns
+    /// The rebuilt structure inside the effect, e.g. `Option<Vec<B>>`.
+    fn traverse(
+        input: Self::Of<A>,
+        func: impl FnMut(A) -> G::Of<B> + Clone + 'static,
+    ) -> G::Of<Self::Of<B>>;
+}
+
+impl<G, A, B> Traversable<G, A, B> for OptionKind
+where
+    G: Applicative<Option<B>> + Functor<B, Option<B>>,
+    B: 'static,
+{
+    /// `None` becomes `G::pure(None)`; `Some(a)` becomes `func(a)` mapped with `Some`.
+    fn traverse(
+        input: Option<A>,
+        mut func: impl FnMut(A) -> G::Of<B> + Clone + 'static,
+    ) -> G::Of<Option<B>> {
+        match input {
+            Some(a) => G::map(func(a), Some),
+            None => G::pure(None),
+        }
+    }
+}
+
+impl<G, A, B, E> Traversable<G, A, B> for ResultKind<E>
+where
+    G: Applicative<Result<B, E>> + Functor<B, Result<B, E>>,
+    B: 'static,
+    E: 'static,
+{
+    /// `Err(e)` becomes `G::pure(Err(e))`; `Ok(a)` becomes `func(a)` mapped with `Ok`.
+    fn traverse(
+        input: Result<A, E>,
+        mut func: impl FnMut(A) -> G::Of<B> + Clone + 'static,
+    ) -> G::Of<Result<B, E>> {
+        match input {
+            Ok(a) => G::map(func(a), Ok),
+            Err(e) => G::pure(Err(e)),
+        }
+    }
+}
+
+impl<G, A, B> Traversable<G, A, B> for VecKind
+where
+    G: Applicative<Vec<B>>
+        + Apply<Vec<B>, Vec<B>>
+        + Functor<B, CFn<Vec<B>, Vec<B>>>
+        + Functor<Vec<B>, Vec<B>>,
+    B: Clone + 'static, // An effect like `Vec` pushes each `b` into several results
+{
+    /// Runs `func` on the elements from left to right, then combines the effects from the
+    /// right with `G::apply`, so each step takes the results so far by value and pushes
+    /// one element onto them. The results are built back to front and reversed once.
+    fn traverse(input: Vec<A>, func: impl FnMut(A) -> G::Of<B> + Clone + 'static) -> G::Of<Vec<B>> {
+        let len = input.len();
+        let effects: Vec<G::Of<B>> = input.into_iter().map(func).collect();
+        let reversed =
+            effects
+                .into_iter()
+                .rev()
+                .fold(G::pure(Vec::with_capacity(len)), |acc, fb| {
+                    let push = G::map(fb, |b: B| {
+                        CFn::new(move |mut bs: Vec<B>| {
+                            bs.push(b.clone());
+                            bs
+                        })
+                    });
+                    G::apply(acc, push)
+                });
+        G::map(reversed, |mut bs: Vec<B>| {
+            bs.reverse();
+            bs
+        })
+    }
+}
+
+impl<G, A, B> Traversable<G, A, B> for IdentityKind
+where
+    G: Functor<B, Identity<B>>,
+    B: 'static,
+{
+    /// Runs `func` on the single value and wraps the result back in `Identity`.
+    fn traverse(
+        input: Identity<A>,
+        mut func: impl FnMut(A) -> G::Of<B> + Clone + 'static,
+    ) -> G::Of<Identity<B>> {
+        G::map(func(input.0), Identity)
+    }
+}
+
+/// Derives `Functor::map` from a [`Traversable`] instance by traversing with [`IdentityKind`].
+pub fn map_default<T, A, B, F>(input: T::Of<A>, func: F) -> T::Of<B>
+where
+    T: Traversable<IdentityKind, A, B>,
+    F: FnMut(A) -> B + Clone + 'static,
+{
+    let mut func = func;
+    T::traverse(input, move |a: A| Identity(func(a))).0
+}
+
+/// Derives a `fold_map` from a [`Traversable`] instance by traversing with [`ConstKind<M>`].
+///
+/// Every element is mapped into the monoid `M` and the results are combined from left
+/// to right, starting from `M::empty()`.
+pub fn fold_map_default<T, M, A, F>(input: T::Of<A>, func: F) -> M
+where
+    T: Traversable<ConstKind<M>, A, ()>,
+    M: Monoid,
+    F: FnMut(A) -> M + Clone + 'static,
+{
+    let mut func = func;
+    T::traverse(input, move |a: A| Const::new(func(a))).get_const()
+}
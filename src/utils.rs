@@ -1,3 +1,6 @@
+use crate::function::CFn;
+use std::rc::Rc;
+
 /// Creates a `CFn` (boxed `Fn`) from a nullary (0-argument) closure.
 ///
 /// The resulting `CFn` will take a dummy argument (e.g., `()`) which it ignores,
@@ -62,6 +65,8 @@ macro_rules! fn1 {
 ///
 /// assert_eq!(curried_add(3).call(7), 10);
 /// ```
+///
+/// Note that the outer stage is a plain closure; [`curry2`] returns a `CFn` at every stage.
 #[macro_export]
 macro_rules! fn2 {
     ($closure:expr) => {
@@ -86,6 +91,8 @@ macro_rules! fn2 {
 ///
 /// assert_eq!(curried_add3(1)(2).call(3), 6);
 /// ```
+///
+/// Note that the outer stage is a plain closure; [`curry3`] returns a `CFn` at every stage.
 #[macro_export]
 macro_rules! fn3 {
     ($closure:expr) => {
@@ -96,3 +103,235 @@ macro_rules! fn3 {
         }
     };
 }
+
+/// Fixes some arguments of a multi-argument function, leaving `_` holes for the rest.
+///
+/// `partial!(f, a, _, c)` evaluates `f`, `a` and `c` once and returns a `CFn` taking the
+/// missing argument. With several holes the result is curried, one `CFn` stage per hole
+/// in order, like [`curry2`]..[`curry8`]; with no holes it is a `CFn<(), R>` that runs
+/// `f` when called with `()`. Since every stage can be called many times, the fixed
+/// arguments and the holes before the last one must be `Clone`.
+///
+/// # Examples
+/// ```
+/// use monadify::partial;
+///
+/// fn clamp(low: i32, x: i32, high: i32) -> i32 {
+///     x.max(low).min(high)
+/// }
+///
+/// let to_percent = partial!(clamp, 0, _, 100);
+/// assert_eq!(to_percent.call(150), 100);
+/// assert_eq!(to_percent.call(42), 42);
+///
+/// let at_least_ten = partial!(clamp, 10, _, _);
+/// assert_eq!(at_least_ten.call(3).call(50), 10);
+///
+/// let fixed = partial!(clamp, 0, 7, 5);
+/// assert_eq!(fixed.call(()), 5);
+/// ```
+#[macro_export]
+macro_rules! partial {
+    ($f:expr, $($args:tt)*) => {
+        $crate::partial!(@parse [$f] [] [] [] $($args)*)
+    };
+    // Each step introduces its own `__arg`, which macro hygiene keeps distinct.
+    (@parse $f:tt [$($fixed:tt)*] [$($holes:ident)*] [$($call:ident)*] _ $(, $($rest:tt)*)?) => {
+        $crate::partial!(@parse $f [$($fixed)*] [$($holes)* __arg] [$($call)* __arg] $($($rest)*)?)
+    };
+    (@parse $f:tt [$($fixed:tt)*] [$($holes:ident)*] [$($call:ident)*] $e:expr $(, $($rest:tt)*)?) => {
+        $crate::partial!(@parse $f [$($fixed)* (__arg = $e)] [$($holes)*] [$($call)* __arg] $($($rest)*)?)
+    };
+    (@parse [$f:expr] [$(($name:ident = $e:expr))*] [] [$($call:ident)*]) => {{
+        let __f = $f;
+        $(let $name = $e;)*
+        $crate::function::CFn::new(move |()| __f($(::std::clone::Clone::clone(&$call)),*))
+    }};
+    (@parse [$f:expr] [$(($name:ident = $e:expr))*] [$($holes:ident)+] [$($call:ident)*]) => {{
+        let __f = ::std::rc::Rc::new($f);
+        $(let $name = $e;)*
+        $crate::partial!(@nest __f [$($name)*] [] [$($call)*] $($holes)*)
+    }};
+    (@nest $f:ident [$($fixed:ident)*] [$($bound:ident)*] [$($call:ident)*] $hole:ident $($rest:ident)*) => {
+        $crate::function::CFn::new(move |$hole| {
+            let $f = ::std::clone::Clone::clone(&$f);
+            $(let $fixed = ::std::clone::Clone::clone(&$fixed);)*
+            $(let $bound = ::std::clone::Clone::clone(&$bound);)*
+            $crate::partial!(@nest $f [$($fixed)*] [$($bound)* $hole] [$($call)*] $($rest)*)
+        })
+    };
+    (@nest $f:ident [$($fixed:ident)*] [$($bound:ident)*] [$($call:ident)*]) => {
+        $f($($call),*)
+    };
+}
+
+/// The type of a curried function: `curried!(R; A B)` is `CFn<A, CFn<B, R>>`.
+macro_rules! curried {
+    ($R:ty;) => { $R };
+    ($R:ty; $A:ident $($rest:ident)*) => { CFn<$A, curried!($R; $($rest)*)> };
+}
+
+/// Turns a function of two arguments into a curried one: `f(a, b)` becomes
+/// `curry2(f).call(a).call(b)`.
+///
+/// Every stage is a `CFn`, so a partially applied function can be mapped, applied or
+/// composed like any other. A stage may be called many times, so every argument but the
+/// last must be `Clone`.
+///
+/// # Examples
+/// ```
+/// use monadify::utils::{curry2, uncurry2};
+///
+/// let add = curry2(|x: i32, y: i32| x + y);
+/// let add_five = add.call(5);
+/// assert_eq!(add_five.call(10), 15);
+/// assert_eq!(add_five.call(1), 6);
+///
+/// let add_again = uncurry2(add);
+/// assert_eq!(add_again.call((2, 3)), 5);
+/// ```
+pub fn curry2<A, B, R>(f: impl Fn(A, B) -> R + 'static) -> CFn<A, CFn<B, R>>
+where
+    A: Clone + 'static,
+    B: 'static,
+    R: 'static,
+{
+    let f = Rc::new(f);
+    CFn::new(move |a: A| {
+        let f = f.clone();
+        CFn::new(move |b: B| f(a.clone(), b))
+    })
+}
+
+/// Turns a curried function of two arguments back into one taking both at once, as a
+/// pair; the inverse of [`curry2`].
+pub fn uncurry2<A, B, R>(f: CFn<A, CFn<B, R>>) -> CFn<(A, B), R>
+where
+    A: 'static,
+    B: 'static,
+    R: 'static,
+{
+    CFn::new(move |(a, b)| f.call(a).call(b))
+}
+
+/// Generates `curryN`/`uncurryN` from `curry(N-1)`: the first argument is fixed, and the
+/// remaining ones are curried by the previous function.
+macro_rules! impl_curry {
+    ($curry:ident, $uncurry:ident, $prev:ident, $n:literal;
+     $A:ident $a:ident, $($B:ident $b:ident),+; $Z:ident $z:ident) => {
+        #[doc = concat!("Turns a function of ", $n, " arguments into a curried one, with a `CFn` at every stage.")]
+        ///
+        /// Every argument but the last must be `Clone`. See [`curry2`].
+        pub fn $curry<$A, $($B,)+ $Z, R>(
+            f: impl Fn($A, $($B,)+ $Z) -> R + 'static,
+        ) -> curried!(R; $A $($B)+ $Z)
+        where
+            $A: Clone + 'static,
+            $($B: Clone + 'static,)+
+            $Z: 'static,
+            R: 'static,
+        {
+            let f = Rc::new(f);
+            CFn::new(move |$a: $A| {
+                let f = f.clone();
+                $prev(move |$($b: $B,)+ $z: $Z| f($a.clone(), $($b,)+ $z))
+            })
+        }
+
+        #[doc = concat!("Turns a curried function of ", $n, " arguments back into one taking them all at once, as a tuple; the inverse of [`", stringify!($curry), "`].")]
+        pub fn $uncurry<$A, $($B,)+ $Z, R>(
+            f: curried!(R; $A $($B)+ $Z),
+        ) -> CFn<($A, $($B,)+ $Z), R>
+        where
+            $A: 'static,
+            $($B: 'static,)+
+            $Z: 'static,
+            R: 'static,
+        {
+            CFn::new(move |($a, $($b,)+ $z)| f.call($a)$(.call($b))+.call($z))
+        }
+    };
+}
+
+impl_curry!(curry3, uncurry3, curry2, 3; A a, B b; C c);
+impl_curry!(curry4, uncurry4, curry3, 4; A a, B b, C c; D d);
+impl_curry!(curry5, uncurry5, curry4, 5; A a, B b, C c, D d; E e);
+impl_curry!(curry6, uncurry6, curry5, 6; A a, B b, C c, D d, E e; F f);
+impl_curry!(curry7, uncurry7, curry6, 7; A a, B b, C c, D d, E e, F f; G g);
+impl_curry!(curry8, uncurry8, curry7, 8; A a, B b, C c, D d, E e, F f, G g; H h);
+
+/// Swaps the first two arguments of a curried function: `flip(f).call(b).call(a)` is
+/// `f.call(a).call(b)`.
+///
+/// # Examples
+/// ```
+/// use monadify::utils::{curry2, flip};
+///
+/// let minus = curry2(|x: i32, y: i32| x - y);
+/// let subtract_from = flip(minus);
+/// assert_eq!(subtract_from.call(1).call(10), 9);
+/// ```
+pub fn flip<A, B, C>(f: CFn<A, CFn<B, C>>) -> CFn<B, CFn<A, C>>
+where
+    A: 'static,
+    B: Clone + 'static,
+    C: 'static,
+{
+    let f = Rc::new(f);
+    CFn::new(move |b: B| {
+        let f = f.clone();
+        CFn::new(move |a: A| f.call(a).call(b.clone()))
+    })
+}
+
+/// A function that ignores its argument and always returns `value`.
+///
+/// # Examples
+/// ```
+/// use monadify::utils::constant;
+///
+/// let always_seven = constant::<&str, i32>(7);
+/// assert_eq!(always_seven.call("anything"), 7);
+/// ```
+pub fn constant<A: 'static, B: Clone + 'static>(value: B) -> CFn<A, B> {
+    CFn::new(move |_| value.clone())
+}
+
+/// The function returning its argument unchanged.
+///
+/// # Examples
+/// ```
+/// use monadify::utils::identity;
+///
+/// assert_eq!(identity().call("same"), "same");
+/// ```
+pub fn identity<A: 'static>() -> CFn<A, A> {
+    CFn::new(|a| a)
+}
+
+/// Runs `g` on both arguments before combining them with `f`: `on(f, g).call(x).call(y)`
+/// is `f(g(x), g(y))`.
+///
+/// Like [`curry2`], the result is curried with a `CFn` at every stage, so the first
+/// argument must be `Clone`; use [`uncurry2`] to pass both arguments at once.
+///
+/// # Examples
+/// ```
+/// use monadify::utils::{on, uncurry2};
+///
+/// let same_length = on(|a: usize, b: usize| a == b, |s: &str| s.len());
+/// assert!(same_length.call("abc").call("xyz"));
+///
+/// let same_length = uncurry2(same_length);
+/// assert!(!same_length.call(("ab", "xyz")));
+/// ```
+pub fn on<A, B, C>(
+    f: impl Fn(B, B) -> C + 'static,
+    g: impl Fn(A) -> B + 'static,
+) -> CFn<A, CFn<A, C>>
+where
+    A: Clone + 'static,
+    C: 'static,
+{
+    curry2(move |x, y| f(g(x), g(y)))
+}
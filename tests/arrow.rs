@@ -0,0 +1,227 @@
+use monadify::arrow::{Arrow, ArrowChoice, Category, Semigroupoid};
+use monadify::function::{CFn, CFnMut, CFnOnce, RcFn};
+use monadify::identity::{Identity, IdentityKind};
+use monadify::kind_based::kind::{
+    CFnKind, CFnMutKind, CFnOnceKind, OptionKind, RcFnKind, ResultKind, VecKind,
+};
+use monadify::kleisli::{Kleisli, KleisliKind};
+use monadify::transformers::reader::{ReaderT, ReaderTKind};
+use monadify::{Choice, Profunctor, Strong};
+
+type Checked<A, B> = Kleisli<ResultKind<String>, A, B>;
+
+fn parse() -> Checked<&'static str, i32> {
+    Kleisli::new(|s: &str| s.parse::<i32>().map_err(|_| format!("not a number: {s}")))
+}
+
+fn positive() -> Checked<i32, u32> {
+    Kleisli::new(|n: i32| u32::try_from(n).map_err(|_| format!("negative: {n}")))
+}
+
+#[test]
+fn cfn_compose_and_id() {
+    let inc = CFn::arr(|x: i32| x + 1);
+    let show = CFn::arr(|x: i32| x.to_string());
+    assert_eq!(show.compose(inc).call(1), "2");
+    assert_eq!(CFn::<char, char>::id().call('q'), 'q');
+}
+
+#[test]
+fn cfn_split_and_fanout() {
+    let both = CFn::arr(|x: i32| x * 2).split(CFn::arr(|s: &str| s.len()));
+    assert_eq!(both.call((4, "abc")), (8, 3));
+
+    let stats =
+        CFn::arr(|v: Vec<i32>| v.iter().sum::<i32>()).fanout(CFn::arr(|v: Vec<i32>| v.len()));
+    assert_eq!(stats.call(vec![1, 2, 3]), (6, 3));
+
+    // `first`/`second` come from `Strong`.
+    assert_eq!(
+        CFn::arr(|x: i32| -x).first::<char>().call((1, 'a')),
+        (-1, 'a')
+    );
+}
+
+#[test]
+fn cfn_choose_and_fanin() {
+    let on_err = || CFn::arr(|e: String| e.len());
+    let on_ok = || CFn::arr(|x: i32| x > 0);
+    let chosen = on_err().choose(on_ok());
+    assert_eq!(chosen.call(Ok(5)), Ok(true));
+    assert_eq!(chosen.call(Err("boom".to_string())), Err(4));
+
+    let merged = on_err().fanin(CFn::arr(|x: i32| x as usize));
+    assert_eq!(merged.call(Ok(7)), 7);
+    assert_eq!(merged.call(Err("ab".to_string())), 2);
+    // `left`/`right` come from `Choice`.
+    assert_eq!(on_err().left::<()>().call(Err("xyz".to_string())), Err(3));
+}
+
+#[test]
+fn cfn_once_arrows_consume_their_input() {
+    let owned = String::from("moved");
+    let take = CFnOnce::new(move |suffix: &str| owned + suffix);
+    let len = CFnOnce::arr(|s: String| s.len());
+    assert_eq!(len.compose(take).call_once("!"), 6);
+
+    let pair = CFnOnce::arr(|x: i32| x + 1).split(CFnOnce::arr(|v: Vec<u8>| v.len()));
+    assert_eq!(pair.call_once((1, vec![0; 3])), (2, 3));
+    let fan = CFnOnce::arr(|s: String| s.clone()).fanout(CFnOnce::arr(|s: String| s.len()));
+    assert_eq!(fan.call_once("ab".to_string()), ("ab".to_string(), 2));
+    let merged = CFnOnce::arr(|e: u8| e as i32).fanin(CFnOnce::arr(|x: i32| x * 10));
+    assert_eq!(merged.call_once(Ok(3)), 30);
+    assert_eq!(CFnOnce::<u8, u8>::id().call_once(9), 9);
+    assert_eq!(
+        CFnOnce::arr(|e: u8| e + 1)
+            .choose(CFnOnce::arr(|x: i32| -x))
+            .call_once(Err(1)),
+        Err(2)
+    );
+}
+
+#[test]
+fn kleisli_compose_short_circuits() {
+    let parse_positive = positive().compose(parse());
+    assert_eq!(parse_positive.run("12"), Ok(12));
+    assert_eq!(parse_positive.run("-3"), Err("negative: -3".to_string()));
+    assert_eq!(parse_positive.run("x"), Err("not a number: x".to_string()));
+}
+
+#[test]
+fn kleisli_split_fanout_and_choice() {
+    let both = parse().split(positive());
+    assert_eq!(both.run(("4", 2)), Ok((4, 2)));
+    assert_eq!(both.run(("4", -2)), Err("negative: -2".to_string()));
+
+    let twice = positive().fanout(Kleisli::arr(|n: i32| n * 2));
+    assert_eq!(twice.run(3), Ok((3, 6)));
+
+    let either = positive().fanin(Kleisli::arr(|s: &str| s.len() as u32));
+    assert_eq!(either.run(Ok("abc")), Ok(3));
+    assert_eq!(either.run(Err(5)), Ok(5));
+    let kept = positive().choose(parse());
+    assert_eq!(kept.run(Ok("8")), Ok(Ok(8)));
+    assert_eq!(kept.run(Err(-1)), Err("negative: -1".to_string()));
+
+    let halve: Kleisli<OptionKind, i32, i32> = Kleisli::new(|n: i32| (n % 2 == 0).then_some(n / 2));
+    assert_eq!(halve.clone().first::<char>().run((4, 'k')), Some((2, 'k')));
+    assert_eq!(halve.right::<()>().run(Err(())), Some(Err(())));
+}
+
+#[test]
+fn kleisli_vec_pairs_every_result() {
+    let signs: Kleisli<VecKind, i32, i32> = Kleisli::new(|n: i32| vec![n, -n]);
+    assert_eq!(
+        signs.clone().first::<char>().run((2, 'a')),
+        vec![(2, 'a'), (-2, 'a')]
+    );
+    assert_eq!(
+        signs.second::<char>().run(('b', 3)),
+        vec![('b', 3), ('b', -3)]
+    );
+}
+
+#[test]
+fn kleisli_reader_is_strong_and_choice_on_every_run() {
+    let divide: Kleisli<ReaderTKind<i32, OptionKind>, i32, i32> =
+        Kleisli::new(|n: i32| ReaderT::new(move |d: i32| n.checked_div(d)));
+    let paired = divide.clone().first::<char>().run((8, 'k'));
+    assert_eq!((paired.run_reader_t)(2), Some((4, 'k')));
+    assert_eq!((paired.run_reader_t)(4), Some((2, 'k')));
+    assert_eq!((paired.run_reader_t)(0), None);
+    let skipped = divide.clone().left::<&str>().run(Ok("skip"));
+    assert_eq!((skipped.run_reader_t)(0), Some(Ok("skip")));
+    assert_eq!((skipped.run_reader_t)(1), Some(Ok("skip")));
+    let shown = divide.dimap(|s: &str| s.len() as i32, |n: i32| n.to_string());
+    assert_eq!((shown.run("abcdef").run_reader_t)(3), Some("2".to_string()));
+
+    let scale: Kleisli<KleisliKind<IdentityKind, i32>, i32, i32> =
+        Kleisli::new(|n: i32| Kleisli::new(move |k: i32| Identity(n * k)));
+    let paired = scale.clone().second::<&str>().run(("s", 3));
+    assert_eq!(paired.run(2), Identity(("s", 6)));
+    assert_eq!(paired.run(5), Identity(("s", 15)));
+    assert_eq!(
+        scale.right::<char>().run(Err('e')).run(7),
+        Identity(Err('e'))
+    );
+}
+
+#[test]
+fn kleisli_function_effects_are_strong_and_choice() {
+    let scale: Kleisli<CFnKind<i32>, i32, i32> = Kleisli::new(|n: i32| CFn::new(move |k| n * k));
+    let paired = scale.clone().first::<&str>().run((3, "s"));
+    assert_eq!(paired.call(2), (6, "s"));
+    assert_eq!(paired.call(4), (12, "s"));
+    assert_eq!(scale.left::<char>().run(Ok('o')).call(5), Ok('o'));
+
+    let shared: Kleisli<RcFnKind<i32>, i32, i32> = Kleisli::new(|n: i32| RcFn::new(move |k| n + k));
+    assert_eq!(shared.right::<()>().run(Ok(1)).call(2), Ok(3));
+
+    let once: Kleisli<CFnOnceKind<i32>, i32, i32> =
+        Kleisli::new(|n: i32| CFnOnce::new(move |k| n - k));
+    assert_eq!(once.second::<char>().run(('x', 5)).call_once(2), ('x', 3));
+
+    let counted: Kleisli<CFnMutKind<i32>, i32, i32> = Kleisli::new(|n: i32| {
+        let mut calls = 0;
+        CFnMut::new(move |k: i32| {
+            calls += 1;
+            n * k + calls
+        })
+    });
+    let mut paired = counted.first::<char>().run((10, 'c'));
+    assert_eq!(paired.call_mut(1), (11, 'c'));
+    assert_eq!(paired.call_mut(1), (12, 'c'));
+}
+
+mod category_laws {
+    use super::*;
+
+    #[test]
+    fn identity() {
+        let f = || CFn::arr(|x: i32| x * 3 - 1);
+        for x in [-2, 0, 5] {
+            assert_eq!(f().compose(CFn::id()).call(x), f().call(x));
+            assert_eq!(CFn::id().compose(f()).call(x), f().call(x));
+        }
+        let k = || Kleisli::<IdentityKind, i32, i32>::arr(|x| x + 1);
+        assert_eq!(k().compose(Kleisli::id()).run(1), Identity(2));
+        assert_eq!(
+            Kleisli::<IdentityKind, i32, i32>::id().compose(k()).run(1),
+            Identity(2)
+        );
+    }
+
+    #[test]
+    fn associativity() {
+        let f = || positive();
+        let g = || Kleisli::<ResultKind<String>, u32, u32>::arr(|n| n + 1);
+        let h = || Kleisli::<ResultKind<String>, u32, String>::arr(|n| n.to_string());
+        for x in [3, -3] {
+            assert_eq!(
+                h().compose(g()).compose(f()).run(x),
+                h().compose(g().compose(f())).run(x)
+            );
+        }
+    }
+}
+
+mod arrow_laws {
+    use super::*;
+
+    #[test]
+    fn arr_distributes_over_composition() {
+        let f = |x: i32| x + 2;
+        let g = |x: i32| x * 5;
+        let fused = Kleisli::<OptionKind, i32, i32>::arr(move |x| g(f(x)));
+        let stepwise = Kleisli::<OptionKind, i32, i32>::arr(g).compose(Kleisli::arr(f));
+        assert_eq!(fused.run(1), stepwise.run(1));
+    }
+
+    #[test]
+    fn first_of_arr() {
+        let f = |x: i32| x - 4;
+        let lifted = CFn::arr(f).first::<bool>();
+        let direct = CFn::arr(move |(x, d): (i32, bool)| (f(x), d));
+        assert_eq!(lifted.call((10, true)), direct.call((10, true)));
+    }
+}
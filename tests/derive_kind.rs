@@ -0,0 +1,191 @@
+use monadify::ext::{map, pure_as};
+use monadify::function::CFn;
+use monadify::functor::kind::Functor;
+use monadify::kind_based::kind::{HasKind, Kind};
+use monadify::{derive_kind, kind, Applicative, Apply, ApplyExt, FunctorExt};
+
+/// Checks at compile time that `T`'s marker is `K`.
+fn assert_kind<T: HasKind<Kind = K>, K: Kind>(_: &T) {}
+
+#[derive(Debug, PartialEq)]
+pub struct Pair<T>(T, T);
+
+kind!(
+    /// The Kind of `Pair<_>`.
+    pub PairKind for Pair<_>
+);
+
+impl<A, B> Functor<A, B> for PairKind {
+    fn map(input: Pair<A>, mut func: impl FnMut(A) -> B + Clone + 'static) -> Pair<B> {
+        Pair(func(input.0), func(input.1))
+    }
+}
+
+impl<A: 'static, B: 'static> Apply<A, B> for PairKind {
+    fn apply(values: Pair<A>, functions: Pair<CFn<A, B>>) -> Pair<B> {
+        Pair(functions.0.call(values.0), functions.1.call(values.1))
+    }
+}
+
+impl<T: Clone + 'static> Applicative<T> for PairKind {
+    fn pure(value: T) -> Pair<T> {
+        Pair(value.clone(), value)
+    }
+}
+
+#[derive(Debug, PartialEq)]
+struct Keyed<K, V> {
+    key: K,
+    value: V,
+}
+
+// The varying parameter doesn't have to come first.
+kind!(KeyedKind<K> for Keyed<K, _>);
+
+// Fixed parameters can also be concrete types.
+#[derive(Debug, PartialEq)]
+enum Checked<T, E> {
+    Valid(T),
+    Invalid(E),
+}
+
+kind!(CheckedStringKind for Checked<_, String>);
+
+#[test]
+fn kind_defines_the_marker_and_back_mapping() {
+    assert_kind::<_, PairKind>(&Pair(1, 2));
+    let keyed = Keyed {
+        key: "id",
+        value: 7u8,
+    };
+    assert_kind::<_, KeyedKind<&str>>(&keyed);
+    assert_kind::<_, CheckedStringKind>(&Checked::<i32, String>::Valid(1));
+
+    let of: <KeyedKind<&str> as Kind>::Of<bool> = Keyed {
+        key: "flag",
+        value: true,
+    };
+    assert!(of.value);
+    let _marker: CheckedStringKind = Default::default();
+    assert_eq!(
+        Checked::<u8, String>::Invalid("bad".into()),
+        Checked::Invalid("bad".to_string())
+    );
+}
+
+#[test]
+fn kind_instances_work_with_inferred_helpers() {
+    assert_eq!(Pair(1, 2).fmap(|x| x * 10), Pair(10, 20));
+    assert_eq!(map(Pair("a", "bc"), str::len), Pair(1, 2));
+    let adders = Pair(CFn::new(|x: i32| x + 1), CFn::new(|x: i32| x - 1));
+    assert_eq!(Pair(5, 5).ap(adders), Pair(6, 4));
+    let both: Pair<char> = pure_as('z');
+    assert_eq!(both, Pair('z', 'z'));
+}
+
+derive_kind! {
+    /// The Kind of `Boxed<_>`.
+    pub kind BoxedKind over T: Functor;
+
+    /// A newtype around a single value.
+    #[derive(Debug, Clone, PartialEq)]
+    pub struct Boxed<T>(pub T);
+}
+
+derive_kind! {
+    kind EitherKind<L> over R: Functor;
+
+    #[derive(Debug, Clone, PartialEq)]
+    enum Either<L, R> {
+        Left(L),
+        /// The value that gets mapped.
+        Right(R),
+        Neither,
+    }
+}
+
+derive_kind! {
+    pub(crate) kind TimedKind over T;
+
+    #[derive(Debug, PartialEq)]
+    pub(crate) struct Timed<T> {
+        pub millis: u64,
+        pub value: T,
+    }
+}
+
+impl<A, B> Functor<A, B> for TimedKind {
+    fn map(input: Timed<A>, mut func: impl FnMut(A) -> B + Clone + 'static) -> Timed<B> {
+        Timed {
+            millis: input.millis,
+            value: func(input.value),
+        }
+    }
+}
+
+#[test]
+fn derive_kind_emits_the_type_and_marker() {
+    let boxed = Boxed(3);
+    assert_kind::<_, BoxedKind>(&boxed);
+    assert_eq!(boxed.clone().0, 3);
+
+    let right: Either<String, i32> = Either::Right(1);
+    assert_kind::<_, EitherKind<String>>(&right);
+
+    let timed = Timed {
+        millis: 5,
+        value: "done",
+    };
+    assert_kind::<_, TimedKind>(&timed);
+    assert_eq!(
+        timed.fmap(str::len),
+        Timed {
+            millis: 5,
+            value: 4
+        }
+    );
+}
+
+#[test]
+fn derived_functor_maps_newtypes() {
+    assert_eq!(BoxedKind::map(Boxed(20), |x: i32| x + 1), Boxed(21));
+    assert_eq!(
+        Boxed("abc").fmap(str::to_uppercase),
+        Boxed("ABC".to_string())
+    );
+}
+
+#[test]
+fn derived_functor_maps_only_the_varying_parameter() {
+    let right: Either<String, i32> = Either::Right(4);
+    let left: Either<String, i32> = Either::Left("kept".to_string());
+    let neither: Either<String, i32> = Either::Neither;
+    assert_eq!(right.fmap(|x| x * x), Either::Right(16));
+    assert_eq!(left.fmap(|x| x * x), Either::Left("kept".to_string()));
+    assert_eq!(neither.fmap(|x| x * x), Either::Neither);
+}
+
+mod derived_functor_laws {
+    use super::*;
+
+    fn samples() -> Vec<Either<char, i32>> {
+        vec![Either::Right(3), Either::Left('x'), Either::Neither]
+    }
+
+    #[test]
+    fn identity() {
+        for fa in samples() {
+            assert_eq!(fa.clone().fmap(|x| x), fa);
+        }
+        assert_eq!(Boxed(1).fmap(|x: i32| x), Boxed(1));
+    }
+
+    #[test]
+    fn composition() {
+        let f = |x: i32| x + 1;
+        let g = |x: i32| x.to_string();
+        for fa in samples() {
+            assert_eq!(fa.clone().fmap(f).fmap(g), fa.fmap(move |x| g(f(x))));
+        }
+    }
+}
@@ -0,0 +1,118 @@
+use monadify::derive_lenses;
+use monadify::function::CFn;
+use monadify::profunctor::{over, set, to_list_of, traversed, view, Check, Lens, Traversal};
+
+derive_lenses! {
+    /// An address, with lenses for each field.
+    #[derive(Clone, Debug, PartialEq)]
+    pub struct Address {
+        /// The street name.
+        pub street: String,
+        pub city: String,
+    }
+}
+
+derive_lenses! {
+    #[derive(Clone, Debug, PartialEq)]
+    struct Employee {
+        name: String,
+        address: Address,
+        skills: Vec<String>,
+        salary: u32,
+    }
+}
+
+fn bob() -> Employee {
+    Employee {
+        name: "Bob".to_string(),
+        address: Address {
+            street: "Elm St".to_string(),
+            city: "Oakland".to_string(),
+        },
+        skills: vec!["rust".to_string(), "sql".to_string()],
+        salary: 100,
+    }
+}
+
+#[test]
+fn generated_lenses_view_each_field() {
+    assert_eq!(view(Employee::name().0, bob()), "Bob");
+    assert_eq!(view(Employee::salary().0, bob()), 100);
+    assert_eq!(view(Employee::address().0, bob()).city, "Oakland");
+    assert_eq!(view(Address::street().0, bob().address), "Elm St");
+}
+
+#[test]
+fn generated_lenses_set_and_over_one_field() {
+    let raised = over(Employee::salary().0, |s: u32| s + 10, bob());
+    assert_eq!(raised.salary, 110);
+    assert_eq!(raised.name, "Bob");
+
+    let renamed = set(Employee::name().0, "Robert".to_string(), bob());
+    assert_eq!(
+        renamed,
+        Employee {
+            name: "Robert".to_string(),
+            ..bob()
+        }
+    );
+}
+
+#[test]
+fn generated_lenses_compose() {
+    let city: Lens<CFn<Employee, Employee>, CFn<String, String>, _, _, _, _> =
+        Employee::address().then(Address::city());
+    let moved = over(city.0, |c: String| c + " CA", bob());
+    assert_eq!(moved.address.city, "Oakland CA");
+    assert_eq!(
+        view(Employee::address().then(Address::city()).0, moved),
+        "Oakland CA"
+    );
+}
+
+#[test]
+fn generated_lenses_compose_with_traversals() {
+    let skills: Traversal<CFn<Employee, Employee>, CFn<String, String>, _, _, _, _> =
+        Traversal::from(Employee::skills()).then(traversed());
+    let shouty = over(skills.0, |s: String| s.to_uppercase(), bob());
+    assert_eq!(shouty.skills, vec!["RUST", "SQL"]);
+
+    let all: Vec<String> = to_list_of(
+        Traversal::from(Employee::skills())
+            .then(traversed::<_, _, String, String>())
+            .0,
+        bob(),
+    );
+    assert_eq!(all, vec!["rust", "sql"]);
+}
+
+#[test]
+fn check_lenses_are_generated() {
+    let c = Check { key: 1, other: 2 };
+    assert_eq!(view(Check::key().0, c), 1);
+    assert_eq!(set(Check::other().0, 5, c), Check { key: 1, other: 5 });
+}
+
+// --- Lens laws for the generated lenses ---
+
+mod lens_laws {
+    use super::*;
+
+    #[test]
+    fn set_view_law() {
+        let s = set(Employee::name().0, "Eve".to_string(), bob());
+        assert_eq!(view(Employee::name().0, s), "Eve");
+    }
+
+    #[test]
+    fn view_set_law() {
+        let name = view(Employee::name().0, bob());
+        assert_eq!(set(Employee::name().0, name, bob()), bob());
+    }
+
+    #[test]
+    fn set_set_law() {
+        let twice = set(Employee::salary().0, 2, set(Employee::salary().0, 1, bob()));
+        assert_eq!(twice, set(Employee::salary().0, 2, bob()));
+    }
+}
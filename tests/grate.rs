@@ -0,0 +1,181 @@
+use monadify::function::CFn;
+use monadify::profunctor::{
+    grate, iso, over, review, zip_with_of, Forget, Grate, Iso, Projection, Tagged, Zipping,
+};
+use monadify::{Closed, Cochoice, Costrong, Profunctor};
+
+type Triple<A> = [A; 3];
+
+// Runs `closed` on the inner profunctor at `Projection<S, A>`, as `grate` does.
+type Closing<PI, S, A, B> =
+    <PI as Profunctor<A, B>>::Pro<CFn<Projection<S, A>, A>, CFn<Projection<S, A>, B>>;
+
+// Focuses on both halves of a pair.
+fn both<PO, PI, A: Clone + 'static, B: 'static>() -> Grate<PO, PI, (A, A), (B, B), A, B>
+where
+    PO: Closed<(A, A), (B, B)>,
+    PI: Closed<A, B>,
+    Closing<PI, (A, A), A, B>: Profunctor<
+        CFn<Projection<(A, A), A>, A>,
+        CFn<Projection<(A, A), A>, B>,
+        Pro<(A, A), (B, B)> = PO,
+    >,
+{
+    grate(|f| (f(CFn::new(|p: (A, A)| p.0)), f(CFn::new(|p: (A, A)| p.1))))
+}
+
+// Focuses on every element of a three-element array.
+fn each3<PO, PI, A: Clone + 'static>() -> Grate<PO, PI, Triple<A>, Triple<A>, A, A>
+where
+    PO: Closed<Triple<A>, Triple<A>>,
+    PI: Closed<A, A>,
+    Closing<PI, Triple<A>, A, A>: Profunctor<
+        CFn<Projection<Triple<A>, A>, A>,
+        CFn<Projection<Triple<A>, A>, A>,
+        Pro<Triple<A>, Triple<A>> = PO,
+    >,
+{
+    grate(|f| {
+        [
+            f(CFn::new(|t: Triple<A>| t[0].clone())),
+            f(CFn::new(|t: Triple<A>| t[1].clone())),
+            f(CFn::new(|t: Triple<A>| t[2].clone())),
+        ]
+    })
+}
+
+#[test]
+fn zip_with_of_combines_part_by_part() {
+    assert_eq!(
+        zip_with_of(both().0, |x: i32, y| x * y, (2, 3), (5, 7)),
+        (10, 21)
+    );
+    assert_eq!(
+        zip_with_of(
+            each3().0,
+            |a: char, b| a.max(b),
+            ['a', 'z', 'm'],
+            ['q', 'b', 'm']
+        ),
+        ['q', 'z', 'm']
+    );
+}
+
+#[test]
+fn zip_with_of_can_change_the_part_type() {
+    let labels: (String, String) = zip_with_of(
+        both().0,
+        |k: &str, v: &str| format!("{k}={v}"),
+        ("a", "b"),
+        ("1", "2"),
+    );
+    assert_eq!(labels, ("a=1".to_string(), "b=2".to_string()));
+}
+
+#[test]
+fn grate_is_a_setter() {
+    assert_eq!(over(both().0, |x: i32| x + 1, (1, 2)), (2, 3));
+    assert_eq!(
+        over(
+            each3().0,
+            |s: String| s.to_uppercase(),
+            ["a".into(), "b".into(), "c".into()]
+        ),
+        ["A", "B", "C"]
+    );
+}
+
+#[test]
+fn grate_can_be_reviewed() {
+    // Every part of the rebuilt whole is the reviewed value.
+    assert_eq!(review(each3().0, 7), [7, 7, 7]);
+    assert_eq!(review(both::<_, _, (), _>().0, 'x'), ('x', 'x'));
+}
+
+#[test]
+fn grates_compose() {
+    let nested = both().then(both());
+    assert_eq!(
+        zip_with_of(
+            nested.0,
+            |x: i32, y| x - y,
+            ((10, 20), (30, 40)),
+            ((1, 2), (3, 4))
+        ),
+        ((9, 18), (27, 36))
+    );
+}
+
+#[test]
+fn iso_is_a_grate() {
+    type Celsius = Grate<Zipping<f64, f64>, Zipping<f64, f64>, f64, f64, f64, f64>;
+    let fahrenheit: Iso<_, _, f64, f64, f64, f64> = iso(
+        CFn::new(|c: f64| c * 9.0 / 5.0 + 32.0),
+        CFn::new(|f: f64| (f - 32.0) * 5.0 / 9.0),
+    );
+    let as_grate: Celsius = fahrenheit.into();
+    // The average of two temperatures, taken in Fahrenheit.
+    assert_eq!(
+        zip_with_of(as_grate.0, |x, y| (x + y) / 2.0, 0.0, 100.0),
+        50.0
+    );
+}
+
+#[test]
+fn closed_post_composes() {
+    let double = CFn::new(|x: i32| x * 2);
+    let lifted = double.closed::<&str>();
+    let len_doubled = lifted.call(CFn::new(|s: &str| s.len() as i32));
+    assert_eq!(len_doubled.call("abc"), 6);
+
+    let constant = Tagged::<i32, char>::new('k').closed::<u8>();
+    assert_eq!(constant.value.call(3), 'k');
+}
+
+#[test]
+fn costrong_tagged_drops_the_passed_component() {
+    let pair: Tagged<(i32, bool), (char, bool)> = Tagged::new(('a', true));
+    assert_eq!(Tagged::<i32, char>::unfirst(pair).value, 'a');
+    let pair: Tagged<(bool, i32), (bool, char)> = Tagged::new((false, 'b'));
+    assert_eq!(Tagged::<i32, char>::unsecond(pair).value, 'b');
+}
+
+#[test]
+fn cochoice_restricts_to_one_case() {
+    // Collatz-style: keep stepping while the value is `Ok` (still running).
+    let step = CFn::new(|r: Result<u32, u32>| match r {
+        Err(n) | Ok(n) if n == 1 => Err(1),
+        Err(n) | Ok(n) if n % 2 == 0 => Ok(n / 2),
+        Err(n) | Ok(n) => Ok(3 * n + 1),
+    });
+    let reach_one = CFn::<u32, u32>::unleft(step);
+    assert_eq!(reach_one.call(6), 1);
+
+    let describe = CFn::new(|r: Result<i32, String>| r.map(|x| x.to_string()));
+    assert_eq!(CFn::<i32, String>::unright(describe).call(42), "42");
+
+    let fold: Forget<Vec<i32>, Result<(), i32>, Result<(), i32>> =
+        Forget::new(|r: Result<(), i32>| r.err().into_iter().collect());
+    let unwrapped = Forget::<Vec<i32>, i32, i32>::unleft(fold);
+    assert_eq!(unwrapped.inner.call(5), vec![5]);
+}
+
+mod closed_laws {
+    use super::*;
+
+    #[test]
+    fn closed_commutes_with_dimap() {
+        // closed(dimap(f, g, p)) == dimap(f . _, g . _, closed(p)), tested pointwise.
+        let p = || CFn::new(|x: i32| x + 1);
+        let f = |s: &str| s.len() as i32;
+        let g = |x: i32| x * 10;
+        let left = p().dimap(f, g).closed::<char>();
+        let right = p().closed::<char>().dimap(
+            move |h: CFn<char, &str>| CFn::new(move |c| f(h.call(c))),
+            move |h: CFn<char, i32>| CFn::new(move |c| g(h.call(c))),
+        );
+        let h = || CFn::new(|c: char| if c == 'a' { "abc" } else { "" });
+        assert_eq!(left.call(h()).call('a'), right.call(h()).call('a'));
+        assert_eq!(left.call(h()).call('b'), right.call(h()).call('b'));
+    }
+}
@@ -0,0 +1,244 @@
+use monadify::function::{CFn, RcFn};
+use monadify::identity::{Identity, IdentityKind};
+use monadify::indexed::{
+    at, iover, ito_list_of, itraversed, itraversed_btree_map, itraversed_hash_map, ix, Indexed,
+};
+use monadify::kind_based::kind::OptionKind;
+use monadify::profunctor::{over, preview, set, to_list_of, view, Star, Strong};
+use monadify::Profunctor;
+use std::any::Any;
+use std::collections::{BTreeMap, HashMap};
+use std::marker::PhantomData;
+
+type Ages = BTreeMap<&'static str, i32>;
+
+#[test]
+fn indexed_dimap_keeps_the_index() {
+    let labelled: Indexed<usize, CFn<(usize, i32), String>> =
+        Indexed::new(CFn::new(|(i, x): (usize, i32)| format!("{i}:{x}")));
+    let mapped = labelled.dimap(|s: &str| s.len() as i32, |s: String| s + "!");
+    assert_eq!(mapped.0.call((3, "abcd")), "3:4!");
+}
+
+#[test]
+fn iover_sees_vec_positions() {
+    assert_eq!(
+        iover(
+            itraversed(),
+            |i: usize, x: i32| x + i as i32,
+            vec![10, 10, 10]
+        ),
+        vec![10, 11, 12]
+    );
+    let labels: Vec<String> = iover(
+        itraversed(),
+        |i: usize, c: char| format!("{i}{c}"),
+        vec!['a', 'b'],
+    );
+    assert_eq!(labels, vec!["0a", "1b"]);
+    assert_eq!(
+        iover(itraversed(), |_: usize, x: i32| x, Vec::<i32>::new()),
+        Vec::<i32>::new()
+    );
+}
+
+#[test]
+fn ito_list_of_pairs_elements_with_indices() {
+    assert_eq!(
+        ito_list_of(itraversed::<_, _, &str, &str>(), vec!["x", "y", "z"]),
+        vec![(0, "x"), (1, "y"), (2, "z")]
+    );
+}
+
+#[test]
+fn itraversed_btree_map_is_indexed_by_key_in_order() {
+    let ages: Ages = BTreeMap::from([("bob", 25), ("ann", 30)]);
+    assert_eq!(
+        ito_list_of(itraversed_btree_map::<_, _, _, i32, i32>(), ages.clone()),
+        vec![("ann", 30), ("bob", 25)]
+    );
+    let older = iover(
+        itraversed_btree_map(),
+        |name: &str, age: i32| if name == "ann" { age + 1 } else { age },
+        ages,
+    );
+    assert_eq!(older, BTreeMap::from([("ann", 31), ("bob", 25)]));
+}
+
+#[test]
+fn itraversed_hash_map_is_indexed_by_key() {
+    let stock = HashMap::from([("apple".to_string(), 3), ("pear".to_string(), 0)]);
+    let mut listed = ito_list_of(itraversed_hash_map::<_, _, _, i32, i32>(), stock.clone());
+    listed.sort();
+    assert_eq!(
+        listed,
+        vec![("apple".to_string(), 3), ("pear".to_string(), 0)]
+    );
+
+    let labelled: HashMap<String, String> = iover(
+        itraversed_hash_map(),
+        |name: String, n: i32| format!("{n} {name}"),
+        stock,
+    );
+    assert_eq!(labelled["apple"], "3 apple");
+    assert_eq!(labelled["pear"], "0 pear");
+}
+
+#[test]
+fn itraversed_runs_with_effects() {
+    type Positions = Indexed<usize, Star<OptionKind, (usize, i32), i32>>;
+    let traversal = itraversed::<Star<OptionKind, Vec<i32>, Vec<i32>>, _, i32, i32>();
+    let checked = |v: Vec<i32>| {
+        let at_most_index: Positions =
+            Indexed::new(Star::new(
+                |(i, x): (usize, i32)| {
+                    if x as usize <= i {
+                        Some(x)
+                    } else {
+                        None
+                    }
+                },
+            ));
+        (traversal.optic)(at_most_index).run(v)
+    };
+    assert_eq!(checked(vec![0, 1, 1]), Some(vec![0, 1, 1]));
+    assert_eq!(checked(vec![0, 2]), None);
+
+    let identity = itraversed::<Star<IdentityKind, Vec<i32>, Vec<i32>>, _, i32, i32>();
+    let doubled: Indexed<usize, Star<IdentityKind, (usize, i32), i32>> =
+        Indexed::new(Star::new(|(_, x): (usize, i32)| Identity(x * 2)));
+    assert_eq!(
+        (identity.optic)(doubled).run(vec![1, 2]),
+        Identity(vec![2, 4])
+    );
+}
+
+#[test]
+fn at_views_sets_and_removes_entries() {
+    let ages: Ages = BTreeMap::from([("ann", 30)]);
+    assert_eq!(view(at("ann").0, ages.clone()), Some(30));
+    assert_eq!(view(at("bob").0, ages.clone()), None);
+
+    let ages = set(at("bob").0, Some(25), ages);
+    assert_eq!(ages, BTreeMap::from([("ann", 30), ("bob", 25)]));
+    let ages = over(at("ann").0, |age: Option<i32>| age.map(|a| a + 1), ages);
+    assert_eq!(ages["ann"], 31);
+    let ages = set(at("ann").0, None, ages);
+    assert_eq!(ages, BTreeMap::from([("bob", 25)]));
+
+    let hashed = HashMap::from([(1, 'a')]);
+    assert_eq!(view(at(1).0, hashed.clone()), Some('a'));
+    assert!(set(at(1).0, None, hashed).is_empty());
+}
+
+/// Like `Forget`, but `first` and `second` keep the other component of the pair, so
+/// running a lens with it hands back the lens's setter for the whole.
+struct Context<A, B>(CFn<A, Box<dyn Any>>, PhantomData<B>);
+
+impl<A: 'static, B> Profunctor<A, B> for Context<A, B> {
+    type Pro<X, Y> = Context<X, Y>;
+
+    fn dimap<X, Y, X2A, B2Y>(self, x2a: X2A, _: B2Y) -> Context<X, Y>
+    where
+        X2A: Fn(X) -> A + 'static,
+        B2Y: Fn(B) -> Y + 'static,
+        X: 'static,
+        A: 'static,
+        B: 'static,
+        Y: 'static,
+    {
+        Context(CFn::new(x2a) >> self.0, PhantomData)
+    }
+}
+
+impl<A: 'static, B> Strong<A, B> for Context<A, B> {
+    fn first<C: 'static>(self) -> Context<(A, C), (B, C)> {
+        Context(
+            CFn::new(|(_, c): (A, C)| Box::new(c) as Box<dyn Any>),
+            PhantomData,
+        )
+    }
+
+    fn second<C: 'static>(self) -> Context<(C, A), (C, B)> {
+        Context(
+            CFn::new(|(c, _): (C, A)| Box::new(c) as Box<dyn Any>),
+            PhantomData,
+        )
+    }
+}
+
+#[test]
+fn at_setter_can_be_applied_twice_to_the_same_map() {
+    let ages: Ages = BTreeMap::from([("ann", 30)]);
+    let grab = at("bob").0.optic.as_ref()(Context(
+        CFn::new(|_| Box::new(()) as Box<dyn Any>),
+        PhantomData,
+    ));
+    let setter = grab
+        .0
+        .call(ages)
+        .downcast::<RcFn<Option<i32>, Ages>>()
+        .unwrap();
+
+    assert_eq!(
+        setter.call(Some(25)),
+        BTreeMap::from([("ann", 30), ("bob", 25)])
+    );
+    assert_eq!(setter.call(None), BTreeMap::from([("ann", 30)]));
+}
+
+#[test]
+fn ix_focuses_on_an_element_when_in_bounds() {
+    let v = vec![1, 2, 3];
+    assert_eq!(preview(ix(1).0, v.clone()), Some(2));
+    assert_eq!(preview(ix(3).0, v.clone()), None);
+    assert_eq!(to_list_of(ix(0).0, v.clone()), vec![1]);
+
+    assert_eq!(over(ix(2).0, |x: i32| x * 10, v.clone()), vec![1, 2, 30]);
+    assert_eq!(set(ix(0).0, 9, v.clone()), vec![9, 2, 3]);
+    assert_eq!(set(ix(7).0, 9, v.clone()), v);
+}
+
+mod ix_laws {
+    use super::*;
+
+    #[test]
+    fn set_then_preview() {
+        // When the index exists, previewing after setting yields what was set.
+        let v = vec!['a', 'b'];
+        assert_eq!(preview(ix(1).0, set(ix(1).0, 'z', v)), Some('z'));
+    }
+
+    #[test]
+    fn set_what_you_preview() {
+        // Setting the previewed element back changes nothing.
+        let v = vec![4, 5, 6];
+        let current = preview(ix(2).0, v.clone()).unwrap();
+        assert_eq!(set(ix(2).0, current, v.clone()), v);
+    }
+}
+
+mod at_laws {
+    use super::*;
+
+    #[test]
+    fn get_set() {
+        // Setting what you viewed changes nothing, whether or not the key is present.
+        let ages: Ages = BTreeMap::from([("ann", 30)]);
+        for key in ["ann", "bob"] {
+            let current = view(at(key).0, ages.clone());
+            assert_eq!(set(at(key).0, current, ages.clone()), ages);
+        }
+    }
+
+    #[test]
+    fn set_get() {
+        // Viewing after setting yields what was set.
+        let ages: Ages = BTreeMap::new();
+        assert_eq!(
+            view(at("ann").0, set(at("ann").0, Some(1), ages.clone())),
+            Some(1)
+        );
+        assert_eq!(view(at("ann").0, set(at("ann").0, None, ages)), None);
+    }
+}
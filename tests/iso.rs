@@ -0,0 +1,133 @@
+use monadify::function::CFn;
+use monadify::profunctor::{from, iso, preview, re, review, view, with_iso, Iso, Lens, Prism, _1};
+use monadify::Profunctor;
+
+type PairFn = CFn<(i32, i32), (i32, i32)>;
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+struct Meters(f64);
+
+#[derive(Clone, Debug, PartialEq)]
+struct Point {
+    x: i32,
+    y: i32,
+}
+
+fn meters<PO, PI>() -> Iso<PO, PI, Meters, Meters, f64, f64>
+where
+    PO: Profunctor<Meters, Meters>,
+    PI: Profunctor<f64, f64, Pro<Meters, Meters> = PO>,
+{
+    iso(CFn::new(|m: Meters| m.0), CFn::new(Meters))
+}
+
+fn point<PO, PI>() -> Iso<PO, PI, Point, Point, (i32, i32), (i32, i32)>
+where
+    PO: Profunctor<Point, Point>,
+    PI: Profunctor<(i32, i32), (i32, i32), Pro<Point, Point> = PO>,
+{
+    iso(
+        CFn::new(|p: Point| (p.x, p.y)),
+        CFn::new(|(x, y): (i32, i32)| Point { x, y }),
+    )
+}
+
+fn unit<PO, PI>() -> Iso<PO, PI, (), (), ((), ()), ((), ())>
+where
+    PO: Profunctor<(), ()>,
+    PI: Profunctor<((), ()), ((), ()), Pro<(), ()> = PO>,
+{
+    iso(CFn::new(|_: ()| ((), ())), CFn::new(|_: ((), ())| ()))
+}
+
+#[test]
+fn view_and_review_through_iso() {
+    assert_eq!(view(meters().into(), Meters(2.5)), 2.5);
+    assert_eq!(review(meters().into(), 4.0), Meters(4.0));
+    assert_eq!(view(point().into(), Point { x: 1, y: 2 }), (1, 2));
+    assert_eq!(review(point().into(), (3, 4)), Point { x: 3, y: 4 });
+}
+
+#[test]
+fn unit_iso() {
+    assert_eq!(view(unit().into(), ()), ((), ()));
+    let () = review(unit().into(), ((), ()));
+}
+
+#[test]
+fn iso_modifies_with_cfn() {
+    let double: Iso<CFn<Meters, Meters>, CFn<f64, f64>, _, _, _, _> = meters();
+    let f = (double.0.optic)(CFn::new(|x: f64| x * 2.0));
+    assert_eq!(f.call(Meters(1.5)), Meters(3.0));
+}
+
+#[test]
+fn re_reverses_the_iso() {
+    assert_eq!(view(re(meters()).into(), 7.0), Meters(7.0));
+    assert_eq!(review(re(point()).into(), Point { x: 5, y: 6 }), (5, 6));
+    assert_eq!(view(from(point()).into(), (8, 9)), Point { x: 8, y: 9 });
+}
+
+#[test]
+fn re_twice_is_the_original() {
+    let (to, back) = with_iso(re(re(point())));
+    assert_eq!(to.call(Point { x: 1, y: 2 }), (1, 2));
+    assert_eq!(back.call((1, 2)), Point { x: 1, y: 2 });
+}
+
+#[test]
+fn iso_converts_into_lens() {
+    let lens: Lens<_, _, Meters, Meters, f64, f64> = meters().into();
+    assert_eq!(view(lens.into(), Meters(1.0)), 1.0);
+
+    // Composing the converted lens manually with `_1` reaches inside the tuple.
+    let set_x: Lens<CFn<Point, Point>, PairFn, _, _, _, _> = point().into();
+    let first: Lens<PairFn, CFn<i32, i32>, _, _, _, _> = _1();
+    let f = (set_x.0.optic)((first.0.optic)(CFn::new(|_: i32| 0)));
+    assert_eq!(f.call(Point { x: 1, y: 2 }), Point { x: 0, y: 2 });
+}
+
+#[test]
+fn iso_converts_into_prism() {
+    let prism: Prism<_, _, Meters, Meters, f64, f64> = meters().into();
+    assert_eq!(preview(prism.into(), Meters(3.0)), Some(3.0));
+    let prism: Prism<_, _, Meters, Meters, f64, f64> = meters().into();
+    assert_eq!(review(prism.into(), 3.0), Meters(3.0));
+}
+
+// --- Iso laws ---
+
+mod iso_laws {
+    use super::*;
+
+    // from(to(s)) == s
+    #[test]
+    fn to_then_from_is_identity() {
+        let (to, back) = with_iso(point());
+        let p = Point { x: -1, y: 10 };
+        assert_eq!(back.call(to.call(p.clone())), p);
+
+        let (to, back) = with_iso(meters());
+        assert_eq!(back.call(to.call(Meters(0.5))), Meters(0.5));
+    }
+
+    // to(from(a)) == a
+    #[test]
+    fn from_then_to_is_identity() {
+        let (to, back) = with_iso(point());
+        assert_eq!(to.call(back.call((4, 2))), (4, 2));
+
+        let (to, back) = with_iso(meters());
+        assert_eq!(to.call(back.call(9.0)), 9.0);
+    }
+
+    // Laws also hold for the reversed iso.
+    #[test]
+    fn reversed_round_trips() {
+        let (to, back) = with_iso(re(point()));
+        assert_eq!(to.call((1, 1)), Point { x: 1, y: 1 });
+        assert_eq!(back.call(to.call((2, 3))), (2, 3));
+        let p = Point { x: 2, y: 3 };
+        assert_eq!(to.call(back.call(p.clone())), p);
+    }
+}
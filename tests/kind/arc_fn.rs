@@ -0,0 +1,177 @@
+use monadify::function::{ArcFn, CFn, CFnOnce, RcFn, SendFnOnce};
+use monadify::kind_based::kind::{ArcFnKind, SendFnOnceKind};
+use monadify::send::{SendApplicative, SendApply, SendBind, SendFunctor, SendMonad};
+use std::thread;
+
+type Env = i32;
+
+fn assert_send_sync<T: Send + Sync>(_: &T) {}
+
+// `ArcFn`s can't be compared directly, so compare them on a few inputs.
+fn assert_same<B: PartialEq + std::fmt::Debug>(f: &ArcFn<Env, B>, g: &ArcFn<Env, B>) {
+    for env in [-3, 0, 7] {
+        assert_eq!(f.call(env), g.call(env));
+    }
+}
+
+#[test]
+fn arc_fn_is_shared_between_threads() {
+    let square = ArcFn::new(|x: i32| x * x);
+    assert_send_sync(&square);
+    let results: Vec<i32> = thread::scope(|s| {
+        let handles: Vec<_> = (1..=3)
+            .map(|i| {
+                let f = square.clone();
+                s.spawn(move || f.call(i))
+            })
+            .collect();
+        handles.into_iter().map(|h| h.join().unwrap()).collect()
+    });
+    assert_eq!(results, vec![1, 4, 9]);
+}
+
+#[test]
+fn arc_fn_composition_and_conversions() {
+    let add_one = ArcFn::new(|x: i32| x + 1);
+    let show = ArcFn::new(|x: i32| x.to_string());
+    assert_eq!((add_one.clone() >> show.clone()).call(4), "5");
+    assert_eq!((show << add_one.clone()).call(9), "10");
+
+    let as_cfn: CFn<i32, i32> = add_one.clone().into();
+    assert_eq!(as_cfn.call(1), 2);
+    let as_rc: RcFn<i32, i32> = add_one.into();
+    assert_eq!(as_rc.call(2), 3);
+}
+
+#[test]
+fn send_fn_once_runs_on_another_thread() {
+    let name = String::from("job");
+    let label = SendFnOnce::new(move |n: usize| format!("{name}#{n}"));
+    let shout = SendFnOnce::new(|s: String| s.to_uppercase());
+    let pipeline = label >> shout;
+    assert_eq!(
+        thread::spawn(move || pipeline.call_once(3)).join().unwrap(),
+        "JOB#3"
+    );
+
+    let len = SendFnOnce::new(|s: String| s.len());
+    let once: CFnOnce<usize, usize> = (len << SendFnOnce::new(|n: usize| "x".repeat(n))).into();
+    assert_eq!(once.call_once(4), 4);
+}
+
+#[test]
+fn send_fn_once_kind_threads_the_environment() {
+    let read = SendFnOnce::new(|env: Env| env * 2);
+    let pipeline = SendFnOnceKind::<Env>::bind(read, |x: i32| {
+        SendFnOnce::new(move |env: Env| format!("{x}+{env}"))
+    });
+    let pipeline = SendFnOnceKind::<Env>::map(pipeline, |s: String| s + "!");
+    assert_eq!(
+        thread::spawn(move || pipeline.call_once(5)).join().unwrap(),
+        "10+5!"
+    );
+
+    let applied = SendFnOnceKind::<Env>::apply(
+        SendFnOnceKind::<Env>::pure(4),
+        SendFnOnce::new(|env: Env| ArcFn::new(move |x: i32| x * env)),
+    );
+    assert_eq!(applied.call_once(3), 12);
+
+    let nested = SendFnOnce::new(|outer: Env| SendFnOnce::new(move |inner: Env| outer - inner));
+    assert_eq!(SendFnOnceKind::<Env>::join(nested).call_once(8), 0);
+}
+
+mod arc_fn_kind_functor_laws {
+    use super::*;
+
+    #[test]
+    fn identity() {
+        let fa = ArcFn::new(|env: Env| env + 1);
+        let mapped = ArcFnKind::<Env>::map(fa.clone(), |x: i32| x);
+        assert_same(&mapped, &fa);
+    }
+
+    #[test]
+    fn composition() {
+        let fa = ArcFn::new(|env: Env| env * 3);
+        let f = |x: i32| x - 2;
+        let g = |x: i32| x.to_string();
+        let fused = ArcFnKind::<Env>::map(fa.clone(), move |x| g(f(x)));
+        let stepwise = ArcFnKind::<Env>::map(ArcFnKind::<Env>::map(fa, f), g);
+        assert_same(&fused, &stepwise);
+    }
+}
+
+mod arc_fn_kind_applicative_laws {
+    use super::*;
+
+    #[test]
+    fn identity() {
+        let v = ArcFn::new(|env: Env| env - 4);
+        let id = ArcFnKind::<Env>::pure(ArcFn::new(|x: i32| x));
+        assert_same(&ArcFnKind::<Env>::apply(v.clone(), id), &v);
+    }
+
+    #[test]
+    fn homomorphism() {
+        let f = |x: i32| x * 7;
+        let applied = ArcFnKind::<Env>::apply(
+            ArcFnKind::<Env>::pure(6),
+            ArcFnKind::<Env>::pure(ArcFn::new(f)),
+        );
+        assert_same(&applied, &ArcFnKind::<Env>::pure(f(6)));
+    }
+
+    #[test]
+    fn interchange() {
+        // u <*> pure(y) == pure(|f| f(y)) <*> u, with u an environment-dependent function.
+        let y = 5;
+        let u = || ArcFn::new(|env: Env| ArcFn::new(move |x: i32| x + env));
+        let lhs = ArcFnKind::<Env>::apply(ArcFnKind::<Env>::pure(y), u());
+        let rhs = ArcFnKind::<Env>::apply(
+            u(),
+            ArcFnKind::<Env>::pure(ArcFn::new(move |f: ArcFn<i32, i32>| f.call(y))),
+        );
+        assert_same(&lhs, &rhs);
+    }
+}
+
+mod arc_fn_kind_monad_laws {
+    use super::*;
+
+    fn f(x: i32) -> ArcFn<Env, i32> {
+        ArcFn::new(move |env: Env| x * env)
+    }
+
+    fn g(x: i32) -> ArcFn<Env, String> {
+        ArcFn::new(move |env: Env| format!("{x}@{env}"))
+    }
+
+    #[test]
+    fn left_identity() {
+        let bound = ArcFnKind::<Env>::bind(ArcFnKind::<Env>::pure(4), f);
+        assert_same(&bound, &f(4));
+    }
+
+    #[test]
+    fn right_identity() {
+        let m = ArcFn::new(|env: Env| env + 100);
+        let bound = ArcFnKind::<Env>::bind(m.clone(), ArcFnKind::<Env>::pure);
+        assert_same(&bound, &m);
+    }
+
+    #[test]
+    fn associativity() {
+        let m = || ArcFn::new(|env: Env| env - 1);
+        let lhs = ArcFnKind::<Env>::bind(ArcFnKind::<Env>::bind(m(), f), g);
+        let rhs = ArcFnKind::<Env>::bind(m(), |x| ArcFnKind::<Env>::bind(f(x), g));
+        assert_same(&lhs, &rhs);
+    }
+
+    #[test]
+    fn join_passes_the_environment_twice() {
+        let nested = ArcFn::new(|outer: Env| ArcFn::new(move |inner: Env| (outer, inner)));
+        let joined = ArcFnKind::<Env>::join(nested);
+        assert_eq!(joined.call(3), (3, 3));
+    }
+}
@@ -0,0 +1,120 @@
+use monadify::function::{CFn, CFnMut};
+use monadify::functor::kind::Functor;
+use monadify::kind_based::kind::CFnMutKind;
+use monadify::monad::kind::Bind;
+use monadify::{Apply, Profunctor, Strong};
+
+// A function returning how many times it has been called so far, plus its argument.
+fn counter() -> CFnMut<i32, i32> {
+    let mut calls = 0;
+    CFnMut::new(move |x: i32| {
+        calls += 1;
+        calls + x
+    })
+}
+
+fn collect<B>(mut f: CFnMut<i32, B>, inputs: &[i32]) -> Vec<B> {
+    inputs.iter().map(|&x| f.call_mut(x)).collect()
+}
+
+#[test]
+fn cfn_mut_keeps_state_between_calls() {
+    let mut total = 0;
+    let mut running_sum = CFnMut::new(move |x: i32| {
+        total += x;
+        total
+    });
+    assert_eq!(running_sum.call_mut(1), 1);
+    assert_eq!(running_sum.call_mut(2), 3);
+    assert_eq!((*running_sum)(3), 6);
+    assert_eq!(collect(counter(), &[0, 0, 10]), vec![1, 2, 13]);
+}
+
+#[test]
+fn cfn_mut_composition_operators() {
+    let show = CFnMut::new(|x: i32| format!("#{x}"));
+    assert_eq!(collect(counter() >> show, &[0, 0]), vec!["#1", "#2"]);
+
+    let mut seen = Vec::new();
+    let history = CFnMut::new(move |s: String| {
+        seen.push(s);
+        seen.join(",")
+    });
+    let show = CFnMut::new(|x: i32| x.to_string());
+    assert_eq!(collect(history << show, &[4, 5]), vec!["4", "4,5"]);
+}
+
+#[test]
+fn cfn_converts_into_cfn_mut() {
+    let stateless: CFnMut<i32, i32> = CFn::new(|x: i32| x * 2).into();
+    assert_eq!(collect(stateless, &[1, 2]), vec![2, 4]);
+}
+
+#[test]
+fn cfn_mut_kind_map_and_bind_keep_the_state() {
+    let labelled = CFnMutKind::<i32>::map(counter(), |n: i32| format!("n={n}"));
+    assert_eq!(collect(labelled, &[0, 0]), vec!["n=1", "n=2"]);
+
+    // The inner function is fresh on every call; only `counter` accumulates.
+    let scaled = CFnMutKind::<i32>::bind(counter(), |n: i32| CFnMut::new(move |x: i32| n * x));
+    assert_eq!(collect(scaled, &[2, 2, 2]), vec![6, 8, 10]);
+
+    let applied = CFnMutKind::<i32>::apply(
+        counter(),
+        CFnMut::new(|x: i32| CFn::new(move |n: i32| n - x)),
+    );
+    assert_eq!(collect(applied, &[0, 5]), vec![1, 2]);
+}
+
+#[test]
+fn cfn_mut_profunctor_and_strong() {
+    let mut mapped = counter().dimap(|s: &str| s.len() as i32, |n: i32| n * 10);
+    assert_eq!(mapped.call_mut("ab"), 30);
+    assert_eq!(mapped.call_mut("ab"), 40);
+
+    let mut first = counter().first::<char>();
+    assert_eq!(first.call_mut((0, 'a')), (1, 'a'));
+    assert_eq!(first.call_mut((0, 'b')), (2, 'b'));
+    let mut second = counter().second::<char>();
+    assert_eq!(second.call_mut(('z', 10)), ('z', 11));
+}
+
+mod cfn_mut_kind_functor_laws {
+    use super::*;
+
+    #[test]
+    fn identity() {
+        let mapped = CFnMutKind::<i32>::map(counter(), |x: i32| x);
+        assert_eq!(collect(mapped, &[3, 3, 3]), collect(counter(), &[3, 3, 3]));
+    }
+
+    #[test]
+    fn composition() {
+        let f = |x: i32| x * 2;
+        let g = |x: i32| x.to_string();
+        let fused = CFnMutKind::<i32>::map(counter(), move |x| g(f(x)));
+        let stepwise = CFnMutKind::<i32>::map(CFnMutKind::<i32>::map(counter(), f), g);
+        assert_eq!(collect(fused, &[1, 2, 3]), collect(stepwise, &[1, 2, 3]));
+    }
+}
+
+mod cfn_mut_profunctor_laws {
+    use super::*;
+
+    #[test]
+    fn dimap_identity() {
+        let same = counter().dimap(|x: i32| x, |y: i32| y);
+        assert_eq!(collect(same, &[5, 6]), collect(counter(), &[5, 6]));
+    }
+
+    #[test]
+    fn dimap_composition() {
+        let f = |x: i32| x + 1;
+        let g = |x: i32| x * 3;
+        let h = |y: i32| y - 1;
+        let i = |y: i32| y * y;
+        let stepwise = counter().dimap(f, h).dimap(g, i);
+        let fused = counter().dimap(move |x| f(g(x)), move |y| i(h(y)));
+        assert_eq!(collect(stepwise, &[1, 2]), collect(fused, &[1, 2]));
+    }
+}
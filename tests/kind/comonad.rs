@@ -0,0 +1,241 @@
+use monadify::comonad::{
+    duplicate, Comonad, Env, EnvKind, Extend, NonEmpty, NonEmptyKind, Store, StoreKind, Traced,
+    TracedKind,
+};
+use monadify::functor::kind::Functor;
+use monadify::identity::{Identity, IdentityKind};
+use monadify::monoid::Sum;
+
+// Store and Traced hold functions, so they are compared by sampling positions.
+fn sample_store(store: &Store<i32, i32>) -> (i32, Vec<i32>) {
+    (store.pos, (-3..=3).map(|s| store.peek(s)).collect())
+}
+
+fn sample_traced(traced: &Traced<Sum<i32>, i32>) -> Vec<i32> {
+    (-3..=3).map(|m| traced.trace(Sum(m))).collect()
+}
+
+// --- IdentityKind ---
+
+#[test]
+fn identity_kind_extend_and_extract() {
+    let w = Identity(3);
+    assert_eq!(
+        IdentityKind::extend(w.clone(), |x: Identity<i32>| x.0 * 2),
+        Identity(6)
+    );
+    assert_eq!(IdentityKind::extract(w), 3);
+}
+
+#[test]
+fn identity_kind_comonad_laws() {
+    let w = Identity(5);
+    let f = |x: Identity<i32>| x.0 + 1;
+    let g = |x: Identity<i32>| x.0 * 10;
+
+    assert_eq!(IdentityKind::extend(w.clone(), IdentityKind::extract), w);
+    assert_eq!(
+        IdentityKind::extract(IdentityKind::extend(w.clone(), f)),
+        f(w.clone())
+    );
+    assert_eq!(
+        IdentityKind::extend(IdentityKind::extend(w.clone(), g), f),
+        IdentityKind::extend(w, move |x| f(IdentityKind::extend(x, g)))
+    );
+}
+
+// --- NonEmptyKind ---
+
+#[test]
+fn non_empty_constructors() {
+    assert_eq!(NonEmpty::from_vec(Vec::<i32>::new()), None);
+    let ne = NonEmpty::from_vec(vec![1, 2, 3]).unwrap();
+    assert_eq!(ne, NonEmpty::new(1, vec![2, 3]));
+    assert_eq!(ne.len(), 3);
+    assert!(!ne.is_empty());
+    assert_eq!(ne.into_vec(), vec![1, 2, 3]);
+}
+
+#[test]
+fn non_empty_kind_map() {
+    let ne = NonEmpty::new(1, vec![2, 3]);
+    assert_eq!(
+        NonEmptyKind::map(ne, |x: i32| x * 2),
+        NonEmpty::new(2, vec![4, 6])
+    );
+}
+
+#[test]
+fn non_empty_kind_extend_sees_suffixes() {
+    let ne = NonEmpty::new(1, vec![2, 3, 4]);
+    // Running sums from each position to the end.
+    let sums = NonEmptyKind::extend(ne.clone(), |w: NonEmpty<i32>| {
+        w.into_vec().iter().sum::<i32>()
+    });
+    assert_eq!(sums, NonEmpty::new(10, vec![9, 7, 4]));
+    assert_eq!(NonEmptyKind::extract(ne), 1);
+}
+
+#[test]
+fn non_empty_kind_duplicate() {
+    let ne = NonEmpty::new(1, vec![2]);
+    assert_eq!(
+        duplicate::<NonEmptyKind, _>(ne),
+        NonEmpty::new(NonEmpty::new(1, vec![2]), vec![NonEmpty::new(2, vec![])])
+    );
+}
+
+#[test]
+fn non_empty_kind_comonad_laws() {
+    let w = NonEmpty::new(3, vec![1, 4, 1]);
+    let f = |x: NonEmpty<i32>| x.head * x.len() as i32;
+    let g = |x: NonEmpty<i32>| x.tail.iter().sum::<i32>();
+
+    assert_eq!(NonEmptyKind::extend(w.clone(), NonEmptyKind::extract), w);
+    assert_eq!(
+        NonEmptyKind::extract(NonEmptyKind::extend(w.clone(), f)),
+        f(w.clone())
+    );
+    assert_eq!(
+        NonEmptyKind::extend(NonEmptyKind::extend(w.clone(), g), f),
+        NonEmptyKind::extend(w, move |x| f(NonEmptyKind::extend(x, g)))
+    );
+}
+
+// --- StoreKind ---
+
+#[test]
+fn store_peek_seek() {
+    let store: Store<i32, i32> = Store::new(|s: i32| s * 100, 2);
+    assert_eq!(store.peek(7), 700);
+    assert_eq!(store.peeks(|s| s + 1), 300);
+    assert_eq!(StoreKind::extract(store.clone().seek(5)), 500);
+    assert_eq!(StoreKind::extract(store.seeks(|s| s - 2)), 0);
+}
+
+#[test]
+fn store_kind_map() {
+    let store: Store<i32, i32> = Store::new(|s: i32| s + 1, 0);
+    let mapped = StoreKind::map(store, |a: i32| a.to_string());
+    assert_eq!(mapped.peek(4), "5");
+    assert_eq!(mapped.pos, 0);
+}
+
+#[test]
+fn store_kind_cellular_rule() {
+    // Rule: a cell is alive if exactly one neighbour is alive.
+    let alive = [false, false, true, false, false];
+    let board: Store<i32, bool> = Store::new(
+        move |i: i32| {
+            usize::try_from(i)
+                .ok()
+                .and_then(|i| alive.get(i).copied())
+                .unwrap_or(false)
+        },
+        0,
+    );
+    let step = StoreKind::extend(board, |w: Store<i32, bool>| {
+        w.peek(w.pos - 1) ^ w.peek(w.pos + 1)
+    });
+    let next: Vec<bool> = (0..5).map(|i| step.peek(i)).collect();
+    assert_eq!(next, vec![false, true, false, true, false]);
+}
+
+#[test]
+fn store_kind_comonad_laws() {
+    let w: Store<i32, i32> = Store::new(|s: i32| s * s - s, 1);
+    let f = |x: Store<i32, i32>| x.peek(x.pos + 1) - x.peek(x.pos);
+    let g = |x: Store<i32, i32>| x.peek(x.pos) * 2;
+
+    assert_eq!(
+        sample_store(&StoreKind::extend(w.clone(), StoreKind::extract)),
+        sample_store(&w)
+    );
+    assert_eq!(
+        StoreKind::extract(StoreKind::extend(w.clone(), f)),
+        f(w.clone())
+    );
+    assert_eq!(
+        sample_store(&StoreKind::extend(StoreKind::extend(w.clone(), g), f)),
+        sample_store(&StoreKind::extend(w, move |x| f(StoreKind::extend(x, g))))
+    );
+}
+
+// --- EnvKind ---
+
+#[test]
+fn env_ask_local() {
+    let e = Env::new("cfg".to_string(), 3);
+    assert_eq!(e.ask(), "cfg");
+    let e2 = e.local(|s| s.to_uppercase());
+    assert_eq!(e2, Env::new("CFG".to_string(), 3));
+}
+
+#[test]
+fn env_kind_map_and_extend() {
+    let e = Env::new(10, 3);
+    assert_eq!(EnvKind::map(e.clone(), |x: i32| x + 1), Env::new(10, 4));
+    assert_eq!(
+        EnvKind::extend(e.clone(), |w: Env<i32, i32>| w.env * w.value),
+        Env::new(10, 30)
+    );
+    assert_eq!(EnvKind::extract(e), 3);
+}
+
+#[test]
+fn env_kind_comonad_laws() {
+    let w = Env::new("env".to_string(), 4);
+    let f = |x: Env<String, i32>| x.env.len() as i32 + x.value;
+    let g = |x: Env<String, i32>| x.value * 3;
+
+    assert_eq!(EnvKind::extend(w.clone(), EnvKind::extract), w);
+    assert_eq!(
+        EnvKind::extract(EnvKind::extend(w.clone(), f)),
+        f(w.clone())
+    );
+    assert_eq!(
+        EnvKind::extend(EnvKind::extend(w.clone(), g), f),
+        EnvKind::extend(w, move |x| f(EnvKind::extend(x, g)))
+    );
+}
+
+// --- TracedKind ---
+
+#[test]
+fn traced_kind_extract_reads_at_empty() {
+    let t: Traced<Sum<i32>, i32> = Traced::new(|Sum(m): Sum<i32>| m + 100);
+    assert_eq!(TracedKind::extract(t.clone()), 100);
+    assert_eq!(t.trace(Sum(5)), 105);
+    assert_eq!(TracedKind::map(t, |a: i32| a * 2).trace(Sum(1)), 202);
+}
+
+#[test]
+fn traced_kind_extend_shifts_position() {
+    let t: Traced<Sum<i32>, i32> = Traced::new(|Sum(m): Sum<i32>| m * m);
+    // Forward difference: t(m + 1) - t(m)
+    let diff = TracedKind::extend(t, |w: Traced<Sum<i32>, i32>| {
+        w.trace(Sum(1)) - w.trace(Sum(0))
+    });
+    assert_eq!(diff.trace(Sum(0)), 1);
+    assert_eq!(diff.trace(Sum(3)), 7);
+}
+
+#[test]
+fn traced_kind_comonad_laws() {
+    let w: Traced<Sum<i32>, i32> = Traced::new(|Sum(m): Sum<i32>| 2 * m + 1);
+    let f = |x: Traced<Sum<i32>, i32>| x.trace(Sum(2)) - x.trace(Sum(0));
+    let g = |x: Traced<Sum<i32>, i32>| x.trace(Sum(-1)) * 3;
+
+    assert_eq!(
+        sample_traced(&TracedKind::extend(w.clone(), TracedKind::extract)),
+        sample_traced(&w)
+    );
+    assert_eq!(
+        TracedKind::extract(TracedKind::extend(w.clone(), f)),
+        f(w.clone())
+    );
+    assert_eq!(
+        sample_traced(&TracedKind::extend(TracedKind::extend(w.clone(), g), f)),
+        sample_traced(&TracedKind::extend(w, move |x| f(TracedKind::extend(x, g))))
+    );
+}
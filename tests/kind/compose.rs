@@ -0,0 +1,204 @@
+use monadify::applicative::kind::Applicative;
+use monadify::apply::kind::Apply;
+use monadify::compose::{ComposeKind, CoproductKind, ProductKind};
+use monadify::function::CFn;
+use monadify::functor::kind::Functor;
+use monadify::identity::{Identity, IdentityKind};
+use monadify::kind_based::kind::{OptionKind, ResultKind, VecKind};
+
+type VecOption = ComposeKind<VecKind, OptionKind>;
+type OptionVec = ComposeKind<OptionKind, VecKind>;
+type ResultVec = ComposeKind<ResultKind<String>, VecKind>;
+type OptionResult = ComposeKind<OptionKind, ResultKind<String>>;
+type OptionVecProduct = ProductKind<OptionKind, VecKind>;
+type OptionVecCoproduct = CoproductKind<OptionKind, VecKind>;
+type IntFnPair = (Option<CFn<i32, i32>>, Vec<CFn<i32, i32>>);
+
+// --- ComposeKind Functor ---
+
+#[test]
+fn compose_kind_map_reaches_both_layers() {
+    let values: Vec<Option<i32>> = vec![Some(1), None, Some(3)];
+    assert_eq!(
+        VecOption::map(values, |x: i32| x.to_string()),
+        vec![Some("1".to_string()), None, Some("3".to_string())]
+    );
+
+    let ok: Result<Vec<i32>, String> = Ok(vec![1, 2]);
+    let err: Result<Vec<i32>, String> = Err("boom".to_string());
+    assert_eq!(ResultVec::map(ok, |x: i32| x + 1), Ok(vec![2, 3]));
+    assert_eq!(ResultVec::map(err, |x: i32| x + 1), Err("boom".to_string()));
+}
+
+#[test]
+fn compose_kind_functor_law_identity() {
+    let values: Vec<Option<i32>> = vec![Some(10), None];
+    assert_eq!(VecOption::map(values.clone(), |x: i32| x), values);
+
+    let nested: Option<Vec<i32>> = Some(vec![1, 2, 3]);
+    assert_eq!(OptionVec::map(nested.clone(), |x: i32| x), nested);
+}
+
+#[test]
+fn compose_kind_functor_law_composition() {
+    let values: Vec<Option<i32>> = vec![Some(10), None, Some(-4)];
+    let f = |x: i32| x * 2;
+    let g = |y: i32| y - 5;
+
+    let composed = VecOption::map(values.clone(), move |x| g(f(x)));
+    let sequential = VecOption::map(VecOption::map(values, f), g);
+    assert_eq!(composed, sequential);
+    assert_eq!(composed, vec![Some(15), None, Some(-13)]);
+}
+
+// --- ComposeKind Apply / Applicative ---
+
+#[test]
+fn compose_kind_apply_vec_option() {
+    let values: Vec<Option<i32>> = vec![Some(1), None];
+    let funcs: Vec<Option<CFn<i32, i32>>> =
+        vec![Some(CFn::new(|x| x + 10)), Some(CFn::new(|x| x * 10)), None];
+    assert_eq!(
+        VecOption::apply(values, funcs),
+        vec![Some(11), None, Some(10), None, None, None]
+    );
+}
+
+#[test]
+fn compose_kind_apply_option_vec() {
+    let values: Option<Vec<i32>> = Some(vec![1, 2]);
+    let funcs: Option<Vec<CFn<i32, i32>>> = Some(vec![CFn::new(|x| x + 10), CFn::new(|x| x * 10)]);
+    assert_eq!(
+        OptionVec::apply(values.clone(), funcs),
+        Some(vec![11, 12, 10, 20])
+    );
+    assert_eq!(OptionVec::apply(values, None::<Vec<CFn<i32, i32>>>), None);
+}
+
+#[test]
+fn compose_kind_applicative_pure() {
+    let v: Vec<Option<i32>> = VecOption::pure(7);
+    assert_eq!(v, vec![Some(7)]);
+    let o: Option<Vec<i32>> = OptionVec::pure(7);
+    assert_eq!(o, Some(vec![7]));
+}
+
+#[test]
+fn compose_kind_applicative_law_identity() {
+    // apply(v, pure(id)) == v
+    let values: Option<Result<i32, String>> = Some(Ok(3));
+    let failed: Option<Result<i32, String>> = Some(Err("nope".to_string()));
+    let pure_id = || OptionResult::pure(CFn::new(|x: i32| x));
+    assert_eq!(OptionResult::apply(values.clone(), pure_id()), values);
+    assert_eq!(OptionResult::apply(failed.clone(), pure_id()), failed);
+    assert_eq!(OptionResult::apply(None, pure_id()), None);
+
+    // `Vec` cannot lift a `CFn` with `pure` (it is not `Clone`), so build `pure(id)` by hand.
+    let nested: Vec<Option<i32>> = vec![Some(1), None, Some(3)];
+    let vec_pure_id: Vec<Option<CFn<i32, i32>>> = vec![Some(CFn::new(|x: i32| x))];
+    assert_eq!(VecOption::apply(nested.clone(), vec_pure_id), nested);
+}
+
+#[test]
+fn compose_kind_applicative_law_homomorphism() {
+    // apply(pure(x), pure(f)) == pure(f(x))
+    let f = |x: i32| x * 3;
+    let lhs: Option<Result<i32, String>> =
+        OptionResult::apply(OptionResult::pure(4), OptionResult::pure(CFn::new(f)));
+    let rhs: Option<Result<i32, String>> = OptionResult::pure(f(4));
+    assert_eq!(lhs, rhs);
+}
+
+#[test]
+fn compose_kind_applicative_law_interchange() {
+    // apply(pure(y), u) == apply(u, pure(|f| f(y)))
+    let u = || -> Option<Result<CFn<i32, String>, String>> {
+        Some(Ok(CFn::new(|x: i32| format!("a{x}"))))
+    };
+    let y = 5;
+    let lhs = OptionResult::apply(OptionResult::pure(y), u());
+    let rhs = OptionResult::apply(
+        u(),
+        OptionResult::pure(CFn::new(move |f: CFn<i32, String>| f.call(y))),
+    );
+    assert_eq!(lhs, rhs);
+    assert_eq!(lhs, Some(Ok("a5".to_string())));
+}
+
+// --- ProductKind ---
+
+#[test]
+fn product_kind_map_both_sides() {
+    let pair: (Option<i32>, Vec<i32>) = (Some(1), vec![2, 3]);
+    assert_eq!(
+        OptionVecProduct::map(pair, |x: i32| x * 10),
+        (Some(10), vec![20, 30])
+    );
+}
+
+#[test]
+fn product_kind_functor_laws() {
+    let pair: (Option<i32>, Vec<i32>) = (None, vec![2, 3]);
+    assert_eq!(OptionVecProduct::map(pair.clone(), |x: i32| x), pair);
+
+    let f = |x: i32| x + 1;
+    let g = |x: i32| x * 2;
+    assert_eq!(
+        OptionVecProduct::map(pair.clone(), move |x| g(f(x))),
+        OptionVecProduct::map(OptionVecProduct::map(pair, f), g)
+    );
+}
+
+#[test]
+fn product_kind_apply_and_pure() {
+    let pair: (Option<i32>, Vec<i32>) = (Some(1), vec![2, 3]);
+    let funcs: IntFnPair = (Some(CFn::new(|x| x + 1)), vec![CFn::new(|x| x * 10)]);
+    assert_eq!(
+        OptionVecProduct::apply(pair, funcs),
+        (Some(2), vec![20, 30])
+    );
+
+    let lifted: (Option<i32>, Vec<i32>) = OptionVecProduct::pure(9);
+    assert_eq!(lifted, (Some(9), vec![9]));
+}
+
+#[test]
+fn product_kind_applicative_law_identity() {
+    let pair: (Option<i32>, Vec<i32>) = (Some(4), vec![5, 6]);
+    let pure_id: IntFnPair = (Some(CFn::new(|x: i32| x)), vec![CFn::new(|x: i32| x)]);
+    assert_eq!(OptionVecProduct::apply(pair.clone(), pure_id), pair);
+}
+
+#[test]
+fn product_kind_with_identity() {
+    type IdOption = ProductKind<IdentityKind, OptionKind>;
+    let pair = (Identity(3), Some(4));
+    assert_eq!(IdOption::map(pair, |x: i32| x * x), (Identity(9), Some(16)));
+}
+
+// --- CoproductKind ---
+
+#[test]
+fn coproduct_kind_map_either_side() {
+    let left: Result<Option<i32>, Vec<i32>> = Ok(Some(2));
+    let right: Result<Option<i32>, Vec<i32>> = Err(vec![1, 2]);
+    assert_eq!(OptionVecCoproduct::map(left, |x: i32| x + 1), Ok(Some(3)));
+    assert_eq!(
+        OptionVecCoproduct::map(right, |x: i32| x + 1),
+        Err(vec![2, 3])
+    );
+}
+
+#[test]
+fn coproduct_kind_functor_laws() {
+    let inputs: Vec<Result<Option<i32>, Vec<i32>>> = vec![Ok(Some(2)), Ok(None), Err(vec![1, 2])];
+    let f = |x: i32| x - 1;
+    let g = |x: i32| x * 7;
+    for input in inputs {
+        assert_eq!(OptionVecCoproduct::map(input.clone(), |x: i32| x), input);
+        assert_eq!(
+            OptionVecCoproduct::map(input.clone(), move |x| g(f(x))),
+            OptionVecCoproduct::map(OptionVecCoproduct::map(input, f), g)
+        );
+    }
+}
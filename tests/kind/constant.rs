@@ -0,0 +1,74 @@
+use monadify::applicative::kind::Applicative;
+use monadify::apply::kind::Apply;
+use monadify::constant::{Const, ConstKind};
+use monadify::function::CFn;
+use monadify::functor::kind::Functor;
+use monadify::monoid::Sum;
+
+#[test]
+fn const_kind_map_keeps_value() {
+    let c: Const<i32, String> = Const::new(5);
+    let mapped: Const<i32, usize> = ConstKind::map(c, |s: String| s.len());
+    assert_eq!(mapped.get_const(), 5);
+}
+
+#[test]
+fn const_kind_map_never_calls_function() {
+    let c: Const<&str, i32> = Const::new("untouched");
+    let mapped: Const<&str, i32> = ConstKind::map(c, |_x: i32| -> i32 { panic!("map called") });
+    assert_eq!(mapped, Const::new("untouched"));
+}
+
+#[test]
+fn const_kind_functor_laws() {
+    let c: Const<String, i32> = Const::new("v".to_string());
+    assert_eq!(ConstKind::map(c.clone(), |x: i32| x), c);
+
+    let f = |x: i32| x + 1;
+    let g = |x: i32| x.to_string();
+    let composed: Const<String, String> = ConstKind::map(c.clone(), move |x| g(f(x)));
+    let sequential: Const<String, String> = ConstKind::map(ConstKind::map(c, f), g);
+    assert_eq!(composed, sequential);
+}
+
+#[test]
+fn const_kind_apply_combines_function_side_first() {
+    let f: Const<String, CFn<i32, i32>> = Const::new("f".to_string());
+    let v: Const<String, i32> = Const::new("v".to_string());
+    assert_eq!(ConstKind::apply(v, f), Const::new("fv".to_string()));
+}
+
+#[test]
+fn const_kind_pure_is_empty() {
+    let p: Const<String, i32> = ConstKind::pure(42);
+    assert_eq!(p.get_const(), "");
+    let s: Const<Sum<i32>, &str> = ConstKind::pure("ignored");
+    assert_eq!(s.get_const(), Sum(0));
+}
+
+#[test]
+fn const_kind_applicative_law_identity() {
+    // apply(v, pure(id)) == v
+    let v: Const<Vec<i32>, i32> = Const::new(vec![1, 2]);
+    let pure_id = ConstKind::<Vec<i32>>::pure(CFn::new(|x: i32| x));
+    assert_eq!(ConstKind::apply(v.clone(), pure_id), v);
+}
+
+#[test]
+fn const_kind_applicative_law_homomorphism() {
+    // apply(pure(x), pure(f)) == pure(f(x))
+    let lhs: Const<String, i32> = ConstKind::apply(
+        ConstKind::pure(3),
+        ConstKind::pure(CFn::new(|x: i32| x * 2)),
+    );
+    let rhs: Const<String, i32> = ConstKind::pure(6);
+    assert_eq!(lhs, rhs);
+}
+
+#[test]
+fn const_debug_and_copy() {
+    let c: Const<i32, String> = Const::new(1);
+    let copied = c;
+    assert_eq!(format!("{:?}", copied), "Const(1)");
+    assert_eq!(c, copied);
+}
@@ -0,0 +1,221 @@
+use monadify::contravariant::{
+    Comparison, ComparisonKind, Contravariant, Decidable, Decide, Divide, Divisible, Equivalence,
+    EquivalenceKind, Op, OpKind, Predicate, PredicateKind,
+};
+use monadify::monoid::Sum;
+use std::cmp::Ordering;
+use std::convert::Infallible;
+
+#[derive(Clone, Debug, PartialEq)]
+struct Person {
+    name: String,
+    age: u32,
+}
+
+fn person(name: &str, age: u32) -> Person {
+    Person {
+        name: name.to_string(),
+        age,
+    }
+}
+
+fn split_person(p: Person) -> (String, u32) {
+    (p.name, p.age)
+}
+
+// --- Contravariant laws ---
+
+#[test]
+fn predicate_contramap_identity_law() {
+    let is_positive = Predicate::new(|x: i32| x > 0);
+    let mapped: Predicate<i32> = PredicateKind::contramap(is_positive, |x: i32| x);
+    assert!(mapped.test(3));
+    assert!(!mapped.test(-3));
+}
+
+#[test]
+fn predicate_contramap_composition_law() {
+    let f = |s: String| s.len();
+    let g = |x: i32| "a".repeat(x as usize);
+
+    let lhs: Predicate<i32> = PredicateKind::contramap(
+        PredicateKind::contramap(Predicate::new(|n: usize| n > 2), f),
+        g,
+    );
+    let rhs: Predicate<i32> =
+        PredicateKind::contramap(Predicate::new(|n: usize| n > 2), move |x: i32| f(g(x)));
+    for x in 0..6 {
+        assert_eq!(lhs.test(x), rhs.test(x));
+    }
+}
+
+#[test]
+fn comparison_contramap_compares_images() {
+    let by_age: Comparison<Person> =
+        ComparisonKind::contramap(Comparison::by_ord(), |p: Person| p.age);
+    assert_eq!(
+        by_age.compare(person("Zed", 20), person("Amy", 30)),
+        Ordering::Less
+    );
+}
+
+#[test]
+fn equivalence_contramap_compares_images() {
+    let same_len: Equivalence<String> =
+        EquivalenceKind::contramap(Equivalence::by_eq(), |s: String| s.len());
+    assert!(same_len.equivalent("abc".to_string(), "xyz".to_string()));
+    assert!(!same_len.equivalent("ab".to_string(), "xyz".to_string()));
+}
+
+#[test]
+fn op_contramap_precomposes() {
+    let show: Op<String, i32> = Op::new(|x: i32| x.to_string());
+    let show_len: Op<String, &str> = OpKind::contramap(show, |s: &str| s.len() as i32);
+    assert_eq!(show_len.call("hello"), "5");
+}
+
+// --- Divide / Divisible ---
+
+#[test]
+fn predicate_divide_requires_both_parts() {
+    let accept: Predicate<Person> = PredicateKind::divide(
+        split_person,
+        Predicate::new(|name: String| !name.is_empty()),
+        Predicate::new(|age: u32| age >= 18),
+    );
+    assert!(accept.test(person("Ann", 30)));
+    assert!(!accept.test(person("Ann", 12)));
+    assert!(!accept.test(person("", 30)));
+}
+
+#[test]
+fn comparison_divide_is_lexicographic() {
+    let cmp: Comparison<Person> =
+        ComparisonKind::divide(split_person, Comparison::by_ord(), Comparison::by_ord());
+    assert_eq!(
+        cmp.compare(person("Ann", 40), person("Bob", 20)),
+        Ordering::Less
+    );
+    assert_eq!(
+        cmp.compare(person("Ann", 40), person("Ann", 20)),
+        Ordering::Greater
+    );
+    assert_eq!(
+        cmp.compare(person("Ann", 40), person("Ann", 40)),
+        Ordering::Equal
+    );
+}
+
+#[test]
+fn comparison_sorts_with_divide() {
+    let cmp: Comparison<Person> =
+        ComparisonKind::divide(split_person, Comparison::by_ord(), Comparison::by_ord());
+    let mut people = vec![person("Bob", 1), person("Ann", 2), person("Ann", 1)];
+    people.sort_by(|x, y| cmp.compare(x.clone(), y.clone()));
+    assert_eq!(
+        people,
+        vec![person("Ann", 1), person("Ann", 2), person("Bob", 1)]
+    );
+}
+
+#[test]
+fn equivalence_divide_requires_both_parts() {
+    let eq: Equivalence<Person> =
+        EquivalenceKind::divide(split_person, Equivalence::by_eq(), Equivalence::by_eq());
+    assert!(eq.equivalent(person("Ann", 1), person("Ann", 1)));
+    assert!(!eq.equivalent(person("Ann", 1), person("Ann", 2)));
+}
+
+#[test]
+fn op_divide_combines_results() {
+    let total: Op<Sum<u32>, (u32, u32)> = OpKind::divide(
+        |pair: (u32, u32)| pair,
+        Op::new(Sum),
+        Op::new(|x: u32| Sum(x * 10)),
+    );
+    assert_eq!(total.call((1, 2)), Sum(21));
+}
+
+#[test]
+fn conquer_is_identity_for_divide() {
+    let is_even = Predicate::new(|x: i32| x % 2 == 0);
+    let with_conquer: Predicate<i32> =
+        PredicateKind::divide(|x: i32| (x, x), is_even, PredicateKind::conquer());
+    assert!(with_conquer.test(4));
+    assert!(!with_conquer.test(3));
+
+    let cmp: Comparison<i32> = ComparisonKind::divide(
+        |x: i32| (x, x),
+        ComparisonKind::conquer(),
+        Comparison::by_ord(),
+    );
+    assert_eq!(cmp.compare(1, 2), Ordering::Less);
+
+    let op: Op<String, i32> = OpKind::conquer();
+    assert_eq!(op.call(7), "");
+}
+
+// --- Decide / Decidable ---
+
+fn parity(x: i32) -> Result<i32, i32> {
+    if x % 2 == 0 {
+        Ok(x)
+    } else {
+        Err(x)
+    }
+}
+
+#[test]
+fn predicate_choose_dispatches_on_result() {
+    let p: Predicate<i32> = PredicateKind::choose(
+        parity,
+        Predicate::new(|even: i32| even > 10),
+        Predicate::new(|odd: i32| odd < 0),
+    );
+    assert!(p.test(12));
+    assert!(!p.test(4));
+    assert!(p.test(-3));
+    assert!(!p.test(3));
+}
+
+#[test]
+fn comparison_choose_orders_ok_before_err() {
+    let cmp: Comparison<i32> =
+        ComparisonKind::choose(parity, Comparison::by_ord(), Comparison::by_ord());
+    assert_eq!(cmp.compare(8, 1), Ordering::Less);
+    assert_eq!(cmp.compare(1, 8), Ordering::Greater);
+    assert_eq!(cmp.compare(2, 4), Ordering::Less);
+    assert_eq!(cmp.compare(5, 3), Ordering::Greater);
+}
+
+#[test]
+fn equivalence_choose_requires_same_alternative() {
+    let eq: Equivalence<i32> = EquivalenceKind::choose(
+        parity,
+        Equivalence::new(|_: i32, _: i32| true),
+        Equivalence::new(|_: i32, _: i32| true),
+    );
+    assert!(eq.equivalent(2, 4));
+    assert!(eq.equivalent(1, 3));
+    assert!(!eq.equivalent(1, 2));
+}
+
+#[test]
+fn op_choose_dispatches_on_result() {
+    let describe: Op<String, i32> = OpKind::choose(
+        parity,
+        Op::new(|x: i32| format!("even {}", x)),
+        Op::new(|x: i32| format!("odd {}", x)),
+    );
+    assert_eq!(describe.call(2), "even 2");
+    assert_eq!(describe.call(3), "odd 3");
+}
+
+#[test]
+fn lose_builds_consumer_of_uninhabited_type() {
+    // No value of `Infallible` exists, so these consumers can be built but never run.
+    let _p: Predicate<Infallible> = PredicateKind::lose(|x: Infallible| x);
+    let _c: Comparison<Infallible> = ComparisonKind::lose(|x: Infallible| x);
+    let _e: Equivalence<Infallible> = EquivalenceKind::lose(|x: Infallible| x);
+    let _o: Op<String, Infallible> = OpKind::lose(|x: Infallible| x);
+}
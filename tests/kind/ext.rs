@@ -0,0 +1,144 @@
+use monadify::ext::{apply, bind, map, pure_as};
+use monadify::function::{CFn, CFnOnce, RcFn};
+use monadify::functor::kind::Functor;
+use monadify::kind_based::kind::{OptionKind, ResultKind};
+use monadify::monad::kind::Bind;
+use monadify::transformers::reader::{Reader, ReaderT};
+use monadify::{ApplyExt, BindExt, FunctorExt, Identity, Pipe};
+
+fn parse(s: &str) -> Result<i32, String> {
+    s.parse::<i32>().map_err(|e| e.to_string())
+}
+
+#[test]
+fn fmap_matches_the_marker_map() {
+    let f = |x: i32| x.to_string();
+    assert_eq!(Some(7).fmap(f), OptionKind::map(Some(7), f));
+    assert_eq!(None::<i32>.fmap(f), None);
+    assert_eq!(vec![1, 2].fmap(f), vec!["1".to_string(), "2".to_string()]);
+    assert_eq!(Identity(3).fmap(|x| x * 3), Identity(9));
+
+    let ok: Result<i32, String> = Ok(2);
+    assert_eq!(ok.clone().fmap(f), ResultKind::<String>::map(ok, f));
+    assert_eq!(Err::<i32, String>("bad".into()).fmap(f), Err("bad".into()));
+}
+
+#[test]
+fn flat_map_matches_the_marker_bind() {
+    let half = |x: i32| if x % 2 == 0 { Some(x / 2) } else { None };
+    assert_eq!(Some(8).flat_map(half), OptionKind::bind(Some(8), half));
+    assert_eq!(
+        Some(8).flat_map(half).flat_map(half).flat_map(half),
+        Some(1)
+    );
+    assert_eq!(Some(6).flat_map(half).flat_map(half), None);
+
+    assert_eq!(Ok("12".to_string()).flat_map(|s: String| parse(&s)), Ok(12));
+    assert!(Ok::<_, String>("x".to_string())
+        .flat_map(|s: String| parse(&s))
+        .is_err());
+    assert_eq!(vec![1, 2].flat_map(|x| vec![x, x * 10]), vec![1, 10, 2, 20]);
+    assert_eq!(Identity(4).flat_map(|x| Identity(x + 1)), Identity(5));
+}
+
+#[test]
+fn ap_applies_the_wrapped_functions() {
+    assert_eq!(Some(4).ap(Some(CFn::new(|x: i32| x + 1))), Some(5));
+    assert_eq!(Some(4).ap(None::<CFn<i32, i32>>), None);
+    let fs = vec![CFn::new(|x: i32| x + 1), CFn::new(|x: i32| x * 10)];
+    assert_eq!(vec![1, 2].ap(fs), vec![2, 3, 10, 20]);
+    assert_eq!(
+        Err::<i32, &str>("no value").ap(Ok(CFn::new(|x: i32| x))),
+        Err("no value")
+    );
+    assert_eq!(
+        Identity(2).ap(Identity(CFn::new(|x: i32| x * x))),
+        Identity(4)
+    );
+}
+
+#[test]
+fn reader_t_methods_share_the_environment() {
+    let base: ReaderT<i32, OptionKind, i32> = ReaderT::new(|env: i32| Some(env * 10));
+    let scaled = base
+        .fmap(|x| x + 1)
+        .flat_map(|x| ReaderT::new(move |env: i32| (env > 0).then_some(x - env)));
+    assert_eq!((scaled.run_reader_t)(2), Some(19));
+    assert_eq!((scaled.run_reader_t)(-1), None);
+
+    let plain: Reader<&str, usize> = ReaderT::new(|env: &str| Identity(env.len()));
+    let doubled = plain.fmap(|n| n * 2);
+    assert_eq!((doubled.run_reader_t)("abc"), Identity(6));
+}
+
+#[test]
+fn pipe_continues_a_chain_through_plain_functions() {
+    let describe = |o: Option<i32>| o.map_or("nothing".to_string(), |x| format!("got {x}"));
+    assert_eq!(Some(20).fmap(|x| x + 1).pipe(describe), "got 21");
+    assert_eq!("  padded ".pipe(str::trim).pipe(str::len), 6);
+}
+
+#[test]
+fn function_wrappers_map_and_bind_over_their_output() {
+    let len = CFn::new(|s: &str| s.len());
+    let described = len.fmap(|n| format!("{n} chars"));
+    assert_eq!(described.call("four"), "4 chars");
+
+    // `bind` passes the same input to both steps, like a Reader.
+    let base = CFn::new(|env: i32| env * 10);
+    let offset = base.flat_map(|x: i32| CFn::new(move |env: i32| x + env));
+    assert_eq!(offset.call(2), 22);
+
+    let once = CFnOnce::new(|v: Vec<i32>| v.len()).fmap(|n| n * 2);
+    assert_eq!(once.call_once(vec![1, 2, 3]), 6);
+
+    let shared = RcFn::new(|x: i32| x - 1).fmap(|x| x * x);
+    assert_eq!(shared.clone().call(4), 9);
+}
+
+mod free_functions {
+    use super::*;
+
+    #[test]
+    fn map_bind_and_apply_infer_the_kind_from_the_value() {
+        assert_eq!(map(Some(2), |x: i32| x + 1), Some(3));
+        assert_eq!(map(vec!["a", "bc"], str::len), vec![1, 2]);
+        assert_eq!(bind(Ok::<_, String>("12"), parse), Ok(12));
+        assert_eq!(bind(Identity(5), |x| Identity(x * 2)), Identity(10));
+        assert_eq!(apply(Some(3), Some(CFn::new(|x: i32| x * x))), Some(9));
+
+        let reader: ReaderT<i32, OptionKind, i32> = ReaderT::new(|env: i32| Some(env + 1));
+        let mapped = map(reader, |x: i32| x.to_string());
+        assert_eq!((mapped.run_reader_t)(4), Some("5".to_string()));
+
+        let shout = map(CFn::new(|s: &str| s.to_string()), |s: String| {
+            s.to_uppercase()
+        });
+        assert_eq!(shout.call("hey"), "HEY");
+    }
+
+    #[test]
+    fn pure_as_reads_the_kind_from_the_result_type() {
+        let opt: Option<i32> = pure_as(1);
+        let many: Vec<char> = pure_as('x');
+        let id: Identity<&str> = pure_as("same");
+        assert_eq!(opt, Some(1));
+        assert_eq!(many, vec!['x']);
+        assert_eq!(id, Identity("same"));
+        assert_eq!(pure_as::<Result<u8, String>>(9), Ok(9));
+
+        let constant: CFn<&str, i32> = pure_as(7);
+        assert_eq!(constant.call("ignored"), 7);
+    }
+
+    #[test]
+    fn free_functions_agree_with_the_methods() {
+        let half = |x: i32| (x % 2 == 0).then_some(x / 2);
+        for start in [Some(12), Some(3), None] {
+            assert_eq!(
+                bind(map(start, |x| x * 2), half),
+                start.fmap(|x| x * 2).flat_map(half)
+            );
+        }
+    }
+}
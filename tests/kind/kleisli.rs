@@ -0,0 +1,184 @@
+use monadify::applicative::kind::Applicative;
+use monadify::apply::kind::Apply;
+use monadify::function::CFn;
+use monadify::functor::kind::Functor;
+use monadify::kind_based::kind::{OptionKind, ResultKind, VecKind};
+use monadify::kleisli::{Kleisli, KleisliKind};
+use monadify::monad::kind::{Bind, Monad};
+use monadify::transformers::reader::ReaderT;
+use monadify::{Choice, Profunctor, ReaderTKind};
+
+type Checked<A, B> = Kleisli<ResultKind<String>, A, B>;
+type Env = i32;
+type ByEnv<B> = Kleisli<OptionKind, Env, B>;
+type ByEnvKind = KleisliKind<OptionKind, Env>;
+
+fn parse() -> Checked<&'static str, i32> {
+    Kleisli::new(|s: &str| s.parse::<i32>().map_err(|e| e.to_string()))
+}
+
+fn non_zero() -> Checked<i32, i32> {
+    Kleisli::new(|n: i32| {
+        if n == 0 {
+            Err("zero".to_string())
+        } else {
+            Ok(n)
+        }
+    })
+}
+
+fn reciprocal() -> Checked<i32, f64> {
+    Kleisli::new(|n: i32| Ok(1.0 / n as f64))
+}
+
+// `Kleisli`s can't be compared directly, so compare them on a few inputs.
+fn assert_same<B: PartialEq + std::fmt::Debug>(f: &ByEnv<B>, g: &ByEnv<B>) {
+    for env in [-3, 0, 7] {
+        assert_eq!(f.run(env), g.run(env));
+    }
+}
+
+#[test]
+fn fish_operator_composes_left_to_right() {
+    let pipeline = parse() >> non_zero() >> reciprocal();
+    assert_eq!(pipeline.run("4"), Ok(0.25));
+    assert_eq!(pipeline.run("0"), Err("zero".to_string()));
+    assert!(pipeline.run("four").is_err());
+
+    let backwards = reciprocal() << non_zero() << parse();
+    assert_eq!(backwards.run("2"), Ok(0.5));
+}
+
+#[test]
+fn fish_operator_over_vec_and_reader_t() {
+    let neighbours: Kleisli<VecKind, i32, i32> = Kleisli::new(|n: i32| vec![n - 1, n + 1]);
+    assert_eq!((neighbours.clone() >> neighbours).run(0), vec![-2, 0, 0, 2]);
+
+    // Steps returning a `ReaderT` share the environment supplied at the end.
+    type Scaled = ReaderTKind<i32, OptionKind>;
+    let scale: Kleisli<Scaled, i32, i32> =
+        Kleisli::new(|x: i32| ReaderT::new(move |factor: i32| Some(x * factor)));
+    let offset: Kleisli<Scaled, i32, i32> =
+        Kleisli::new(|x: i32| ReaderT::new(move |factor: i32| (factor > 0).then_some(x + factor)));
+    let both = scale >> offset;
+    assert_eq!((both.run(5).run_reader_t)(3), Some(18));
+    assert_eq!((both.run(5).run_reader_t)(-1), None);
+}
+
+#[test]
+fn kleisli_vec_is_a_profunctor_and_choice() {
+    let digits: Kleisli<VecKind, u32, u32> = Kleisli::new(|n: u32| {
+        n.to_string()
+            .chars()
+            .filter_map(|c| c.to_digit(10))
+            .collect()
+    });
+    let shown = digits
+        .clone()
+        .dimap(|s: &str| s.len() as u32 * 7, |d: u32| d * 2);
+    assert_eq!(shown.run("abc"), vec![4, 2]);
+    assert_eq!(digits.clone().right::<()>().run(Ok(12)), vec![Ok(1), Ok(2)]);
+    assert_eq!(digits.right::<char>().run(Err('x')), vec![Err('x')]);
+}
+
+#[test]
+fn kleisli_kind_passes_the_input_to_each_step() {
+    let half: ByEnv<i32> = Kleisli::new(|env: Env| (env % 2 == 0).then_some(env / 2));
+    let described = ByEnvKind::bind(half, |h: i32| {
+        Kleisli::new(move |env: Env| Some(format!("{env}/2={h}")))
+    });
+    assert_eq!(described.run(8), Some("8/2=4".to_string()));
+    assert_eq!(described.run(7), None);
+
+    let added = ByEnvKind::apply(
+        ByEnvKind::pure(10),
+        Kleisli::new(|env: Env| Some(CFn::new(move |x: i32| x + env))),
+    );
+    assert_eq!(added.run(5), Some(15));
+}
+
+mod kleisli_kind_functor_laws {
+    use super::*;
+
+    #[test]
+    fn identity() {
+        let fa: ByEnv<i32> = Kleisli::new(|env: Env| env.checked_mul(1000));
+        assert_same(&ByEnvKind::map(fa.clone(), |x: i32| x), &fa);
+    }
+
+    #[test]
+    fn composition() {
+        let fa: ByEnv<i32> = Kleisli::new(|env: Env| (env >= 0).then_some(env));
+        let f = |x: i32| x * 2;
+        let g = |x: i32| x.to_string();
+        let fused = ByEnvKind::map(fa.clone(), move |x| g(f(x)));
+        let stepwise = ByEnvKind::map(ByEnvKind::map(fa, f), g);
+        assert_same(&fused, &stepwise);
+    }
+}
+
+mod kleisli_kind_monad_laws {
+    use super::*;
+
+    fn f(x: i32) -> ByEnv<i32> {
+        Kleisli::new(move |env: Env| x.checked_sub(env).filter(|d| *d != 0))
+    }
+
+    fn g(x: i32) -> ByEnv<String> {
+        Kleisli::new(move |env: Env| Some(format!("{x}@{env}")))
+    }
+
+    #[test]
+    fn left_identity() {
+        assert_same(&ByEnvKind::bind(ByEnvKind::pure(4), f), &f(4));
+    }
+
+    #[test]
+    fn right_identity() {
+        let m: ByEnv<i32> = Kleisli::new(|env: Env| (env != 0).then_some(env));
+        assert_same(&ByEnvKind::bind(m.clone(), ByEnvKind::pure), &m);
+    }
+
+    #[test]
+    fn associativity() {
+        let m = || -> ByEnv<i32> { Kleisli::new(|env: Env| Some(env + 1)) };
+        let lhs = ByEnvKind::bind(ByEnvKind::bind(m(), f), g);
+        let rhs = ByEnvKind::bind(m(), |x| ByEnvKind::bind(f(x), g));
+        assert_same(&lhs, &rhs);
+    }
+
+    #[test]
+    fn join_passes_the_input_twice() {
+        let nested: ByEnv<ByEnv<(i32, i32)>> =
+            Kleisli::new(|outer: Env| Some(Kleisli::new(move |inner: Env| Some((outer, inner)))));
+        assert_eq!(ByEnvKind::join(nested).run(3), Some((3, 3)));
+    }
+}
+
+mod fish_laws {
+    use super::*;
+
+    fn pure_arrow() -> Checked<i32, i32> {
+        Kleisli::new(Ok)
+    }
+
+    #[test]
+    fn identity() {
+        for s in ["5", "0", "x"] {
+            assert_eq!((parse() >> pure_arrow()).run(s), parse().run(s));
+        }
+        for n in [0, 3] {
+            assert_eq!((pure_arrow() >> non_zero()).run(n), non_zero().run(n));
+        }
+    }
+
+    #[test]
+    fn associativity() {
+        for s in ["4", "0", "x"] {
+            assert_eq!(
+                ((parse() >> non_zero()) >> reciprocal()).run(s),
+                (parse() >> (non_zero() >> reciprocal())).run(s)
+            );
+        }
+    }
+}
@@ -1,6 +1,17 @@
 pub mod applicative;
+pub mod arc_fn;
+pub mod cfn_mut;
+pub mod comonad;
+pub mod compose;
+pub mod constant;
+pub mod contravariant;
+pub mod ext;
 pub mod functor;
 pub mod identity;
 pub mod kind;
+pub mod kleisli;
 pub mod monad;
+pub mod natural;
+pub mod rc_fn;
 pub mod transformers;
+pub mod traversable;
@@ -0,0 +1,166 @@
+use monadify::compose::ComposeKind;
+use monadify::constant::{Const, ConstKind};
+use monadify::function::CFn;
+use monadify::functor::kind::Functor;
+use monadify::identity::{Identity, IdentityKind};
+use monadify::kind_based::kind::{CFnKind, OptionKind, ResultKind, VecKind};
+use monadify::natural::{
+    Composed, IdentityToApplicative, NaturalTransformation, OptionToVec, ResultToOption,
+};
+use monadify::transformers::reader::{ReaderT, ReaderTKind};
+
+type TestResult<T> = Result<T, String>;
+
+#[test]
+fn option_to_vec_transform() {
+    assert_eq!(OptionToVec::transform(Some(1)), vec![1]);
+    assert_eq!(OptionToVec::transform(None::<i32>), Vec::<i32>::new());
+}
+
+#[test]
+fn result_to_option_transform() {
+    let ok: TestResult<i32> = Ok(5);
+    let err: TestResult<i32> = Err("bad".to_string());
+    assert_eq!(ResultToOption::transform(ok), Some(5));
+    assert_eq!(ResultToOption::transform(err), None);
+}
+
+#[test]
+fn identity_to_applicative_transform() {
+    let to_option: Option<i32> = <IdentityToApplicative as NaturalTransformation<
+        IdentityKind,
+        OptionKind,
+    >>::transform(Identity(4));
+    assert_eq!(to_option, Some(4));
+
+    let to_vec: Vec<String> = <IdentityToApplicative as NaturalTransformation<
+        IdentityKind,
+        VecKind,
+    >>::transform(Identity("x".to_string()));
+    assert_eq!(to_vec, vec!["x".to_string()]);
+
+    let to_result: TestResult<i32> = <IdentityToApplicative as NaturalTransformation<
+        IdentityKind,
+        ResultKind<String>,
+    >>::transform(Identity(9));
+    assert_eq!(to_result, Ok(9));
+
+    let to_nested: Vec<Option<i32>> = <IdentityToApplicative as NaturalTransformation<
+        IdentityKind,
+        ComposeKind<VecKind, OptionKind>,
+    >>::transform(Identity(2));
+    assert_eq!(to_nested, vec![Some(2)]);
+
+    let to_const: Const<String, i32> = <IdentityToApplicative as NaturalTransformation<
+        IdentityKind,
+        ConstKind<String>,
+    >>::transform(Identity(5));
+    assert_eq!(to_const.0, String::new());
+}
+
+#[test]
+fn identity_to_applicative_lift_reaches_cloning_applicatives() {
+    let to_fn: CFn<i32, String> =
+        IdentityToApplicative::lift::<CFnKind<i32>, _>(Identity("k".to_string()));
+    assert_eq!(to_fn.call(1), "k");
+    assert_eq!(to_fn.call(2), "k");
+
+    let to_reader: ReaderT<i32, OptionKind, i32> =
+        IdentityToApplicative::lift::<ReaderTKind<i32, OptionKind>, _>(Identity(8));
+    assert_eq!((to_reader.run_reader_t)(0), Some(8));
+
+    let to_option: Option<i32> = IdentityToApplicative::lift::<OptionKind, _>(Identity(3));
+    assert_eq!(
+        to_option,
+        <IdentityToApplicative as NaturalTransformation<IdentityKind, OptionKind>>::transform(
+            Identity(3)
+        )
+    );
+}
+
+// The element type is chosen per call, so a transformation taken as a parameter can be
+// used at several element types.
+fn transform_both<N: NaturalTransformation<OptionKind, VecKind>>(
+    n: Option<i32>,
+    s: Option<String>,
+) -> (Vec<i32>, Vec<String>) {
+    (N::transform(n), N::transform(s))
+}
+
+#[test]
+fn transformation_is_polymorphic_in_the_element() {
+    assert_eq!(
+        transform_both::<OptionToVec>(Some(1), Some("a".to_string())),
+        (vec![1], vec!["a".to_string()])
+    );
+    type ResultToVec = Composed<ResultToOption, OptionToVec, OptionKind>;
+    let ok: TestResult<i32> = Ok(3);
+    let words: TestResult<&str> = Ok("w");
+    assert_eq!(
+        <ResultToVec as NaturalTransformation<ResultKind<String>, VecKind>>::transform(ok),
+        vec![3]
+    );
+    assert_eq!(
+        <ResultToVec as NaturalTransformation<ResultKind<String>, VecKind>>::transform(words),
+        vec!["w"]
+    );
+}
+
+#[test]
+fn composed_transformation() {
+    type ResultToVec = Composed<ResultToOption, OptionToVec, OptionKind>;
+    let ok: TestResult<i32> = Ok(3);
+    let err: TestResult<i32> = Err("e".to_string());
+    assert_eq!(
+        <ResultToVec as NaturalTransformation<ResultKind<String>, VecKind>>::transform(ok),
+        vec![3]
+    );
+    assert_eq!(
+        <ResultToVec as NaturalTransformation<ResultKind<String>, VecKind>>::transform(err),
+        Vec::<i32>::new()
+    );
+
+    type IdentityToVec = Composed<IdentityToApplicative, OptionToVec, OptionKind>;
+    assert_eq!(
+        <IdentityToVec as NaturalTransformation<IdentityKind, VecKind>>::transform(Identity(1)),
+        vec![1]
+    );
+}
+
+// Naturality: N::transform(F::map(fa, f)) == G::map(N::transform(fa), f)
+#[test]
+fn option_to_vec_naturality() {
+    let f = |x: i32| x.to_string();
+    for fa in [Some(10), None] {
+        let lhs = OptionToVec::transform(OptionKind::map(fa, f));
+        let rhs = VecKind::map(OptionToVec::transform(fa), f);
+        assert_eq!(lhs, rhs);
+    }
+}
+
+#[test]
+fn result_to_option_naturality() {
+    let f = |x: i32| x * 2;
+    let inputs: Vec<TestResult<i32>> = vec![Ok(4), Err("e".to_string())];
+    for fa in inputs {
+        let lhs = ResultToOption::transform(ResultKind::<String>::map(fa.clone(), f));
+        let rhs = OptionKind::map(ResultToOption::transform(fa), f);
+        assert_eq!(lhs, rhs);
+    }
+}
+
+#[test]
+fn identity_to_applicative_naturality() {
+    let f = |x: i32| x + 1;
+    let fa = Identity(41);
+    let lhs: Option<i32> = <IdentityToApplicative as NaturalTransformation<
+        IdentityKind,
+        OptionKind,
+    >>::transform(IdentityKind::map(fa.clone(), f));
+    let rhs: Option<i32> = OptionKind::map(
+        <IdentityToApplicative as NaturalTransformation<IdentityKind, OptionKind>>::transform(fa),
+        f,
+    );
+    assert_eq!(lhs, rhs);
+    assert_eq!(lhs, Some(42));
+}
@@ -0,0 +1,157 @@
+use monadify::applicative::kind::Applicative;
+use monadify::apply::kind::Apply;
+use monadify::function::{CFn, RcFn};
+use monadify::functor::kind::Functor;
+use monadify::kind_based::kind::RcFnKind;
+use monadify::monad::kind::{Bind, Monad};
+use monadify::{Choice, Profunctor, Strong};
+
+type Env = i32;
+
+// `RcFn`s can't be compared directly, so compare them on a few inputs.
+fn assert_same<B: PartialEq + std::fmt::Debug>(f: &RcFn<Env, B>, g: &RcFn<Env, B>) {
+    for env in [-3, 0, 7] {
+        assert_eq!(f.call(env), g.call(env));
+    }
+}
+
+#[test]
+fn rc_fn_clones_share_the_closure() {
+    let square = RcFn::new(|x: i32| x * x);
+    let copies = [square.clone(), square.clone(), square];
+    let results: Vec<i32> = copies.iter().map(|f| f.call(3)).collect();
+    assert_eq!(results, vec![9, 9, 9]);
+}
+
+#[test]
+fn rc_fn_composition_operators() {
+    let add_one = RcFn::new(|x: i32| x + 1);
+    let show = RcFn::new(|x: i32| x.to_string());
+    assert_eq!((add_one.clone() >> show.clone()).call(4), "5");
+    assert_eq!((show << add_one).call(9), "10");
+}
+
+#[test]
+fn rc_fn_converts_to_and_from_cfn() {
+    let from_cfn: RcFn<i32, i32> = CFn::new(|x: i32| x - 1).into();
+    assert_eq!(from_cfn.clone().call(1), 0);
+    let back: CFn<i32, i32> = from_cfn.into();
+    assert_eq!(back.call(10), 9);
+}
+
+#[test]
+fn rc_fn_profunctor_strong_choice() {
+    let double = || RcFn::new(|x: i32| x * 2);
+    let mapped = double().dimap(|s: &str| s.len() as i32, |x: i32| format!("<{x}>"));
+    assert_eq!(mapped.call("abc"), "<6>");
+    assert_eq!(double().first::<char>().call((2, 'a')), (4, 'a'));
+    assert_eq!(double().second::<char>().call(('a', 2)), ('a', 4));
+    assert_eq!(double().left::<()>().call(Err(5)), Err(10));
+    assert_eq!(double().left::<()>().call(Ok(())), Ok(()));
+    assert_eq!(double().right::<()>().call(Ok(5)), Ok(10));
+}
+
+#[test]
+fn rc_fn_kind_reads_a_shared_environment() {
+    // `bind` passes the same environment to both steps, like a Reader.
+    let base = RcFn::new(|env: Env| env * 10);
+    let offset = RcFnKind::<Env>::bind(base, |x: i32| RcFn::new(move |env: Env| x + env));
+    assert_eq!(offset.call(2), 22);
+}
+
+mod rc_fn_kind_functor_laws {
+    use super::*;
+
+    #[test]
+    fn identity() {
+        let fa = RcFn::new(|env: Env| env + 1);
+        let mapped = RcFnKind::<Env>::map(fa.clone(), |x: i32| x);
+        assert_same(&mapped, &fa);
+    }
+
+    #[test]
+    fn composition() {
+        let fa = RcFn::new(|env: Env| env * 3);
+        let f = |x: i32| x - 2;
+        let g = |x: i32| x.to_string();
+        let fused = RcFnKind::<Env>::map(fa.clone(), move |x| g(f(x)));
+        let stepwise = RcFnKind::<Env>::map(RcFnKind::<Env>::map(fa, f), g);
+        assert_same(&fused, &stepwise);
+    }
+}
+
+mod rc_fn_kind_applicative_laws {
+    use super::*;
+
+    #[test]
+    fn identity() {
+        let v = RcFn::new(|env: Env| env - 4);
+        let id: RcFn<Env, CFn<i32, i32>> = RcFn::new(|_| CFn::new(|x: i32| x));
+        assert_same(&RcFnKind::<Env>::apply(v.clone(), id), &v);
+    }
+
+    #[test]
+    fn homomorphism() {
+        // pure(f) <*> pure(x) == pure(f(x)); `pure` needs a `Clone` value, so the
+        // function is lifted through `map` over `pure(())`.
+        let f = |x: i32| x * 7;
+        let pure_f = RcFnKind::<Env>::map(RcFnKind::<Env>::pure(()), move |_| CFn::new(f));
+        let applied = RcFnKind::<Env>::apply(RcFnKind::<Env>::pure(6), pure_f);
+        assert_same(&applied, &RcFnKind::<Env>::pure(f(6)));
+    }
+
+    #[test]
+    fn interchange() {
+        // u <*> pure(y) == pure(|f| f(y)) <*> u, with u an environment-dependent function.
+        let y = 5;
+        let u = || RcFn::new(|env: Env| CFn::new(move |x: i32| x + env));
+        let lhs = RcFnKind::<Env>::apply(RcFnKind::<Env>::pure(y), u());
+        let rhs = RcFnKind::<Env>::apply(
+            u(),
+            RcFnKind::<Env>::map(RcFnKind::<Env>::pure(()), move |_| {
+                CFn::new(move |f: CFn<i32, i32>| f.call(y))
+            }),
+        );
+        assert_same(&lhs, &rhs);
+    }
+}
+
+mod rc_fn_kind_monad_laws {
+    use super::*;
+
+    fn f(x: i32) -> RcFn<Env, i32> {
+        RcFn::new(move |env: Env| x * env)
+    }
+
+    fn g(x: i32) -> RcFn<Env, String> {
+        RcFn::new(move |env: Env| format!("{x}@{env}"))
+    }
+
+    #[test]
+    fn left_identity() {
+        let bound = RcFnKind::<Env>::bind(RcFnKind::<Env>::pure(4), f);
+        assert_same(&bound, &f(4));
+    }
+
+    #[test]
+    fn right_identity() {
+        let m = RcFn::new(|env: Env| env + 100);
+        let bound = RcFnKind::<Env>::bind(m.clone(), RcFnKind::<Env>::pure);
+        assert_same(&bound, &m);
+    }
+
+    #[test]
+    fn associativity() {
+        let m = || RcFn::new(|env: Env| env - 1);
+        let lhs = RcFnKind::<Env>::bind(RcFnKind::<Env>::bind(m(), f), g);
+        let rhs = RcFnKind::<Env>::bind(m(), |x| RcFnKind::<Env>::bind(f(x), g));
+        assert_same(&lhs, &rhs);
+    }
+
+    #[test]
+    fn join_passes_the_environment_twice() {
+        let nested = RcFn::new(|outer: Env| RcFn::new(move |inner: Env| (outer, inner)));
+        let joined = RcFnKind::<Env>::join(nested);
+        assert_eq!(joined.call(3), (3, 3));
+    }
+}
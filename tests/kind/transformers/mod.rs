@@ -1,2 +1,4 @@
 #[cfg(not(feature = "legacy"))]
 pub mod reader;
+#[cfg(not(feature = "legacy"))]
+pub mod send_reader;
@@ -0,0 +1,154 @@
+use monadify::function::ArcFn;
+use monadify::identity::{Identity, IdentityKind};
+use monadify::kind_based::kind::{OptionKind, ResultKind};
+use monadify::send::{SendApplicative, SendApply, SendBind, SendFunctor, SendMonad};
+use monadify::transformers::send_reader::{SendReader, SendReaderT, SendReaderTKind};
+use monadify::ReaderT;
+use std::thread;
+
+#[derive(Clone, Debug, PartialEq)]
+struct Config {
+    rate: u32,
+    label: &'static str,
+}
+
+type ConfigReader<A> = SendReaderT<Config, ResultKind<String>, A>;
+type ConfigReaderKind = SendReaderTKind<Config, ResultKind<String>>;
+type Plain<A> = SendReader<i32, A>;
+type PlainKind = SendReaderTKind<i32, IdentityKind>;
+
+// Builds a pipeline that reads the rate, rejects zero, and labels the result.
+fn price(amount: u32) -> ConfigReader<String> {
+    let rate = ConfigReaderKind::map(SendReaderT::ask(), |c: Config| c.rate);
+    let total = ConfigReaderKind::bind(rate, move |rate: u32| {
+        SendReaderT::new(move |_| {
+            if rate == 0 {
+                Err("no rate".to_string())
+            } else {
+                Ok(amount * rate)
+            }
+        })
+    });
+    ConfigReaderKind::bind(total, |t: u32| {
+        SendReaderT::new(move |c: Config| Ok(format!("{}: {t}", c.label)))
+    })
+}
+
+#[test]
+fn pipeline_built_on_one_thread_runs_on_scoped_workers() {
+    let pipeline = price(10);
+    let configs = [
+        Config {
+            rate: 2,
+            label: "a",
+        },
+        Config {
+            rate: 0,
+            label: "b",
+        },
+        Config {
+            rate: 5,
+            label: "c",
+        },
+    ];
+    let results: Vec<Result<String, String>> = thread::scope(|s| {
+        let handles: Vec<_> = configs
+            .iter()
+            .map(|c| s.spawn(|| pipeline.run(c.clone())))
+            .collect();
+        handles.into_iter().map(|h| h.join().unwrap()).collect()
+    });
+    assert_eq!(
+        results,
+        vec![
+            Ok("a: 20".to_string()),
+            Err("no rate".to_string()),
+            Ok("c: 50".to_string())
+        ]
+    );
+}
+
+#[test]
+fn pipeline_can_be_moved_to_a_spawned_thread() {
+    let pipeline = price(3);
+    let handle = thread::spawn(move || {
+        pipeline.run(Config {
+            rate: 4,
+            label: "moved",
+        })
+    });
+    assert_eq!(handle.join().unwrap(), Ok("moved: 12".to_string()));
+}
+
+#[test]
+fn local_and_apply() {
+    let doubled_rate = price(1).local(|c: Config| Config {
+        rate: c.rate * 2,
+        ..c
+    });
+    assert_eq!(
+        doubled_rate.run(Config {
+            rate: 3,
+            label: "x"
+        }),
+        Ok("x: 6".to_string())
+    );
+
+    let scaled: SendReaderT<i32, OptionKind, i32> = SendReaderTKind::apply(
+        SendReaderTKind::pure(7),
+        SendReaderT::new(|env: i32| Some(ArcFn::new(move |x: i32| x * env))),
+    );
+    assert_eq!(scaled.run(3), Some(21));
+}
+
+#[test]
+fn converts_into_reader_t() {
+    let reader: ReaderT<i32, IdentityKind, i32> =
+        PlainKind::map(SendReaderT::ask(), |env: i32| env + 1).into();
+    assert_eq!((reader.run_reader_t)(4), Identity(5));
+}
+
+mod send_reader_monad_laws {
+    use super::*;
+
+    fn f(x: i32) -> Plain<i32> {
+        SendReaderT::new(move |env: i32| Identity(x * env))
+    }
+
+    fn g(x: i32) -> Plain<String> {
+        SendReaderT::new(move |env: i32| Identity(format!("{x}@{env}")))
+    }
+
+    fn assert_same<A: PartialEq + std::fmt::Debug>(r: &Plain<A>, s: &Plain<A>) {
+        for env in [-2, 0, 9] {
+            assert_eq!(r.run(env), s.run(env));
+        }
+    }
+
+    #[test]
+    fn left_identity() {
+        assert_same(&PlainKind::bind(PlainKind::pure(4), f), &f(4));
+    }
+
+    #[test]
+    fn right_identity() {
+        let m: Plain<i32> = SendReaderT::new(|env: i32| Identity(env + 100));
+        assert_same(&PlainKind::bind(m.clone(), PlainKind::pure), &m);
+    }
+
+    #[test]
+    fn associativity() {
+        let m = || -> Plain<i32> { SendReaderT::new(|env: i32| Identity(env - 1)) };
+        let lhs = PlainKind::bind(PlainKind::bind(m(), f), g);
+        let rhs = PlainKind::bind(m(), |x| PlainKind::bind(f(x), g));
+        assert_same(&lhs, &rhs);
+    }
+
+    #[test]
+    fn join_passes_the_environment_twice() {
+        let nested: Plain<Plain<(i32, i32)>> = SendReaderT::new(|outer: i32| {
+            Identity(SendReaderT::new(move |inner: i32| Identity((outer, inner))))
+        });
+        assert_eq!(PlainKind::join(nested).run(3), Identity((3, 3)));
+    }
+}
@@ -0,0 +1,205 @@
+use monadify::compose::ComposeKind;
+use monadify::functor::kind::Functor;
+use monadify::identity::{Identity, IdentityKind};
+use monadify::kind_based::kind::{OptionKind, ResultKind, VecKind};
+use monadify::monoid::Sum;
+use monadify::natural::{NaturalTransformation, OptionToVec};
+use monadify::traversable::{fold_map_default, map_default, Traversable};
+use std::cell::Cell;
+
+type TestResult<T> = Result<T, String>;
+
+fn parse(s: &str) -> Option<i32> {
+    s.parse().ok()
+}
+
+#[test]
+fn vec_kind_traverse_option() {
+    let all_good = <VecKind as Traversable<OptionKind, &str, i32>>::traverse(vec!["1", "2"], parse);
+    assert_eq!(all_good, Some(vec![1, 2]));
+
+    let one_bad = <VecKind as Traversable<OptionKind, &str, i32>>::traverse(vec!["1", "x"], parse);
+    assert_eq!(one_bad, None);
+
+    let empty = <VecKind as Traversable<OptionKind, &str, i32>>::traverse(vec![], parse);
+    assert_eq!(empty, Some(vec![]));
+}
+
+#[test]
+fn vec_kind_traverse_result_short_circuits() {
+    let check = |x: i32| -> TestResult<i32> {
+        if x > 0 {
+            Ok(x)
+        } else {
+            Err(format!("bad {x}"))
+        }
+    };
+    assert_eq!(
+        <VecKind as Traversable<ResultKind<String>, i32, i32>>::traverse(vec![1, 2, 3], check),
+        Ok(vec![1, 2, 3])
+    );
+    assert_eq!(
+        <VecKind as Traversable<ResultKind<String>, i32, i32>>::traverse(vec![1, -2, 3], check),
+        Err("bad -2".to_string())
+    );
+}
+
+#[test]
+fn vec_kind_traverse_vec_is_cartesian_product() {
+    let choices = |x: i32| vec![x, x * 10];
+    assert_eq!(
+        <VecKind as Traversable<VecKind, i32, i32>>::traverse(vec![1, 2], choices),
+        vec![vec![1, 2], vec![1, 20], vec![10, 2], vec![10, 20]]
+    );
+}
+
+#[test]
+fn vec_kind_traverse_copies_each_element_once() {
+    thread_local! {
+        static CLONES: Cell<usize> = const { Cell::new(0) };
+    }
+    #[derive(Debug, PartialEq)]
+    struct Counted(i32);
+    impl Clone for Counted {
+        fn clone(&self) -> Self {
+            CLONES.with(|c| c.set(c.get() + 1));
+            Counted(self.0)
+        }
+    }
+
+    let counted = <VecKind as Traversable<OptionKind, i32, Counted>>::traverse(
+        (0..100).collect(),
+        |x: i32| Some(Counted(x)),
+    );
+    assert_eq!(counted.map(|v| v.len()), Some(100));
+    // The results so far are moved through every step, not copied.
+    assert_eq!(CLONES.with(Cell::get), 100);
+}
+
+#[test]
+fn option_and_result_kind_traverse() {
+    assert_eq!(
+        <OptionKind as Traversable<VecKind, i32, i32>>::traverse(Some(2), |x: i32| vec![x, x]),
+        vec![Some(2), Some(2)]
+    );
+    assert_eq!(
+        <OptionKind as Traversable<VecKind, i32, i32>>::traverse(None, |x: i32| vec![x, x]),
+        vec![None]
+    );
+
+    let err: TestResult<&str> = Err("e".to_string());
+    assert_eq!(
+        <ResultKind<String> as Traversable<OptionKind, &str, i32>>::traverse(Ok("4"), parse),
+        Some(Ok(4))
+    );
+    assert_eq!(
+        <ResultKind<String> as Traversable<OptionKind, &str, i32>>::traverse(err, parse),
+        Some(Err("e".to_string()))
+    );
+}
+
+#[test]
+fn identity_kind_traverse() {
+    assert_eq!(
+        <IdentityKind as Traversable<OptionKind, &str, i32>>::traverse(Identity("7"), parse),
+        Some(Identity(7))
+    );
+}
+
+#[test]
+fn traversable_law_identity() {
+    // traverse(x, Identity) == Identity(x)
+    let xs = vec![1, 2, 3];
+    assert_eq!(
+        <VecKind as Traversable<IdentityKind, i32, i32>>::traverse(xs.clone(), Identity),
+        Identity(xs)
+    );
+    assert_eq!(
+        <OptionKind as Traversable<IdentityKind, i32, i32>>::traverse(Some(1), Identity),
+        Identity(Some(1))
+    );
+}
+
+#[test]
+fn traversable_law_naturality() {
+    // n(traverse(x, f)) == traverse(x, n . f) for n = OptionToVec
+    let f = |x: i32| if x > 0 { Some(x) } else { None };
+    for xs in [vec![1, 2], vec![1, -1]] {
+        let lhs = OptionToVec::transform(<VecKind as Traversable<OptionKind, i32, i32>>::traverse(
+            xs.clone(),
+            f,
+        ));
+        let rhs = <VecKind as Traversable<VecKind, i32, i32>>::traverse(xs, move |x| {
+            OptionToVec::transform(f(x))
+        });
+        assert_eq!(lhs, rhs);
+    }
+}
+
+#[test]
+fn traversable_law_composition() {
+    // traverse(x, Compose . map(g) . f) == Compose(map(traverse(_, g), traverse(x, f)))
+    type OptionResult = ComposeKind<OptionKind, ResultKind<String>>;
+    let f = |x: i32| if x != 0 { Some(x) } else { None };
+    let g = |x: i32| -> TestResult<i32> {
+        if x > 0 {
+            Ok(x * 2)
+        } else {
+            Err("neg".to_string())
+        }
+    };
+    for xs in [vec![1, 2], vec![1, 0], vec![3, -1]] {
+        let lhs =
+            <VecKind as Traversable<OptionResult, i32, i32>>::traverse(xs.clone(), move |x| {
+                OptionKind::map(f(x), g)
+            });
+        let rhs = OptionKind::map(
+            <VecKind as Traversable<OptionKind, i32, i32>>::traverse(xs, f),
+            move |ys: Vec<i32>| {
+                <VecKind as Traversable<ResultKind<String>, i32, i32>>::traverse(ys, g)
+            },
+        );
+        assert_eq!(lhs, rhs);
+    }
+}
+
+#[test]
+fn map_default_matches_functor_map() {
+    let f = |x: i32| x * 3;
+    assert_eq!(
+        map_default::<VecKind, _, _, _>(vec![1, 2], f),
+        VecKind::map(vec![1, 2], f)
+    );
+    assert_eq!(
+        map_default::<OptionKind, _, _, _>(Some(4), f),
+        OptionKind::map(Some(4), f)
+    );
+    assert_eq!(
+        map_default::<IdentityKind, _, _, _>(Identity(5), f),
+        Identity(15)
+    );
+}
+
+#[test]
+fn fold_map_default_accumulates_left_to_right() {
+    assert_eq!(
+        fold_map_default::<VecKind, _, _, _>(vec!["a", "b", "c"], |s: &str| s.to_string()),
+        "abc"
+    );
+    assert_eq!(
+        fold_map_default::<VecKind, _, _, _>(vec![1, 2, 3, 4], Sum),
+        Sum(10)
+    );
+    assert_eq!(
+        fold_map_default::<VecKind, _, _, _>(Vec::<i32>::new(), Sum),
+        Sum(0)
+    );
+    assert_eq!(
+        fold_map_default::<OptionKind, _, _, _>(Some(3), |x: i32| vec![x; 2]),
+        vec![3, 3]
+    );
+    assert_eq!(
+        fold_map_default::<OptionKind, _, _, _>(None, |x: i32| vec![x]),
+        Vec::<i32>::new()
+    );
+}
@@ -0,0 +1,110 @@
+use monadify::function::CFn;
+use monadify::memo::{memo_fix, memoize, memoize_lru};
+use std::cell::RefCell;
+use std::rc::Rc;
+
+/// Wraps `f` in a `CFn` that records every argument it's actually called with.
+fn recording<A: Clone + 'static, B: 'static>(
+    f: impl Fn(A) -> B + 'static,
+) -> (CFn<A, B>, Rc<RefCell<Vec<A>>>) {
+    let calls = Rc::new(RefCell::new(Vec::new()));
+    let log = calls.clone();
+    let wrapped = CFn::new(move |a: A| {
+        log.borrow_mut().push(a.clone());
+        f(a)
+    });
+    (wrapped, calls)
+}
+
+#[test]
+fn memoize_runs_the_function_once_per_key() {
+    let (len, calls) = recording(|s: String| s.len());
+    let len = memoize(len);
+    for word in ["apple", "fig", "apple", "fig", "apple"] {
+        assert_eq!(len.call(word.to_string()), word.len());
+    }
+    assert_eq!(
+        *calls.borrow(),
+        vec!["apple".to_string(), "fig".to_string()]
+    );
+}
+
+#[test]
+fn memoized_functions_compose_like_any_cfn() {
+    let (double, calls) = recording(|x: i32| x * 2);
+    let pipeline = memoize(double) >> CFn::new(|x: i32| x + 1);
+    assert_eq!(pipeline.call(5), 11);
+    assert_eq!(pipeline.call(5), 11);
+    assert_eq!(*calls.borrow(), vec![5]);
+}
+
+#[test]
+fn each_memoized_function_has_its_own_cache() {
+    let (negate, calls) = recording(|x: i32| -x);
+    let shared = Rc::new(negate);
+    let first = memoize(CFn::new({
+        let shared = shared.clone();
+        move |x| shared.call(x)
+    }));
+    let second = memoize(CFn::new(move |x| shared.call(x)));
+    assert_eq!(first.call(1), -1);
+    assert_eq!(second.call(1), -1);
+    assert_eq!(first.call(1), -1);
+    assert_eq!(*calls.borrow(), vec![1, 1]);
+}
+
+#[test]
+fn memoize_lru_evicts_the_least_recently_used_key() {
+    let (square, calls) = recording(|x: u32| x * x);
+    let square = memoize_lru(square, 2);
+    square.call(1);
+    square.call(2);
+    square.call(1); // cached, and now more recent than 2
+    square.call(3); // evicts 2
+    square.call(1); // still cached
+    square.call(2); // recomputed, evicts 3
+    square.call(3); // recomputed
+    assert_eq!(*calls.borrow(), vec![1, 2, 3, 2, 3]);
+    assert_eq!(square.call(3), 9);
+}
+
+#[test]
+fn memoize_lru_with_zero_capacity_caches_nothing() {
+    let (square, calls) = recording(|x: u32| x * x);
+    let square = memoize_lru(square, 0);
+    assert_eq!(square.call(4), 16);
+    assert_eq!(square.call(4), 16);
+    assert_eq!(*calls.borrow(), vec![4, 4]);
+}
+
+#[test]
+fn memo_fix_computes_each_argument_once() {
+    let calls = Rc::new(RefCell::new(Vec::new()));
+    let log = calls.clone();
+    let fib = memo_fix(move |fib: &dyn Fn(u64) -> u64, n: u64| {
+        log.borrow_mut().push(n);
+        if n < 2 {
+            n
+        } else {
+            fib(n - 1) + fib(n - 2)
+        }
+    });
+    assert_eq!(fib.call(30), 832_040);
+    assert_eq!(calls.borrow().len(), 31);
+    assert_eq!(fib.call(20), 6_765);
+    assert_eq!(calls.borrow().len(), 31);
+}
+
+#[test]
+fn memo_fix_handles_several_recursive_calls() {
+    // Number of lattice paths from (0, 0) to (x, y), moving right or up.
+    let paths = memo_fix(|paths: &dyn Fn((u32, u32)) -> u64, (x, y): (u32, u32)| {
+        if x == 0 || y == 0 {
+            1
+        } else {
+            paths((x - 1, y)) + paths((x, y - 1))
+        }
+    });
+    assert_eq!(paths.call((2, 2)), 6);
+    assert_eq!(paths.call((16, 16)), 601_080_390);
+}
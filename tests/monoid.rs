@@ -0,0 +1,67 @@
+use monadify::monoid::{concat_all, First, Monoid, Semigroup, Sum};
+
+fn assert_monoid_laws<M: Monoid + Clone + PartialEq + std::fmt::Debug>(a: M, b: M, c: M) {
+    // Associativity
+    assert_eq!(
+        a.clone().combine(b.clone()).combine(c.clone()),
+        a.clone().combine(b.combine(c))
+    );
+    // Left and right identity
+    assert_eq!(M::empty().combine(a.clone()), a.clone());
+    assert_eq!(a.clone().combine(M::empty()), a);
+}
+
+#[test]
+fn string_monoid() {
+    assert_eq!("foo".to_string().combine("bar".to_string()), "foobar");
+    assert_eq!(String::empty(), "");
+    assert_monoid_laws("a".to_string(), "b".to_string(), "c".to_string());
+}
+
+#[test]
+fn vec_monoid() {
+    assert_eq!(vec![1, 2].combine(vec![3]), vec![1, 2, 3]);
+    assert_monoid_laws(vec![1], vec![2, 3], vec![]);
+}
+
+#[test]
+fn unit_monoid() {
+    assert_monoid_laws((), (), ());
+}
+
+#[test]
+fn option_monoid() {
+    assert_eq!(
+        Some("a".to_string()).combine(Some("b".to_string())),
+        Some("ab".to_string())
+    );
+    assert_eq!(None.combine(Some(vec![1])), Some(vec![1]));
+    assert_eq!(Some(vec![1]).combine(None), Some(vec![1]));
+    assert_monoid_laws(Some(Sum(1)), None, Some(Sum(3)));
+}
+
+#[test]
+fn sum_monoid() {
+    assert_eq!(Sum(2).combine(Sum(3)), Sum(5));
+    assert_eq!(Sum::<f64>::empty(), Sum(0.0));
+    assert_monoid_laws(Sum(1), Sum(-2), Sum(10));
+}
+
+#[test]
+fn option_first_keeps_first_some() {
+    assert_eq!(First(1).combine(First(2)), First(1));
+    assert_eq!(
+        concat_all(vec![None, Some(First('a')), Some(First('b'))]),
+        Some(First('a'))
+    );
+    assert_monoid_laws(Some(First(1)), None, Some(First(3)));
+}
+
+#[test]
+fn concat_all_folds_left_to_right() {
+    assert_eq!(
+        concat_all(vec!["x".to_string(), "y".to_string(), "z".to_string()]),
+        "xyz"
+    );
+    assert_eq!(concat_all(Vec::<Sum<i32>>::new()), Sum(0));
+}
@@ -0,0 +1,174 @@
+use monadify::function::CFn;
+use monadify::profunctor::{
+    _err, _ok, _some, preview, prism, re_review, review, to_list_of, view, Prism, Tagged,
+};
+use monadify::{Choice, Costrong, Profunctor};
+
+type SomeFnPrism =
+    Prism<CFn<Option<i32>, Option<i32>>, CFn<i32, i32>, Option<i32>, Option<i32>, i32, i32>;
+
+#[derive(Clone, Debug, PartialEq)]
+enum Shape {
+    Circle(f64),
+    Square(f64),
+}
+
+fn _circle<PO, PI>() -> Prism<PO, PI, Shape, Shape, f64, f64>
+where
+    PO: Choice<Shape, Shape>,
+    PI: Choice<f64, f64>,
+    <PI as Profunctor<f64, f64>>::Pro<Result<f64, Shape>, Result<f64, Shape>>:
+        Profunctor<Result<f64, Shape>, Result<f64, Shape>, Pro<Shape, Shape> = PO>,
+{
+    prism(
+        CFn::new(Shape::Circle),
+        CFn::new(|s: Shape| match s {
+            Shape::Circle(r) => Ok(r),
+            other => Err(other),
+        }),
+    )
+}
+
+#[test]
+fn preview_some() {
+    assert_eq!(preview::<_, _, _, i32>(_some().into(), Some(3)), Some(3));
+    assert_eq!(preview(_some::<_, _, i32, i32>().into(), None), None);
+}
+
+#[test]
+fn preview_ok_and_err() {
+    let ok: Result<i32, String> = Ok(1);
+    let err: Result<i32, String> = Err("bad".to_string());
+    assert_eq!(preview::<_, _, _, i32>(_ok().into(), ok.clone()), Some(1));
+    assert_eq!(preview::<_, _, _, i32>(_ok().into(), err.clone()), None);
+    assert_eq!(
+        preview::<_, _, _, String>(_err().into(), err),
+        Some("bad".to_string())
+    );
+    assert_eq!(preview::<_, _, _, String>(_err().into(), ok), None);
+}
+
+#[test]
+fn review_builds_the_focused_case() {
+    assert_eq!(review::<_, _, i32, _>(_some().into(), 5), Some(5));
+    assert_eq!(
+        review::<Result<i32, String>, _, _, _>(_ok().into(), 5),
+        Ok(5)
+    );
+    assert_eq!(
+        review::<Result<i32, String>, _, String, _>(_err().into(), "e".to_string()),
+        Err("e".to_string())
+    );
+}
+
+#[test]
+fn prism_over_with_cfn_modifies_only_the_focused_case() {
+    let incr = |p: SomeFnPrism| (p.0.optic)(CFn::new(|x: i32| x + 1));
+    assert_eq!(incr(_some()).call(Some(1)), Some(2));
+    assert_eq!(incr(_some()).call(None), None);
+}
+
+#[test]
+fn custom_prism_on_enum() {
+    assert_eq!(preview(_circle().into(), Shape::Circle(1.5)), Some(1.5));
+    assert_eq!(preview(_circle().into(), Shape::Square(2.0)), None);
+    assert_eq!(review(_circle().into(), 2.0), Shape::Circle(2.0));
+}
+
+#[test]
+fn tagged_right_wraps_in_ok() {
+    let t: Tagged<i32, i32> = Tagged::new(3);
+    assert_eq!(t.right::<()>().value, Ok(3));
+}
+
+#[test]
+fn tagged_dimap_maps_only_the_value() {
+    let t: Tagged<i32, i32> = Tagged::new(3);
+    let mapped = t.dimap(|s: &str| s.len() as i32, |x: i32| x * 2);
+    assert_eq!(mapped.value, 6);
+}
+
+#[test]
+fn tagged_unfirst_keeps_the_built_part() {
+    let built: Tagged<(i32, ()), (Shape, ())> = Tagged::new((Shape::Square(1.0), ()));
+    assert_eq!(
+        Tagged::<i32, Shape>::unfirst(built).value,
+        Shape::Square(1.0)
+    );
+}
+
+#[test]
+fn re_review_views_the_built_whole() {
+    assert_eq!(
+        view(re_review::<_, _, _, i32, _>(_some().into()), 5),
+        Some(5)
+    );
+    assert_eq!(
+        view(
+            re_review::<_, Result<i32, String>, _, _, _>(_ok().into()),
+            5
+        ),
+        Ok(5)
+    );
+    assert_eq!(view(re_review(_circle().into()), 2.5), Shape::Circle(2.5));
+    assert_eq!(
+        to_list_of(re_review(_circle().into()), 1.0),
+        vec![Shape::Circle(1.0)]
+    );
+}
+
+// --- Prism laws ---
+
+mod prism_laws {
+    use super::*;
+
+    // review-preview: previewing what was just reviewed gives back the part.
+    #[test]
+    fn review_preview_law() {
+        for b in [0, 1, -7] {
+            assert_eq!(
+                preview::<_, _, _, i32>(_some().into(), review::<_, _, i32, _>(_some().into(), b)),
+                Some(b)
+            );
+            let built: Result<i32, String> = review::<_, _, i32, _>(_ok().into(), b);
+            assert_eq!(preview::<_, _, _, i32>(_ok().into(), built), Some(b));
+        }
+        let built: Result<(), i32> = review::<_, _, i32, _>(_err().into(), 4);
+        assert_eq!(preview::<_, _, _, i32>(_err().into(), built), Some(4));
+        assert_eq!(
+            preview(_circle().into(), review(_circle().into(), 1.0)),
+            Some(1.0)
+        );
+    }
+
+    // preview-review: if a part can be previewed, reviewing it rebuilds the whole.
+    #[test]
+    fn preview_review_law() {
+        let wholes: Vec<Result<i32, String>> = vec![Ok(1), Err("x".to_string())];
+        for s in wholes {
+            if let Some(a) = preview::<_, _, _, i32>(_ok().into(), s.clone()) {
+                assert_eq!(review::<Result<i32, String>, _, _, _>(_ok().into(), a), s);
+            }
+            if let Some(e) = preview::<_, _, _, String>(_err().into(), s.clone()) {
+                assert_eq!(
+                    review::<Result<i32, String>, _, String, _>(_err().into(), e),
+                    s
+                );
+            }
+        }
+        for s in [Shape::Circle(3.0), Shape::Square(3.0)] {
+            if let Some(r) = preview(_circle().into(), s.clone()) {
+                assert_eq!(review(_circle().into(), r), s);
+            }
+        }
+    }
+
+    // Modifying through a prism with the identity function leaves the whole unchanged.
+    #[test]
+    fn identity_law() {
+        for s in [Some(2), None] {
+            let id: SomeFnPrism = _some();
+            assert_eq!((id.0.optic)(CFn::new(|x: i32| x)).call(s), s);
+        }
+    }
+}
@@ -442,3 +442,296 @@ mod choice_laws {
         assert_eq!(lhs_result_err, Err(99));
     }
 }
+
+#[cfg(test)]
+mod setter_tests {
+    use monadify::function::CFn;
+    use monadify::kind_based::kind::{OptionKind, VecKind};
+    use monadify::profunctor::{
+        _key, lens, mapped, over, set, sets, view, Check, Lens, Setter, _1, _2,
+    };
+
+    #[derive(Clone, Copy, Debug, PartialEq)]
+    struct Point {
+        x: i32,
+        y: i32,
+    }
+
+    fn x_lens() -> Lens<CFn<Point, Point>, CFn<i32, i32>, Point, Point, i32, i32> {
+        lens(
+            CFn::new(|p: Point| p.x),
+            CFn::new(|p: Point| CFn::new(move |x| Point { x, ..p })),
+        )
+    }
+
+    #[test]
+    fn set_and_over_with_tuple_lenses() {
+        assert_eq!(set(_1().into(), 10, (1, 'a')), (10, 'a'));
+        assert_eq!(set(_2().into(), 'z', (1, 'a')), (1, 'z'));
+        assert_eq!(over(_1().into(), |x: i32| x * 3, (2, "s")), (6, "s"));
+        assert_eq!(over(_2().into(), |x: i32| x - 1, ("s", 2)), ("s", 1));
+    }
+
+    #[test]
+    fn set_can_change_the_focus_type() {
+        let r: (String, bool) = set(_1().into(), "new".to_string(), (5, true));
+        assert_eq!(r, ("new".to_string(), true));
+        let r: (i32, String) = over(_2().into(), |n: usize| "x".repeat(n), (0, 3));
+        assert_eq!(r, (0, "xxx".to_string()));
+    }
+
+    #[test]
+    fn set_and_over_with_built_lenses() {
+        let p = Point { x: 1, y: 2 };
+        assert_eq!(set(x_lens().0, 7, p), Point { x: 7, y: 2 });
+        assert_eq!(over(x_lens().0, |x| x + 1, p), Point { x: 2, y: 2 });
+
+        let c = Check { key: 1, other: 9 };
+        assert_eq!(set(_key().0, 4, c), Check { key: 4, other: 9 });
+        assert_eq!(over(_key().0, |k| -k, c), Check { key: -1, other: 9 });
+    }
+
+    #[test]
+    fn mapped_setter_maps_inside_functors() {
+        assert_eq!(
+            over(mapped::<OptionKind, _, _>(), |x: i32| x + 1, Some(1)),
+            Some(2)
+        );
+        assert_eq!(
+            over(mapped::<OptionKind, _, _>(), |x: i32| x + 1, None),
+            None
+        );
+        assert_eq!(
+            set(mapped::<VecKind, i32, _>(), 'c', vec![1, 2]),
+            vec!['c', 'c']
+        );
+    }
+
+    #[test]
+    fn sets_builds_a_setter_from_a_map_function() {
+        let every_other: Setter<Vec<i32>, Vec<i32>, i32, i32> = sets(|f: CFn<i32, i32>| {
+            CFn::new(move |v: Vec<i32>| {
+                v.into_iter()
+                    .enumerate()
+                    .map(|(i, x)| if i % 2 == 0 { f.call(x) } else { x })
+                    .collect()
+            })
+        });
+        assert_eq!(
+            over(every_other, |x| x * 10, vec![1, 2, 3]),
+            vec![10, 2, 30]
+        );
+    }
+
+    // --- Lens laws, checked through view and set ---
+
+    // view(l, set(l, b, s)) == b
+    #[test]
+    fn set_view_law() {
+        let p = Point { x: 1, y: 2 };
+        assert_eq!(view(x_lens_getter(), set(x_lens().0, 5, p)), 5);
+    }
+
+    // set(l, view(l, s), s) == s
+    #[test]
+    fn view_set_law() {
+        let p = Point { x: 3, y: 4 };
+        assert_eq!(set(x_lens().0, view(x_lens_getter(), p), p), p);
+    }
+
+    // set(l, c, set(l, b, s)) == set(l, c, s)
+    #[test]
+    fn set_set_law() {
+        let p = Point { x: 3, y: 4 };
+        assert_eq!(
+            set(x_lens().0, 9, set(x_lens().0, 8, p)),
+            set(x_lens().0, 9, p)
+        );
+    }
+
+    fn x_lens_getter() -> monadify::profunctor::AGetter<Point, Point, i32, i32> {
+        lens(
+            CFn::new(|p: Point| p.x),
+            CFn::new(|p: Point| CFn::new(move |x| Point { x, ..p })),
+        )
+        .0
+    }
+}
+
+#[cfg(test)]
+mod compose_tests {
+    use monadify::function::{CFn, RcFn};
+    use monadify::profunctor::{
+        _ok, _some, iso, lens, over, preview, review, set, to_list_of, traversed, view, Forget,
+        Iso, Lens, Optic, Prism, Traversal,
+    };
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Address {
+        street: String,
+        city: String,
+    }
+
+    #[derive(Clone, Debug, PartialEq)]
+    struct Person {
+        name: String,
+        address: Address,
+    }
+
+    type StrFn = CFn<String, String>;
+    type AddressFn = CFn<Address, Address>;
+    type GridFn = CFn<Vec<Vec<i32>>, Vec<Vec<i32>>>;
+    type OptFn = CFn<Option<i32>, Option<i32>>;
+
+    fn address<PO, PI>() -> Lens<PO, PI, Person, Person, Address, Address>
+    where
+        PO: monadify::Strong<Person, Person>,
+        PI: monadify::Strong<Address, Address>,
+        <PI as monadify::Profunctor<Address, Address>>::Pro<
+            (Address, RcFn<Address, Person>),
+            (Address, RcFn<Address, Person>),
+        >: monadify::Profunctor<
+            (Address, RcFn<Address, Person>),
+            (Address, RcFn<Address, Person>),
+            Pro<Person, Person> = PO,
+        >,
+    {
+        lens(
+            CFn::new(|p: Person| p.address),
+            CFn::new(|p: Person| {
+                CFn::new(move |address| Person {
+                    address,
+                    ..p.clone()
+                })
+            }),
+        )
+    }
+
+    fn city<PO, PI>() -> Lens<PO, PI, Address, Address, String, String>
+    where
+        PO: monadify::Strong<Address, Address>,
+        PI: monadify::Strong<String, String>,
+        <PI as monadify::Profunctor<String, String>>::Pro<
+            (String, RcFn<String, Address>),
+            (String, RcFn<String, Address>),
+        >: monadify::Profunctor<
+            (String, RcFn<String, Address>),
+            (String, RcFn<String, Address>),
+            Pro<Address, Address> = PO,
+        >,
+    {
+        lens(
+            CFn::new(|a: Address| a.city),
+            CFn::new(|a: Address| CFn::new(move |city| Address { city, ..a.clone() })),
+        )
+    }
+
+    fn alice() -> Person {
+        Person {
+            name: "Alice".to_string(),
+            address: Address {
+                street: "Main St".to_string(),
+                city: "Springfield".to_string(),
+            },
+        }
+    }
+
+    #[test]
+    fn lens_works_on_non_copy_structs() {
+        assert_eq!(view(city().0, alice().address), "Springfield".to_string());
+        let moved = set(city().0, "Shelbyville".to_string(), alice().address);
+        assert_eq!(moved.city, "Shelbyville");
+        assert_eq!(moved.street, "Main St");
+    }
+
+    #[test]
+    fn optics_are_reusable() {
+        let upper: Lens<AddressFn, StrFn, _, _, _, _> = city();
+        let a = over(
+            upper.0.clone(),
+            |c: String| c.to_uppercase(),
+            alice().address,
+        );
+        let b = over(upper.0, |c: String| c + "!", a.clone());
+        assert_eq!(a.city, "SPRINGFIELD");
+        assert_eq!(b.city, "SPRINGFIELD!");
+
+        let getter: Optic<
+            Forget<String, Address, Address>,
+            Forget<String, String, String>,
+            _,
+            _,
+            _,
+            _,
+        > = city().0;
+        let f = (getter.optic)(Forget::new(|s: String| s));
+        let g = (getter.optic)(Forget::new(|s: String| s.len().to_string()));
+        assert_eq!(f.inner.call(alice().address), "Springfield");
+        assert_eq!(g.inner.call(alice().address), "11");
+    }
+
+    #[test]
+    fn lenses_compose_with_then() {
+        let person_city: Lens<CFn<Person, Person>, StrFn, _, _, _, _> = address().then(city());
+        let moved = over(person_city.0, |_| "Capital City".to_string(), alice());
+        assert_eq!(moved.address.city, "Capital City");
+        assert_eq!(moved.name, "Alice");
+
+        assert_eq!(view(address().then(city()).0, alice()), "Springfield");
+    }
+
+    #[test]
+    fn prisms_compose_with_then() {
+        let nested: Option<Result<i32, String>> = Some(Ok(3));
+        assert_eq!(
+            preview::<_, _, _, i32>(_some().then(_ok()).0, nested.clone()),
+            Some(3)
+        );
+        assert_eq!(
+            preview::<_, _, _, i32>(_some().then(_ok()).0, Some(Err::<i32, _>("e".to_string()))),
+            None
+        );
+        let built: Option<Result<i32, String>> = review::<_, _, i32, _>(_some().then(_ok()).0, 4);
+        assert_eq!(built, Some(Ok(4)));
+
+        let both: Prism<CFn<_, _>, CFn<i32, i32>, _, _, _, _> = _some().then(_ok());
+        assert_eq!(over(both.0, |x| x + 1, nested), Some(Ok::<_, String>(4)));
+    }
+
+    #[test]
+    fn isos_compose_with_then() {
+        let double: Iso<CFn<i32, i32>, CFn<i64, i64>, i32, i32, i64, i64> =
+            iso(CFn::new(|x: i32| x as i64), CFn::new(|x: i64| x as i32));
+        let neg: Iso<CFn<i64, i64>, CFn<i64, i64>, i64, i64, i64, i64> =
+            iso(CFn::new(|x: i64| -x), CFn::new(|x: i64| -x));
+        let both = double.then(neg);
+        assert_eq!(over(both.0, |x| x + 1, 5), 4);
+    }
+
+    #[test]
+    fn traversals_compose_with_then() {
+        let grid = vec![vec![1, 2], vec![3]];
+        let every: Traversal<GridFn, CFn<i32, i32>, _, _, _, _> = traversed().then(traversed());
+        assert_eq!(
+            over(every.0, |x| x * 10, grid.clone()),
+            vec![vec![10, 20], vec![30]]
+        );
+        assert_eq!(
+            to_list_of(traversed().then(traversed::<_, _, i32, i32>()).0, grid),
+            vec![1, 2, 3]
+        );
+    }
+
+    #[test]
+    fn optic_then_mixes_optic_kinds() {
+        // A lens into a field followed by a prism inside it, composed as plain optics.
+        let pair = (Some(2), "tag");
+        let first: Lens<CFn<_, _>, OptFn, _, _, _, _> =
+            monadify::profunctor::_1::<_, _, &str, _, _>();
+        let inner: Prism<OptFn, CFn<i32, i32>, _, _, _, _> = _some();
+        assert_eq!(
+            over(first.0.then(inner.0), |x| x * 5, pair),
+            (Some(10), "tag")
+        );
+    }
+}
@@ -0,0 +1,316 @@
+use monadify::comonad::{Env, EnvKind, NonEmpty, NonEmptyKind};
+use monadify::constant::{Const, ConstKind};
+use monadify::function::{CFn, RcFn};
+use monadify::identity::{Identity, IdentityKind};
+use monadify::kind_based::kind::{Kind, OptionKind, ResultKind, VecKind};
+use monadify::monoid::Sum;
+use monadify::profunctor::{_some, iso, lens, traverse_of, traversed, Costar, Lens, Star, _1};
+use monadify::transformers::reader::{ReaderT, ReaderTKind};
+use monadify::{Choice, Profunctor, Strong};
+
+#[derive(Clone, Debug, PartialEq)]
+struct Account {
+    owner: String,
+    balance: i64,
+}
+
+// A lens onto the balance, run with `Star<F, _, _>`.
+type BalanceLens<F> =
+    Lens<Star<F, Account, Account>, Star<F, i64, i64>, Account, Account, i64, i64>;
+
+fn balance<F>() -> BalanceLens<F>
+where
+    F: Kind,
+    Star<F, Account, Account>: Strong<Account, Account>,
+    Star<F, i64, i64>: Strong<i64, i64>,
+    <Star<F, i64, i64> as Profunctor<i64, i64>>::Pro<
+        (i64, RcFn<i64, Account>),
+        (i64, RcFn<i64, Account>),
+    >: Profunctor<
+        (i64, RcFn<i64, Account>),
+        (i64, RcFn<i64, Account>),
+        Pro<Account, Account> = Star<F, Account, Account>,
+    >,
+{
+    lens(
+        CFn::new(|a: Account| a.balance),
+        CFn::new(|a: Account| {
+            CFn::new(move |balance| Account {
+                balance,
+                ..a.clone()
+            })
+        }),
+    )
+}
+
+fn withdraw(amount: i64) -> impl Fn(i64) -> Result<i64, String> {
+    move |b| {
+        if b >= amount {
+            Ok(b - amount)
+        } else {
+            Err(format!("insufficient funds: {b}"))
+        }
+    }
+}
+
+#[test]
+fn traverse_of_lens_into_result() {
+    let acct = Account {
+        owner: "ann".to_string(),
+        balance: 50,
+    };
+    assert_eq!(
+        traverse_of(
+            balance::<ResultKind<String>>().0,
+            withdraw(20),
+            acct.clone()
+        ),
+        Ok(Account {
+            balance: 30,
+            ..acct.clone()
+        })
+    );
+    assert_eq!(
+        traverse_of(balance::<ResultKind<String>>().0, withdraw(80), acct),
+        Err("insufficient funds: 50".to_string())
+    );
+}
+
+#[test]
+fn traverse_of_lens_into_option_and_identity() {
+    assert_eq!(
+        traverse_of::<OptionKind, _, _, _, _>(_1().0, |x: i32| x.checked_mul(2), (21, 'a')),
+        Some((42, 'a'))
+    );
+    assert_eq!(
+        traverse_of::<OptionKind, _, _, _, _>(_1().0, |x: i32| x.checked_mul(2), (i32::MAX, 'a')),
+        None
+    );
+    assert_eq!(
+        traverse_of::<IdentityKind, _, _, _, _>(_1().0, |x: i32| Identity(x + 1), (1, "b")),
+        Identity((2, "b"))
+    );
+}
+
+#[test]
+fn traverse_of_traversal_sequences_effects() {
+    let parse = |s: &str| s.parse::<i32>().ok();
+    assert_eq!(
+        traverse_of::<OptionKind, _, _, _, _>(traversed().0, parse, vec!["1", "2"]),
+        Some(vec![1, 2])
+    );
+    assert_eq!(
+        traverse_of::<OptionKind, _, _, _, _>(traversed().0, parse, vec!["1", "x"]),
+        None
+    );
+}
+
+#[test]
+fn traverse_of_with_const_folds_the_foci() {
+    let total = traverse_of::<ConstKind<Sum<i32>>, _, _, _, i32>(
+        traversed().0,
+        |x: i32| Const::new(Sum(x)),
+        vec![1, 2, 3],
+    );
+    assert_eq!(total.get_const(), Sum(6));
+}
+
+#[test]
+fn traverse_of_prism_skips_missing_focus() {
+    assert_eq!(
+        traverse_of::<ResultKind<String>, _, _, _, _>(_some().0, |x: i32| Ok(x * 3), Some(2)),
+        Ok(Some(6))
+    );
+    assert_eq!(
+        traverse_of::<ResultKind<String>, _, _, _, i32>(
+            _some().0,
+            |x: i32| Err(format!("{x}")),
+            None
+        ),
+        Ok(None)
+    );
+}
+
+#[test]
+fn star_const_is_strong_and_choice() {
+    let count: Star<ConstKind<Sum<i32>>, i32, ()> = Star::new(|x: i32| Const::new(Sum(x)));
+    assert_eq!(
+        count.first::<&str>().run((5, "ignored")),
+        Const::new(Sum(5))
+    );
+
+    let count: Star<ConstKind<Sum<i32>>, i32, ()> = Star::new(|x: i32| Const::new(Sum(x)));
+    assert_eq!(count.right::<()>().run(Err(())), Const::new(Sum(0)));
+}
+
+#[test]
+fn traverse_of_into_vec_tries_every_result() {
+    assert_eq!(
+        traverse_of::<VecKind, _, _, _, _>(_1().0, |x: i32| vec![x, -x], (3, 'a')),
+        vec![(3, 'a'), (-3, 'a')]
+    );
+    assert_eq!(
+        traverse_of::<VecKind, _, _, _, _>(traversed().0, |x: i32| vec![x, x * 10], vec![1, 2]),
+        vec![vec![1, 2], vec![1, 20], vec![10, 2], vec![10, 20]]
+    );
+}
+
+#[test]
+fn traverse_of_lens_into_reader() {
+    let acct = Account {
+        owner: "ann".to_string(),
+        balance: 50,
+    };
+    let charged = traverse_of(
+        balance::<ReaderTKind<i64, OptionKind>>().0,
+        |b: i64| ReaderT::new(move |fee: i64| (b >= fee).then_some(b - fee)),
+        acct.clone(),
+    );
+    assert_eq!(
+        (charged.run_reader_t)(20),
+        Some(Account {
+            balance: 30,
+            ..acct.clone()
+        })
+    );
+    assert_eq!((charged.run_reader_t)(80), None);
+}
+
+#[test]
+fn traverse_of_prism_and_traversal_into_reader() {
+    let scaled = traverse_of::<ReaderTKind<i32, IdentityKind>, _, _, _, _>(
+        _some().0,
+        |x: i32| ReaderT::new(move |k: i32| Identity(x * k)),
+        None,
+    );
+    // The missing focus is handed back on every run.
+    assert_eq!((scaled.run_reader_t)(2), Identity(None));
+    assert_eq!((scaled.run_reader_t)(3), Identity(None));
+
+    let divided = traverse_of::<ReaderTKind<i32, OptionKind>, _, _, _, _>(
+        traversed().0,
+        |x: i32| ReaderT::new(move |d: i32| x.checked_div(d)),
+        vec![4, 8],
+    );
+    assert_eq!((divided.run_reader_t)(2), Some(vec![2, 4]));
+    assert_eq!((divided.run_reader_t)(0), None);
+}
+
+#[test]
+fn traverse_of_iso_into_reader() {
+    let scaled = traverse_of::<ReaderTKind<i64, OptionKind>, _, _, _, _>(
+        iso(
+            CFn::new(|cents: (i64,)| cents.0),
+            CFn::new(|amount: i64| (amount,)),
+        )
+        .0,
+        |amount: i64| ReaderT::new(move |rate: i64| amount.checked_mul(rate)),
+        (25,),
+    );
+    assert_eq!((scaled.run_reader_t)(4), Some((100,)));
+    assert_eq!((scaled.run_reader_t)(i64::MAX), None);
+}
+
+#[test]
+fn star_reader_dimap_maps_every_run() {
+    let lookup: Star<ReaderTKind<Vec<i32>, VecKind>, usize, i32> =
+        Star::new(|n: usize| ReaderT::new(move |env: Vec<i32>| env.into_iter().take(n).collect()));
+    let shown = lookup.dimap(|s: &str| s.len(), |x: i32| x.to_string());
+    let reader = shown.run("ab");
+    assert_eq!((reader.run_reader_t)(vec![7, 8, 9]), vec!["7", "8"]);
+    assert_eq!((reader.run_reader_t)(vec![1]), vec!["1"]);
+}
+
+#[test]
+fn costar_dimap_maps_every_element() {
+    let total: Costar<VecKind, i32, i32> = Costar::new(|xs: Vec<i32>| xs.into_iter().sum());
+    let mapped = total.dimap(|s: &str| s.len() as i32, |n: i32| n * 10);
+    assert_eq!(mapped.run(vec!["ab", "cde"]), 50);
+
+    let first: Costar<OptionKind, i32, String> =
+        Costar::new(|x: Option<i32>| x.map_or("none".to_string(), |x| x.to_string()));
+    assert_eq!(
+        first.dimap(|x: u8| x as i32, |s| s + "!").run(Some(7)),
+        "7!"
+    );
+
+    let longest: Costar<NonEmptyKind, String, usize> =
+        Costar::new(|xs: NonEmpty<String>| xs.into_vec().iter().map(String::len).max().unwrap());
+    assert_eq!(
+        longest
+            .dimap(|n: usize| "x".repeat(n), |l| l)
+            .run(NonEmpty::new(2, vec![5, 1])),
+        5
+    );
+}
+
+#[test]
+fn costar_identity_is_strong_and_choice() {
+    let double = || Costar::<IdentityKind, i32, i32>::new(|Identity(x)| x * 2);
+    assert_eq!(double().first::<char>().run(Identity((4, 'c'))), (8, 'c'));
+    assert_eq!(double().second::<char>().run(Identity(('c', 4))), ('c', 8));
+    assert_eq!(double().left::<&str>().run(Identity(Err(3))), Err(6));
+    assert_eq!(double().left::<&str>().run(Identity(Ok("x"))), Ok("x"));
+    assert_eq!(double().right::<&str>().run(Identity(Ok(3))), Ok(6));
+}
+
+#[test]
+fn costar_env_is_strong_and_choice() {
+    let scale = || Costar::<EnvKind<i32>, i32, i32>::new(|w: Env<i32, i32>| w.env * w.value);
+    assert_eq!(
+        scale().first::<char>().run(Env::new(3, (4, 'c'))),
+        (12, 'c')
+    );
+    assert_eq!(
+        scale().second::<char>().run(Env::new(3, ('c', 4))),
+        ('c', 12)
+    );
+    assert_eq!(scale().left::<&str>().run(Env::new(2, Err(5))), Err(10));
+    assert_eq!(scale().right::<&str>().run(Env::new(2, Err("x"))), Err("x"));
+}
+
+#[test]
+fn costar_non_empty_is_strong_and_choice() {
+    let total =
+        || Costar::<NonEmptyKind, i32, i32>::new(|w: NonEmpty<i32>| w.into_vec().iter().sum());
+    // The head's tag is kept; the others are dropped.
+    let pairs = NonEmpty::new((1, 'a'), vec![(2, 'b'), (3, 'c')]);
+    assert_eq!(total().first::<char>().run(pairs), (6, 'a'));
+    let pairs = NonEmpty::new(('z', 4), vec![('y', 5)]);
+    assert_eq!(total().second::<char>().run(pairs), ('z', 9));
+
+    // Any element on the other side is returned; otherwise all of them are summed.
+    let mixed = NonEmpty::new(Ok(10), vec![Err("skip"), Ok(1)]);
+    assert_eq!(total().right::<&str>().run(mixed), Err("skip"));
+    let focused = NonEmpty::new(Ok(10), vec![Ok(2), Ok(1)]);
+    assert_eq!(total().right::<&str>().run(focused), Ok(13));
+    let mixed = NonEmpty::new(Ok("stop"), vec![Err(1)]);
+    assert_eq!(total().left::<&str>().run(mixed), Ok("stop"));
+    let mixed = NonEmpty::new(Err(2), vec![Ok("skip"), Err(3)]);
+    assert_eq!(total().left::<&str>().run(mixed), Ok("skip"));
+    let focused = NonEmpty::new(Err(2), vec![Err(3)]);
+    assert_eq!(total().left::<&str>().run(focused), Err(5));
+}
+
+mod costar_laws {
+    use super::*;
+
+    #[test]
+    fn dimap_identity() {
+        let len: Costar<VecKind, char, usize> = Costar::new(|xs: Vec<char>| xs.len());
+        let same = len.dimap(|c: char| c, |n: usize| n);
+        assert_eq!(same.run(vec!['a', 'b']), 2);
+    }
+
+    #[test]
+    fn dimap_composition() {
+        let sum = || Costar::<VecKind, i32, i32>::new(|xs: Vec<i32>| xs.into_iter().sum());
+        let f = |x: i64| x as i32;
+        let g = |y: u8| y as i64 * 2;
+        let h = |n: i32| n + 1;
+        let i = |n: i32| n.to_string();
+        let stepwise = sum().dimap(f, h).dimap(g, i);
+        let fused = sum().dimap(move |y: u8| f(g(y)), move |n: i32| i(h(n)));
+        assert_eq!(stepwise.run(vec![1, 2]), fused.run(vec![1, 2]));
+    }
+}
@@ -0,0 +1,219 @@
+use monadify::function::CFn;
+use monadify::identity::{Identity, IdentityKind};
+use monadify::kind_based::kind::{OptionKind, ResultKind, VecKind};
+use monadify::profunctor::{
+    _some, lens, over, set, to_list_of, traversal, traversed, Lens, Prism, Star, Strong, Traversal,
+    Traversed, Wander, _1,
+};
+use monadify::{Choice, Profunctor};
+
+#[derive(Clone, Debug, PartialEq)]
+struct Team {
+    name: String,
+    scores: Vec<i32>,
+}
+
+type ScoresFn = CFn<Vec<i32>, Vec<i32>>;
+
+// A traversal of every element of a `Vec`, run with `Star<F, _, _>`.
+type StarTraversal<F, A, B> =
+    Traversal<Star<F, Vec<A>, Vec<B>>, Star<F, A, B>, Vec<A>, Vec<B>, A, B>;
+
+// A type-preserving traversal run with `CFn`.
+type FnTraversal<S, A> = Traversal<CFn<S, S>, CFn<A, A>, S, S, A, A>;
+
+// A type-preserving prism run with `CFn`.
+type FnPrism<S, A> = Prism<CFn<S, S>, CFn<A, A>, S, S, A, A>;
+
+// Focuses on the `scores` field, run with `CFn` to update it.
+fn scores() -> Lens<CFn<Team, Team>, ScoresFn, Team, Team, Vec<i32>, Vec<i32>> {
+    lens(
+        CFn::new(|t: Team| t.scores),
+        CFn::new(|t: Team| {
+            CFn::new(move |scores| Team {
+                scores,
+                ..t.clone()
+            })
+        }),
+    )
+}
+
+#[test]
+fn over_modifies_every_element() {
+    assert_eq!(
+        over(traversed().into(), |x: i32| x * 10, vec![1, 2, 3]),
+        vec![10, 20, 30]
+    );
+    assert_eq!(
+        over(traversed().into(), |x: i32| x + 1, Vec::<i32>::new()),
+        Vec::<i32>::new()
+    );
+}
+
+#[test]
+fn over_can_change_the_element_type() {
+    let shown: Vec<String> = over(traversed().into(), |x: i32| x.to_string(), vec![1, 2]);
+    assert_eq!(shown, vec!["1", "2"]);
+}
+
+#[test]
+fn set_replaces_every_element() {
+    assert_eq!(set(traversed().into(), 0, vec![1, 2, 3]), vec![0, 0, 0]);
+}
+
+#[test]
+fn to_list_of_collects_the_foci() {
+    assert_eq!(
+        to_list_of(traversed::<_, _, i32, i32>().into(), vec![4, 5, 6]),
+        vec![4, 5, 6]
+    );
+    let nested: Vec<Vec<i32>> = vec![vec![1], vec![2, 3]];
+    assert_eq!(
+        to_list_of(
+            traversed::<_, _, Vec<i32>, Vec<i32>>().into(),
+            nested.clone()
+        ),
+        nested
+    );
+}
+
+#[test]
+fn traversal_inside_a_record_field() {
+    let team = Team {
+        name: "red".to_string(),
+        scores: vec![1, 2, 3],
+    };
+    let each: FnTraversal<Vec<i32>, i32> = traversed();
+    let bump = over(scores().0.then(each.0), |x: i32| x + 100, team);
+    assert_eq!(
+        bump,
+        Team {
+            name: "red".to_string(),
+            scores: vec![101, 102, 103],
+        }
+    );
+}
+
+#[test]
+fn traversal_with_star_collects_effects() {
+    let parse_all: StarTraversal<OptionKind, &str, i32> = traversed();
+    let run = (parse_all.0.optic)(Star::new(|s: &str| s.parse::<i32>().ok()));
+    assert_eq!(run.run(vec!["1", "2"]), Some(vec![1, 2]));
+    assert_eq!(run.run(vec!["1", "x"]), None);
+}
+
+#[test]
+fn traversal_with_star_result_short_circuits() {
+    type R<T> = Result<T, String>;
+    let check = |x: i32| -> R<i32> {
+        if x >= 0 {
+            Ok(x)
+        } else {
+            Err(format!("negative: {x}"))
+        }
+    };
+    let each: StarTraversal<ResultKind<String>, i32, i32> = traversed();
+    let run = (each.0.optic)(Star::new(check));
+    assert_eq!(run.run(vec![1, 2]), Ok(vec![1, 2]));
+    assert_eq!(run.run(vec![1, -2, 3]), Err("negative: -2".to_string()));
+}
+
+#[test]
+fn traversal_from_a_traversing_value() {
+    let each: FnTraversal<Vec<i32>, i32> = traversal(Traversed::<VecKind>::new());
+    let f = (each.0.optic)(CFn::new(|x: i32| -x));
+    assert_eq!(f.call(vec![1, -2]), vec![-1, 2]);
+}
+
+#[test]
+fn lens_and_prism_convert_into_traversals() {
+    let first: FnTraversal<(i32, char), i32> = _1().into();
+    assert_eq!(over(first.into(), |x| x + 1, (1, 'a')), (2, 'a'));
+
+    let some: FnPrism<Option<i32>, i32> = _some();
+    let some: Traversal<_, _, _, _, _, _> = some.into();
+    assert_eq!(over(some.into(), |x| x * 2, Some(4)), Some(8));
+    assert_eq!(
+        to_list_of(Traversal::from(_some::<_, _, i32, i32>()).into(), None),
+        Vec::<i32>::new()
+    );
+}
+
+// --- Star as a profunctor ---
+
+#[test]
+fn star_dimap_and_strong() {
+    let half = Star::<OptionKind, i32, i32>::new(|x| if x % 2 == 0 { Some(x / 2) } else { None });
+    let shown = half.dimap(|s: &str| s.len() as i32, |x: i32| x.to_string());
+    assert_eq!(shown.run("abcd"), Some("2".to_string()));
+    assert_eq!(shown.run("abc"), None);
+
+    let half = Star::<OptionKind, i32, i32>::new(|x| if x % 2 == 0 { Some(x / 2) } else { None });
+    assert_eq!(half.first::<char>().run((4, 'k')), Some((2, 'k')));
+}
+
+#[test]
+fn star_choice_passes_the_other_case_through() {
+    let pos = Star::<VecKind, i32, i32>::new(|x| vec![x, -x]);
+    let right = pos.right::<char>();
+    assert_eq!(right.run(Ok(3)), vec![Ok(3), Ok(-3)]);
+    assert_eq!(right.run(Err('c')), vec![Err('c')]);
+
+    let id = Star::<IdentityKind, i32, i32>::new(|x| Identity(x + 1));
+    assert_eq!(id.left::<()>().run(Err(1)), Identity(Err(2)));
+}
+
+#[test]
+fn star_wander_with_identity() {
+    let id = Star::<IdentityKind, i32, i32>::new(|x| Identity(x * 3));
+    let all = id.wander(Traversed::<VecKind>::new());
+    assert_eq!(all.run(vec![1, 2]), Identity(vec![3, 6]));
+}
+
+// --- Traversal laws ---
+
+mod traversal_laws {
+    use super::*;
+
+    // over(t, id) == id
+    #[test]
+    fn identity_law() {
+        let xs = vec![3, 1, 2];
+        assert_eq!(over(traversed().into(), |x: i32| x, xs.clone()), xs);
+    }
+
+    // over(t, f) . over(t, g) == over(t, f . g)
+    #[test]
+    fn composition_law() {
+        let f = |x: i32| x * 2;
+        let g = |x: i32| x + 3;
+        let xs = vec![1, 2, 3];
+        let lhs = over(
+            traversed().into(),
+            f,
+            over(traversed().into(), g, xs.clone()),
+        );
+        let rhs = over(traversed().into(), move |x| f(g(x)), xs);
+        assert_eq!(lhs, rhs);
+    }
+
+    // set(t, b, set(t, a, s)) == set(t, b, s)
+    #[test]
+    fn set_set_law() {
+        let xs = vec![1, 2];
+        let lhs = set(
+            traversed().into(),
+            9,
+            set(traversed().into(), 5, xs.clone()),
+        );
+        assert_eq!(lhs, set(traversed().into(), 9, xs));
+    }
+
+    // Traversing with the pure effect returns the structure unchanged.
+    #[test]
+    fn pure_traversal_law() {
+        let each: StarTraversal<OptionKind, i32, i32> = traversed();
+        let run = (each.0.optic)(Star::new(Some));
+        assert_eq!(run.run(vec![7, 8]), Some(vec![7, 8]));
+    }
+}
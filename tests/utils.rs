@@ -0,0 +1,147 @@
+use monadify::function::CFn;
+use monadify::partial;
+use monadify::utils::{
+    constant, curry2, curry3, curry4, curry8, flip, identity, on, uncurry2, uncurry3, uncurry8,
+};
+
+#[test]
+fn curried_stages_are_reusable_cfns() {
+    let greet = curry2(|greeting: String, name: &str| format!("{greeting}, {name}!"));
+    let hello: CFn<&str, String> = greet.call("Hello".to_string());
+    assert_eq!(hello.call("Ann"), "Hello, Ann!");
+    assert_eq!(hello.call("Bob"), "Hello, Bob!");
+
+    let volume = curry3(|l: u32, w: u32, h: u32| l * w * h);
+    let base = volume.call(2).call(3);
+    assert_eq!(base.call(4), 24);
+    assert_eq!(base.call(5), 30);
+}
+
+#[test]
+fn curried_stages_compose_with_other_cfns() {
+    let add = curry2(|x: i32, y: i32| x + y);
+    let double = CFn::new(|x: i32| x * 2);
+    assert_eq!((add.call(1) >> double).call(4), 10);
+    assert_eq!(Some(3).map(|y| add.call(10).call(y)), Some(13));
+}
+
+#[test]
+fn curry_keeps_argument_order_up_to_eight() {
+    // Each argument becomes one digit, so a swapped pair changes the result.
+    let sum8 = |a: i32, b: i32, c: i32, d: i32, e: i32, f: i32, g: i32, h: i32| {
+        a + 10 * b + 100 * c + 1_000 * d + 10_000 * e + 100_000 * f + 1_000_000 * g + 10_000_000 * h
+    };
+    let expected = sum8(1, 2, 3, 4, 5, 6, 7, 8);
+    let curried = curry8(sum8);
+    let result = curried
+        .call(1)
+        .call(2)
+        .call(3)
+        .call(4)
+        .call(5)
+        .call(6)
+        .call(7)
+        .call(8);
+    assert_eq!(result, expected);
+    assert_eq!(uncurry8(curried).call((1, 2, 3, 4, 5, 6, 7, 8)), expected);
+
+    let joined =
+        curry4(|a: char, b: char, c: char, d: char| [a, b, c, d].iter().collect::<String>());
+    assert_eq!(joined.call('a').call('b').call('c').call('d'), "abcd");
+}
+
+#[test]
+fn uncurry_inverts_curry() {
+    let sub = |x: i32, y: i32| x - y;
+    let round_trip: CFn<(i32, i32), i32> = uncurry2(curry2(sub));
+    assert_eq!(round_trip.call((10, 3)), sub(10, 3));
+
+    let clamp = uncurry3(curry3(|low: i32, x: i32, high: i32| x.max(low).min(high)));
+    assert_eq!(clamp.call((0, 150, 100)), 100);
+    assert_eq!(clamp.call((0, -5, 100)), 0);
+}
+
+#[test]
+fn flip_swaps_the_first_two_arguments() {
+    let div = curry2(|x: f64, y: f64| x / y);
+    let halve = flip(div).call(2.0);
+    assert_eq!(halve.call(9.0), 4.5);
+
+    let concat = curry2(|a: String, b: &str| a + b);
+    assert_eq!(flip(flip(concat)).call("ab".to_string()).call("cd"), "abcd");
+}
+
+#[test]
+fn constant_and_identity() {
+    let zero = constant::<String, i32>(0);
+    assert_eq!(zero.call("ignored".to_string()), 0);
+    assert_eq!(identity::<Vec<i32>>().call(vec![1, 2]), vec![1, 2]);
+    assert_eq!((identity() >> CFn::new(|x: i32| x + 1)).call(1), 2);
+}
+
+#[test]
+fn on_applies_the_projection_to_both_arguments() {
+    let by_abs = on(|a: i32, b: i32| a.cmp(&b), |x: i32| x.abs());
+    let mut values = vec![-7, 3, -1, 5];
+    values.sort_by(|a, b| by_abs.call(*a).call(*b));
+    assert_eq!(values, vec![-1, 3, 5, -7]);
+
+    let total_len: CFn<&str, CFn<&str, usize>> = on(|a: usize, b: usize| a + b, |s: &str| s.len());
+    let from_ab = total_len.call("ab");
+    assert_eq!(from_ab.call("cde"), 5);
+    assert_eq!(from_ab.call(""), 2);
+    assert_eq!(uncurry2(total_len).call(("x", "yz")), 3);
+}
+
+mod partial_application {
+    use super::*;
+
+    fn describe(name: &str, age: u32, city: String) -> String {
+        format!("{name} ({age}) from {city}")
+    }
+
+    #[test]
+    fn fills_a_single_hole() {
+        let from_paris = partial!(describe, _, 30, "Paris".to_string());
+        assert_eq!(from_paris.call("Ann"), "Ann (30) from Paris");
+        assert_eq!(from_paris.call("Bob"), "Bob (30) from Paris");
+    }
+
+    #[test]
+    fn several_holes_are_curried_in_order() {
+        let aged_40 = partial!(describe, _, 40, _);
+        let cal: CFn<String, String> = aged_40.call("Cal");
+        assert_eq!(cal.call("Rome".to_string()), "Cal (40) from Rome");
+        assert_eq!(cal.call("Oslo".to_string()), "Cal (40) from Oslo");
+    }
+
+    #[test]
+    fn fixed_arguments_are_evaluated_once() {
+        let mut evaluations = 0;
+        let add3 = |a: i32, b: i32, c: i32| a + b + c;
+        let add_ten = partial!(
+            add3,
+            {
+                evaluations += 1;
+                10
+            },
+            _,
+            0
+        );
+        assert_eq!(add_ten.call(1) + add_ten.call(2), 23);
+        assert_eq!(evaluations, 1);
+    }
+
+    #[test]
+    fn no_holes_gives_a_thunk() {
+        let thunk: CFn<(), String> = partial!(describe, "Dan", 25, "Lima".to_string());
+        assert_eq!(thunk.call(()), "Dan (25) from Lima");
+        assert_eq!(thunk.call(()), "Dan (25) from Lima");
+    }
+
+    #[test]
+    fn works_with_closures_and_all_holes() {
+        let pair = partial!(|a: i32, b: char| (a, b), _, _);
+        assert_eq!(pair.call(1).call('x'), (1, 'x'));
+    }
+}